@@ -9,6 +9,66 @@ pub trait BevyExtensionExport<D>: Send + Sync + 'static {
 
 impl BevyExtensionExport<GltfDocument> for DefaultExtensions {
     fn bevy_export(In(mut context): In<ExportContext>, world: &mut World) -> ExportContext {
+        #[cfg(feature = "khr_animation_pointer")]
+        {
+            use bevy::ecs::system::RunSystemOnce;
+
+            context = world
+                .run_system_once_with(
+                    gltf_kun::extensions::khr_animation_pointer::KhrAnimationPointer::bevy_export,
+                    context,
+                )
+                .expect("khr animation pointer");
+        }
+
+        #[cfg(feature = "khr_audio_emitter")]
+        {
+            use bevy::ecs::system::RunSystemOnce;
+
+            context = world
+                .run_system_once_with(
+                    gltf_kun::extensions::khr_audio_emitter::KhrAudioEmitter::bevy_export,
+                    context,
+                )
+                .expect("khr audio emitter");
+        }
+
+        #[cfg(feature = "khr_node_visibility")]
+        {
+            use bevy::ecs::system::RunSystemOnce;
+
+            context = world
+                .run_system_once_with(
+                    gltf_kun::extensions::khr_node_visibility::KhrNodeVisibility::bevy_export,
+                    context,
+                )
+                .expect("khr node visibility");
+        }
+
+        #[cfg(feature = "msft_lod")]
+        {
+            use bevy::ecs::system::RunSystemOnce;
+
+            context = world
+                .run_system_once_with(
+                    gltf_kun::extensions::msft_lod::MsftLod::bevy_export,
+                    context,
+                )
+                .expect("msft lod");
+        }
+
+        #[cfg(feature = "omi_link")]
+        {
+            use bevy::ecs::system::RunSystemOnce;
+
+            context = world
+                .run_system_once_with(
+                    gltf_kun::extensions::omi_link::OmiLink::bevy_export,
+                    context,
+                )
+                .expect("omi link");
+        }
+
         #[cfg(feature = "omi_physics")]
         {
             use bevy::ecs::system::RunSystemOnce;
@@ -21,6 +81,30 @@ impl BevyExtensionExport<GltfDocument> for DefaultExtensions {
                 .expect("omi physics");
         }
 
+        #[cfg(feature = "omi_seat")]
+        {
+            use bevy::ecs::system::RunSystemOnce;
+
+            context = world
+                .run_system_once_with(
+                    gltf_kun::extensions::omi_seat::OmiSeat::bevy_export,
+                    context,
+                )
+                .expect("omi seat");
+        }
+
+        #[cfg(feature = "omi_spawn_point")]
+        {
+            use bevy::ecs::system::RunSystemOnce;
+
+            context = world
+                .run_system_once_with(
+                    gltf_kun::extensions::omi_spawn_point::OmiSpawnPoint::bevy_export,
+                    context,
+                )
+                .expect("omi spawn point");
+        }
+
         context
     }
 }
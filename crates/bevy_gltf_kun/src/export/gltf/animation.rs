@@ -63,8 +63,8 @@ pub fn export_animations(
     ctx
 }
 
-const SAMPLE_RATE: f32 = 0.05;
-const F32_ERR_MARGIN: f32 = 1e-12;
+pub const SAMPLE_RATE: f32 = 0.05;
+pub const F32_ERR_MARGIN: f32 = 1e-12;
 
 #[allow(clippy::too_many_lines)]
 fn export_curve(
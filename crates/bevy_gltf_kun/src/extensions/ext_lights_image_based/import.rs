@@ -0,0 +1,258 @@
+use std::f32::consts::PI;
+
+use bevy::{
+    asset::RenderAssetUsages,
+    prelude::*,
+    render::render_resource::{
+        Extent3d, TextureDataOrder, TextureDimension, TextureFormat, TextureViewDescriptor,
+        TextureViewDimension,
+    },
+};
+use gltf_kun::{
+    extensions::ext_lights_image_based::{
+        ExtLightsImageBased,
+        light::{CUBE_FACES, ImageBasedLight, ImageBasedLightWeight},
+    },
+    graph::{ByteNode, Extensions, GraphNodeWeight, gltf::Scene},
+};
+use half::f16;
+use image::DynamicImage;
+use thiserror::Error;
+
+use crate::import::gltf::document::ImportContext;
+
+use super::{DIFFUSE_MAP_SIZE, INTENSITY_SCALE, LIGHT_PROBE_EXTENT};
+
+#[derive(Debug, Error)]
+pub enum CubemapError {
+    #[error("light has no specular images")]
+    Empty,
+    #[error("failed to decode image: {0}")]
+    Image(#[from] image::ImageError),
+    #[error("specular image is {found}px, expected {expected}px")]
+    Size { expected: u32, found: u32 },
+}
+
+/// Spawns a [`LightProbe`] with an [`EnvironmentMapLight`] under the scene root,
+/// if the scene references an image based light.
+pub fn import_scene(context: &mut ImportContext, scene: Scene, world: &mut World) {
+    let Some(light) = scene.get_extension::<ImageBasedLight>(context.graph) else {
+        return;
+    };
+
+    let Some(root) = context.scene_root else {
+        return;
+    };
+
+    let index = context
+        .doc
+        .get_extension::<ExtLightsImageBased>(context.graph)
+        .and_then(|ext| ext.lights(context.graph).iter().position(|l| *l == light))
+        .expect("index should exist for light");
+
+    let weight = light.read(context.graph);
+
+    let specular = match specular_cubemap(context, light, &weight) {
+        Ok(image) => image,
+        Err(e) => {
+            warn!("Failed to import image based light: {}", e);
+            return;
+        }
+    };
+    let diffuse = diffuse_cubemap(&weight.irradiance_coefficients);
+
+    let label = light_label(index);
+    let specular_map = context
+        .load_context
+        .add_labeled_asset(format!("{label}/Specular"), specular);
+    let diffuse_map = context
+        .load_context
+        .add_labeled_asset(format!("{label}/Diffuse"), diffuse);
+
+    world.spawn((
+        Name::new(weight.name.unwrap_or(label)),
+        LightProbe,
+        EnvironmentMapLight {
+            diffuse_map,
+            specular_map,
+            intensity: weight.intensity * INTENSITY_SCALE,
+            rotation: Quat::from_array(weight.rotation),
+            ..default()
+        },
+        Transform::from_scale(Vec3::splat(LIGHT_PROBE_EXTENT * 2.0)),
+        ChildOf(root),
+    ));
+}
+
+/// Builds a mipmapped cubemap from the light's specular images.
+/// The mip chain is cut short at the first level with a mismatched size.
+fn specular_cubemap(
+    context: &ImportContext,
+    light: ImageBasedLight,
+    weight: &ImageBasedLightWeight,
+) -> Result<Image, CubemapError> {
+    let mips = light.specular_images(context.graph);
+    let first = mips.first().ok_or(CubemapError::Empty)?;
+
+    let size = if weight.specular_image_size == 0 {
+        image::load_from_memory(&first[0].get(context.graph).data)?.width()
+    } else {
+        weight.specular_image_size
+    };
+
+    let mut faces = vec![Vec::new(); CUBE_FACES];
+    let mut mip_count = 0;
+
+    for (level, images) in mips.iter().enumerate() {
+        let expected = (size >> level).max(1);
+
+        let decoded = images
+            .iter()
+            .map(|image| image::load_from_memory(&image.get(context.graph).data))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if let Some(found) = decoded
+            .iter()
+            .map(|image| image.width().max(image.height()))
+            .find(|found| *found != expected)
+        {
+            if level == 0 {
+                return Err(CubemapError::Size { expected, found });
+            }
+            warn!(
+                "Specular mip {} is {}px, expected {}px",
+                level, found, expected
+            );
+            break;
+        }
+
+        for (face, image) in faces.iter_mut().zip(decoded) {
+            face.push(linear_rgba16(&image));
+        }
+
+        mip_count += 1;
+    }
+
+    // Each face is followed by its full mip chain.
+    let data = faces.into_iter().flatten().flatten().collect();
+
+    Ok(cubemap(size, mip_count, data))
+}
+
+/// Evaluates the irradiance spherical harmonics into a small cubemap.
+fn diffuse_cubemap(coefficients: &[[f32; 3]]) -> Image {
+    let coefficients = coefficients
+        .iter()
+        .map(|c| Vec3::from_array(*c))
+        .collect::<Vec<_>>();
+
+    let mut data = Vec::new();
+
+    for face in 0..CUBE_FACES {
+        for y in 0..DIFFUSE_MAP_SIZE {
+            for x in 0..DIFFUSE_MAP_SIZE {
+                let u = ((x as f32 + 0.5) / DIFFUSE_MAP_SIZE as f32).mul_add(2.0, -1.0);
+                let v = ((y as f32 + 0.5) / DIFFUSE_MAP_SIZE as f32).mul_add(2.0, -1.0);
+                let dir = face_direction(face, u, v);
+
+                // Lambertian radiance from irradiance.
+                let radiance = sh_basis(dir)
+                    .iter()
+                    .zip(&coefficients)
+                    .fold(Vec3::ZERO, |acc, (basis, c)| acc + *c * *basis)
+                    .max(Vec3::ZERO)
+                    / PI;
+
+                for channel in radiance.extend(1.0).to_array() {
+                    data.extend(f16::from_f32(channel).to_le_bytes());
+                }
+            }
+        }
+    }
+
+    cubemap(DIFFUSE_MAP_SIZE, 1, data)
+}
+
+fn cubemap(size: u32, mip_count: u32, data: Vec<u8>) -> Image {
+    let mut image = Image::new_uninit(
+        Extent3d {
+            width: size,
+            height: size,
+            depth_or_array_layers: u32::try_from(CUBE_FACES).expect("face count should fit"),
+        },
+        TextureDimension::D2,
+        TextureFormat::Rgba16Float,
+        RenderAssetUsages::default(),
+    );
+
+    image.data = Some(data);
+    image.data_order = TextureDataOrder::LayerMajor;
+    image.texture_descriptor.mip_level_count = mip_count;
+    image.texture_view_descriptor = Some(TextureViewDescriptor {
+        dimension: Some(TextureViewDimension::Cube),
+        ..default()
+    });
+
+    image
+}
+
+/// Converts an image to linear half float RGBA.
+/// Integer images are assumed to be sRGB encoded.
+fn linear_rgba16(image: &DynamicImage) -> Vec<u8> {
+    let is_float = matches!(
+        image,
+        DynamicImage::ImageRgb32F(_) | DynamicImage::ImageRgba32F(_)
+    );
+
+    image
+        .to_rgba32f()
+        .pixels()
+        .flat_map(|pixel| {
+            let [r, g, b, a] = pixel.0;
+            let rgb = [r, g, b].map(|c| if is_float { c } else { srgb_to_linear(c) });
+            [rgb[0], rgb[1], rgb[2], a]
+        })
+        .flat_map(|c| f16::from_f32(c).to_le_bytes())
+        .collect()
+}
+
+fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Direction through a texel of a cubemap face, ordered +X, -X, +Y, -Y, +Z, -Z.
+fn face_direction(face: usize, u: f32, v: f32) -> Vec3 {
+    match face {
+        0 => Vec3::new(1.0, -v, -u),
+        1 => Vec3::new(-1.0, -v, u),
+        2 => Vec3::new(u, 1.0, v),
+        3 => Vec3::new(u, -1.0, -v),
+        4 => Vec3::new(u, -v, 1.0),
+        _ => Vec3::new(-u, -v, -1.0),
+    }
+    .normalize()
+}
+
+/// Real spherical harmonic basis up to l=2.
+fn sh_basis(dir: Vec3) -> [f32; 9] {
+    let Vec3 { x, y, z } = dir;
+    [
+        0.282_095,
+        0.488_603 * y,
+        0.488_603 * z,
+        0.488_603 * x,
+        1.092_548 * x * y,
+        1.092_548 * y * z,
+        0.315_392 * (3.0 * z).mul_add(z, -1.0),
+        1.092_548 * x * z,
+        0.546_274 * x.mul_add(x, -y * y),
+    ]
+}
+
+fn light_label(index: usize) -> String {
+    format!("ImageBasedLight{index}")
+}
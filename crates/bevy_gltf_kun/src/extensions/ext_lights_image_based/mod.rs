@@ -0,0 +1,13 @@
+//! Maps scene-level image based lights to an [`EnvironmentMapLight`](bevy::prelude::EnvironmentMapLight)
+//! on a [`LightProbe`](bevy::prelude::LightProbe) covering the scene.
+
+pub mod import;
+
+/// Half-extent of the light probe spawned for a scene's image based light.
+pub const LIGHT_PROBE_EXTENT: f32 = 1000.0;
+
+/// glTF intensity is a unitless multiplier, while Bevy expects cd/m^2.
+pub const INTENSITY_SCALE: f32 = 1000.0;
+
+/// Size of each face of the generated diffuse cubemap.
+pub const DIFFUSE_MAP_SIZE: u32 = 16;
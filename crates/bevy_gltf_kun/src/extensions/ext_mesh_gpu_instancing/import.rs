@@ -0,0 +1,80 @@
+use bevy::prelude::*;
+use gltf_kun::{
+    extensions::ext_mesh_gpu_instancing::{ExtMeshGpuInstancing, ROTATION, SCALE, TRANSLATION},
+    graph::{
+        Graph,
+        gltf::{Accessor, accessor::iter::AccessorIter, document::GltfDocument},
+    },
+};
+
+use crate::import::{extensions::NodeExtensionImport, gltf::document::ImportContext};
+
+impl NodeExtensionImport<GltfDocument> for ExtMeshGpuInstancing {
+    fn import_node(context: &mut ImportContext, entity: &mut EntityWorldMut, ext: Self) {
+        let transforms = instance_transforms(context.graph, ext);
+
+        let Some((first, rest)) = transforms.split_first() else {
+            return;
+        };
+
+        let parent = entity.id();
+
+        let primitives = entity
+            .get::<Children>()
+            .map(|children| children.iter().collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        entity.world_scope(|world| {
+            for primitive in primitives {
+                if !world.entity(primitive).contains::<Mesh3d>() {
+                    continue;
+                }
+
+                for transform in rest {
+                    let clone = world.entity_mut(primitive).clone_and_spawn();
+                    world
+                        .entity_mut(clone)
+                        .insert((*transform, ChildOf(parent)));
+                }
+
+                world.entity_mut(primitive).insert(*first);
+            }
+        });
+    }
+}
+
+/// Reads the transform of each instance.
+/// Missing attributes default to the identity.
+fn instance_transforms(graph: &Graph, ext: ExtMeshGpuInstancing) -> Vec<Transform> {
+    let translations = read_vec3(graph, ext.attribute(graph, TRANSLATION));
+    let rotations = match ext.attribute(graph, ROTATION).map(|a| a.to_iter(graph)) {
+        Some(Ok(AccessorIter::F32x4(iter))) => iter.map(Quat::from_array).collect(),
+        Some(_) => {
+            warn!("Unsupported instance {} accessor", ROTATION);
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+    let scales = read_vec3(graph, ext.attribute(graph, SCALE));
+
+    let count = translations.len().max(rotations.len()).max(scales.len());
+
+    (0..count)
+        .map(|i| Transform {
+            translation: translations.get(i).copied().unwrap_or(Vec3::ZERO),
+            rotation: rotations.get(i).copied().unwrap_or(Quat::IDENTITY),
+            scale: scales.get(i).copied().unwrap_or(Vec3::ONE),
+        })
+        .collect()
+}
+
+fn read_vec3(graph: &Graph, accessor: Option<Accessor>) -> Vec<Vec3> {
+    match accessor.map(|a| a.to_iter(graph)) {
+        Some(Ok(AccessorIter::F32x3(iter))) => iter.map(Vec3::from_array).collect(),
+        Some(_) => {
+            warn!("Unsupported instance accessor");
+            Vec::new()
+        }
+        None => Vec::new(),
+    }
+}
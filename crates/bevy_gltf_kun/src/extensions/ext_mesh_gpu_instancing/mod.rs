@@ -0,0 +1,3 @@
+//! Spawns a copy of an instanced node's primitives for each instance.
+
+pub mod import;
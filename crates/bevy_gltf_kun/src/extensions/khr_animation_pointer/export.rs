@@ -0,0 +1,244 @@
+use std::any::TypeId;
+
+use bevy::{
+    animation::{AnimationEntityMut, AnimationTargetId, animated_field},
+    ecs::system::RunSystemOnce,
+    platform::collections::HashSet,
+    prelude::*,
+};
+use gltf_kun::{
+    extensions::khr_animation_pointer::{
+        KhrAnimationPointer, KhrAnimationPointerWeight, PointerTarget,
+    },
+    graph::{
+        ByteNode, Extensions, GraphNodeWeight,
+        gltf::{
+            Animation, GltfDocument, Material,
+            accessor::{ComponentType, Type},
+            animation::{AnimationSampler, Interpolation},
+        },
+    },
+};
+
+use crate::export::{
+    extensions::BevyExtensionExport,
+    gltf::{
+        ExportContext,
+        animation::{F32_ERR_MARGIN, SAMPLE_RATE},
+    },
+};
+
+use super::{
+    ALPHA_CUTOFF_POINTER, AnimatedMaterial, BASE_COLOR_POINTER, EMISSIVE_POINTER, METALLIC_POINTER,
+    ROUGHNESS_POINTER, UV_OFFSET_POINTER, UV_ROTATION_POINTER, UV_SCALE_POINTER,
+};
+
+impl BevyExtensionExport<GltfDocument> for KhrAnimationPointer {
+    fn bevy_export(In(context): In<ExportContext>, world: &mut World) -> ExportContext {
+        world
+            .run_system_once_with(export_material_animations, context)
+            .expect("export material animations")
+    }
+}
+
+/// An [`AnimatedMaterial`] field, and how to write it as a pointer channel.
+struct MaterialField {
+    id: (TypeId, usize),
+    pointer: &'static str,
+    element_type: Type,
+    read: fn(&AnimatedMaterial) -> Vec<f32>,
+}
+
+fn material_fields() -> Vec<MaterialField> {
+    fn field_id(property: &impl AnimatableProperty) -> (TypeId, usize) {
+        match property.evaluator_id() {
+            EvaluatorId::ComponentField(id) => **id,
+            EvaluatorId::Type(_) => unreachable!(),
+        }
+    }
+
+    vec![
+        MaterialField {
+            id: field_id(&animated_field!(AnimatedMaterial::base_color)),
+            pointer: BASE_COLOR_POINTER,
+            element_type: Type::Vec4,
+            read: |m| m.base_color.to_array().to_vec(),
+        },
+        MaterialField {
+            id: field_id(&animated_field!(AnimatedMaterial::emissive)),
+            pointer: EMISSIVE_POINTER,
+            element_type: Type::Vec3,
+            read: |m| m.emissive.to_array().to_vec(),
+        },
+        MaterialField {
+            id: field_id(&animated_field!(AnimatedMaterial::metallic)),
+            pointer: METALLIC_POINTER,
+            element_type: Type::Scalar,
+            read: |m| vec![m.metallic],
+        },
+        MaterialField {
+            id: field_id(&animated_field!(AnimatedMaterial::roughness)),
+            pointer: ROUGHNESS_POINTER,
+            element_type: Type::Scalar,
+            read: |m| vec![m.roughness],
+        },
+        MaterialField {
+            id: field_id(&animated_field!(AnimatedMaterial::alpha_cutoff)),
+            pointer: ALPHA_CUTOFF_POINTER,
+            element_type: Type::Scalar,
+            read: |m| vec![m.alpha_cutoff],
+        },
+        MaterialField {
+            id: field_id(&animated_field!(AnimatedMaterial::uv_offset)),
+            pointer: UV_OFFSET_POINTER,
+            element_type: Type::Vec2,
+            read: |m| m.uv_offset.to_array().to_vec(),
+        },
+        MaterialField {
+            id: field_id(&animated_field!(AnimatedMaterial::uv_rotation)),
+            pointer: UV_ROTATION_POINTER,
+            element_type: Type::Scalar,
+            read: |m| vec![m.uv_rotation],
+        },
+        MaterialField {
+            id: field_id(&animated_field!(AnimatedMaterial::uv_scale)),
+            pointer: UV_SCALE_POINTER,
+            element_type: Type::Vec2,
+            read: |m| m.uv_scale.to_array().to_vec(),
+        },
+    ]
+}
+
+/// Exports curves targeting [`AnimatedMaterial`] as pointer channels.
+pub fn export_material_animations(
+    In(mut context): In<ExportContext>,
+    clips: Res<Assets<AnimationClip>>,
+    targets: Query<&AnimationTargetId, With<AnimatedMaterial>>,
+) -> ExportContext {
+    let fields = material_fields();
+
+    // Primitives sharing a material share its curves, so only export them once.
+    let mut exported = HashSet::new();
+
+    let materials = context
+        .materials
+        .iter()
+        .filter_map(|cached| Some((targets.get(cached.entity).ok()?, cached.material)))
+        .collect::<Vec<_>>();
+
+    for (target, material) in materials {
+        for (id, clip) in clips.iter() {
+            let Some(curves) = clip.curves_for_target(*target) else {
+                continue;
+            };
+
+            if curves.is_empty() || !exported.insert((id, material)) {
+                continue;
+            }
+
+            let animation = context.doc.create_animation(&mut context.graph);
+
+            for curve in curves {
+                if let Err(e) = export_curve(&mut context, &fields, animation, curve, material) {
+                    warn!("Failed to export material animation curve: {:?}", e);
+                }
+            }
+        }
+    }
+
+    context
+}
+
+fn export_curve(
+    context: &mut ExportContext,
+    fields: &[MaterialField],
+    animation: Animation,
+    curve: &VariableCurve,
+    material: Material,
+) -> anyhow::Result<()> {
+    let EvaluatorId::ComponentField(id) = curve.0.evaluator_id() else {
+        anyhow::bail!("Custom animation types not supported.");
+    };
+
+    let field = fields
+        .iter()
+        .find(|f| f.id == **id)
+        .ok_or_else(|| anyhow::format_err!("Unknown material animation field"))?;
+
+    let mut world = World::default();
+    let ent = world.spawn(AnimatedMaterial::default()).id();
+
+    let mut graph = AnimationGraph::default();
+    let idx = graph.add_blend(1.0, graph.root);
+
+    let mut eval = curve.0.create_evaluator();
+    let interval = curve.0.domain();
+
+    let mut t = interval.start();
+    let mut timestamps = Vec::new();
+    let mut keyframes = Vec::new();
+
+    loop {
+        timestamps.push(t);
+
+        curve
+            .0
+            .apply(eval.as_mut(), t, 1.0, idx)
+            .map_err(|e| anyhow::format_err!("{e:?}"))?;
+
+        let mut anim_query = world.query::<AnimationEntityMut>();
+        let ent_anim = anim_query
+            .get_mut(&mut world, ent)
+            .expect("entity should exist");
+
+        eval.commit(ent_anim)
+            .map_err(|e| anyhow::format_err!("{e:?}"))?;
+
+        let animated = world
+            .get::<AnimatedMaterial>(ent)
+            .expect("entity should have animated material");
+        keyframes.extend((field.read)(animated));
+
+        if (t - interval.end()).abs() < F32_ERR_MARGIN {
+            break;
+        }
+
+        t = (t + SAMPLE_RATE).min(interval.end());
+    }
+
+    let graph = &mut context.graph;
+    let buffer = context.doc.create_buffer(graph);
+
+    let mut input = context.doc.create_accessor(graph);
+    input.set_buffer(graph, Some(buffer));
+    let input_weight = input.get_mut(graph);
+    input_weight.element_type = Type::Scalar;
+    input_weight.component_type = ComponentType::F32;
+    input_weight.data = timestamps.into_iter().flat_map(f32::to_le_bytes).collect();
+
+    let mut output = context.doc.create_accessor(graph);
+    output.set_buffer(graph, Some(buffer));
+    let output_weight = output.get_mut(graph);
+    output_weight.element_type = field.element_type;
+    output_weight.component_type = ComponentType::F32;
+    output_weight.data = keyframes.into_iter().flat_map(f32::to_le_bytes).collect();
+
+    let mut sampler = AnimationSampler::new(graph);
+    sampler.get_mut(graph).interpolation = Interpolation::Linear;
+    sampler.set_input(graph, Some(input));
+    sampler.set_output(graph, Some(output));
+
+    let channel = animation.create_channel(graph);
+    channel.set_sampler(graph, Some(sampler));
+
+    let ext = channel.create_extension::<KhrAnimationPointer>(graph);
+    ext.write(
+        graph,
+        &KhrAnimationPointerWeight {
+            pointer: field.pointer.to_string(),
+        },
+    );
+    ext.set_target(graph, Some(PointerTarget::Material(material)));
+
+    Ok(())
+}
@@ -0,0 +1,463 @@
+use anyhow::{Result, bail};
+use bevy::{
+    animation::{
+        AnimatedBy, AnimationTargetId, animated_field,
+        gltf_curves::{
+            CubicKeyframeCurve, CubicRotationCurve, SteppedKeyframeCurve, WideCubicKeyframeCurve,
+            WideLinearKeyframeCurve, WideSteppedKeyframeCurve,
+        },
+    },
+    math::{StableInterpolate, VectorSpace},
+    platform::collections::{HashMap, HashSet},
+    prelude::*,
+    reflect::{GetTypeRegistration, Typed},
+};
+use gltf_kun::{
+    extensions::khr_animation_pointer::{KhrAnimationPointer, PointerTarget},
+    graph::{
+        ByteNode, Extensions, GraphNodeWeight,
+        gltf::{
+            Material, Mesh, Node, accessor::iter::AccessorIter, animation::Interpolation,
+            material::AlphaMode,
+        },
+    },
+};
+
+#[cfg(feature = "khr_node_visibility")]
+use crate::extensions::khr_node_visibility::NodeVisibility;
+use crate::import::gltf::{animation::paths_recur, document::ImportContext};
+
+use super::{
+    ALPHA_CUTOFF_POINTER, AnimatedMaterial, BASE_COLOR_POINTER, EMISSIVE_POINTER, METALLIC_POINTER,
+    ROUGHNESS_POINTER, UV_OFFSET_POINTER, UV_ROTATION_POINTER, UV_SCALE_POINTER, WEIGHTS_POINTER,
+    material_target_id,
+};
+
+/// A curve, with the animation root and target it should be added to.
+pub struct PointerCurve {
+    pub root: Node,
+    pub target: AnimationTargetId,
+    pub curve: VariableCurve,
+}
+
+/// Convert a pointer channel into curves.
+/// Material and mesh curves are added once for every node using the material or mesh.
+pub fn import_channel(
+    context: &ImportContext,
+    paths: &HashMap<Node, (Node, Vec<Name>)>,
+    pointer: KhrAnimationPointer,
+    times: &[f32],
+    output: AccessorIter,
+    interpolation: Interpolation,
+) -> Result<Vec<PointerCurve>> {
+    let relative = pointer.read(context.graph).pointer;
+
+    let Some(target) = pointer.target(context.graph) else {
+        bail!("Pointer {relative} is not supported");
+    };
+
+    let (targets, curve) = match target {
+        PointerTarget::Material(material) => {
+            let curve = material_curve(&relative, times, output, interpolation)?;
+
+            let index = context
+                .doc
+                .material_index(context.graph, material)
+                .expect("index should exist for material");
+
+            let targets = material_nodes(context, material)
+                .into_iter()
+                .filter_map(|node| paths.get(&node))
+                .map(|(root, path)| (*root, material_target_id(path, index)))
+                .collect::<Vec<_>>();
+
+            (targets, curve)
+        }
+        PointerTarget::Node(node) => {
+            let curve = node_curve(&relative, times, output, interpolation)?;
+
+            let targets = paths
+                .get(&node)
+                .map(|(root, path)| (*root, AnimationTargetId::from_names(path.iter())))
+                .into_iter()
+                .collect::<Vec<_>>();
+
+            (targets, curve)
+        }
+        PointerTarget::Mesh(mesh) => {
+            let curve = match (relative.as_str(), output) {
+                (WEIGHTS_POINTER, AccessorIter::F32(iter)) => {
+                    weights_curve(times, iter.collect(), interpolation)
+                }
+                _ => bail!("Mesh pointer {relative} is not supported"),
+            }
+            .ok_or_else(|| anyhow::format_err!("Invalid {relative} keyframe data"))?;
+
+            let targets = mesh_nodes(context, mesh)
+                .into_iter()
+                .filter_map(|node| paths.get(&node))
+                .map(|(root, path)| (*root, AnimationTargetId::from_names(path.iter())))
+                .collect::<Vec<_>>();
+
+            (targets, curve)
+        }
+    };
+
+    Ok(targets
+        .into_iter()
+        .map(|(root, target)| PointerCurve {
+            root,
+            target,
+            curve: curve.clone(),
+        })
+        .collect())
+}
+
+fn material_curve(
+    pointer: &str,
+    times: &[f32],
+    output: AccessorIter,
+    interpolation: Interpolation,
+) -> Result<VariableCurve> {
+    let curve = match (pointer, output) {
+        (BASE_COLOR_POINTER, AccessorIter::F32x4(iter)) => keyframe_curve(
+            animated_field!(AnimatedMaterial::base_color),
+            times,
+            iter.map(Vec4::from_array).collect(),
+            interpolation,
+        ),
+        (EMISSIVE_POINTER, AccessorIter::F32x3(iter)) => keyframe_curve(
+            animated_field!(AnimatedMaterial::emissive),
+            times,
+            iter.map(Vec3::from_array).collect(),
+            interpolation,
+        ),
+        (METALLIC_POINTER, AccessorIter::F32(iter)) => keyframe_curve(
+            animated_field!(AnimatedMaterial::metallic),
+            times,
+            iter.collect(),
+            interpolation,
+        ),
+        (ROUGHNESS_POINTER, AccessorIter::F32(iter)) => keyframe_curve(
+            animated_field!(AnimatedMaterial::roughness),
+            times,
+            iter.collect(),
+            interpolation,
+        ),
+        (ALPHA_CUTOFF_POINTER, AccessorIter::F32(iter)) => keyframe_curve(
+            animated_field!(AnimatedMaterial::alpha_cutoff),
+            times,
+            iter.collect(),
+            interpolation,
+        ),
+        (UV_OFFSET_POINTER, AccessorIter::F32x2(iter)) => keyframe_curve(
+            animated_field!(AnimatedMaterial::uv_offset),
+            times,
+            iter.map(Vec2::from_array).collect(),
+            interpolation,
+        ),
+        (UV_ROTATION_POINTER, AccessorIter::F32(iter)) => keyframe_curve(
+            animated_field!(AnimatedMaterial::uv_rotation),
+            times,
+            iter.collect(),
+            interpolation,
+        ),
+        (UV_SCALE_POINTER, AccessorIter::F32x2(iter)) => keyframe_curve(
+            animated_field!(AnimatedMaterial::uv_scale),
+            times,
+            iter.map(Vec2::from_array).collect(),
+            interpolation,
+        ),
+        _ => bail!("Material pointer {pointer} is not supported"),
+    };
+
+    curve.ok_or_else(|| anyhow::format_err!("Invalid {pointer} keyframe data"))
+}
+
+fn node_curve(
+    pointer: &str,
+    times: &[f32],
+    output: AccessorIter,
+    interpolation: Interpolation,
+) -> Result<VariableCurve> {
+    let curve = match (pointer, output) {
+        ("/translation", AccessorIter::F32x3(iter)) => keyframe_curve(
+            animated_field!(Transform::translation),
+            times,
+            iter.map(Vec3::from_array).collect(),
+            interpolation,
+        ),
+        ("/scale", AccessorIter::F32x3(iter)) => keyframe_curve(
+            animated_field!(Transform::scale),
+            times,
+            iter.map(Vec3::from_array).collect(),
+            interpolation,
+        ),
+        ("/rotation", AccessorIter::F32x4(iter)) => {
+            let property = animated_field!(Transform::rotation);
+            let values = iter.map(Quat::from_array).collect::<Vec<_>>();
+            let samples = || times.iter().copied().zip(values.iter().copied());
+
+            match interpolation {
+                Interpolation::Linear => UnevenSampleAutoCurve::new(samples())
+                    .ok()
+                    .map(|c| VariableCurve::new(AnimatableCurve::new(property, c))),
+                Interpolation::Step => SteppedKeyframeCurve::new(samples())
+                    .ok()
+                    .map(|c| VariableCurve::new(AnimatableCurve::new(property, c))),
+                Interpolation::CubicSpline => CubicRotationCurve::new(
+                    times.iter().copied(),
+                    values.iter().copied().map(Vec4::from),
+                )
+                .ok()
+                .map(|c| VariableCurve::new(AnimatableCurve::new(property, c))),
+            }
+        }
+        (WEIGHTS_POINTER, AccessorIter::F32(iter)) => {
+            weights_curve(times, iter.collect(), interpolation)
+        }
+        #[cfg(feature = "khr_node_visibility")]
+        (gltf_kun::extensions::khr_node_visibility::VISIBLE_POINTER, AccessorIter::U8(iter)) => {
+            let property = animated_field!(NodeVisibility::visible);
+            let values = iter.map(|v| v != 0).collect::<Vec<_>>();
+
+            if let [value] = values.as_slice() {
+                Some(VariableCurve::new(AnimatableCurve::new(
+                    property,
+                    ConstantCurve::new(Interval::EVERYWHERE, *value),
+                )))
+            } else {
+                SteppedKeyframeCurve::new(times.iter().copied().zip(values))
+                    .ok()
+                    .map(|c| VariableCurve::new(AnimatableCurve::new(property, c)))
+            }
+        }
+        _ => bail!("Node pointer {pointer} is not supported"),
+    };
+
+    curve.ok_or_else(|| anyhow::format_err!("Invalid {pointer} keyframe data"))
+}
+
+fn keyframe_curve<P, T>(
+    property: P,
+    times: &[f32],
+    values: Vec<T>,
+    interpolation: Interpolation,
+) -> Option<VariableCurve>
+where
+    P: AnimatableProperty<Property = T> + Clone,
+    T: Animatable
+        + VectorSpace<Scalar = f32>
+        + StableInterpolate
+        + FromReflect
+        + GetTypeRegistration
+        + Typed,
+{
+    if let [value] = values.as_slice() {
+        return Some(VariableCurve::new(AnimatableCurve::new(
+            property,
+            ConstantCurve::new(Interval::EVERYWHERE, *value),
+        )));
+    }
+
+    let samples = times.iter().copied().zip(values.iter().copied());
+
+    match interpolation {
+        Interpolation::Linear => UnevenSampleAutoCurve::new(samples)
+            .ok()
+            .map(|c| VariableCurve::new(AnimatableCurve::new(property, c))),
+        Interpolation::Step => SteppedKeyframeCurve::new(samples)
+            .ok()
+            .map(|c| VariableCurve::new(AnimatableCurve::new(property, c))),
+        Interpolation::CubicSpline => CubicKeyframeCurve::new(times.iter().copied(), values)
+            .ok()
+            .map(|c| VariableCurve::new(AnimatableCurve::new(property, c))),
+    }
+}
+
+/// Morph target weights curve, with every weight of a keyframe in one output.
+fn weights_curve(
+    times: &[f32],
+    values: Vec<f32>,
+    interpolation: Interpolation,
+) -> Option<VariableCurve> {
+    if let [_] = times {
+        return Some(VariableCurve::new(WeightsCurve(ConstantCurve::new(
+            Interval::EVERYWHERE,
+            values,
+        ))));
+    }
+
+    let times = times.to_vec();
+
+    match interpolation {
+        Interpolation::Linear => WideLinearKeyframeCurve::new(times, values)
+            .ok()
+            .map(|c| VariableCurve::new(WeightsCurve(c))),
+        Interpolation::Step => WideSteppedKeyframeCurve::new(times, values)
+            .ok()
+            .map(|c| VariableCurve::new(WeightsCurve(c))),
+        Interpolation::CubicSpline => WideCubicKeyframeCurve::new(times, values)
+            .ok()
+            .map(|c| VariableCurve::new(WeightsCurve(c))),
+    }
+}
+
+/// Nodes using the mesh's default weights, which are not overridden by their own.
+fn mesh_nodes(context: &ImportContext, mesh: Mesh) -> Vec<Node> {
+    context
+        .doc
+        .nodes(context.graph)
+        .into_iter()
+        .filter(|node| {
+            node.mesh(context.graph) == Some(mesh) && node.get(context.graph).weights.is_empty()
+        })
+        .collect()
+}
+
+/// Nodes with a mesh that uses the material.
+fn material_nodes(context: &ImportContext, material: Material) -> Vec<Node> {
+    context
+        .doc
+        .nodes(context.graph)
+        .into_iter()
+        .filter(|node| {
+            node.mesh(context.graph).is_some_and(|mesh| {
+                mesh.primitives(context.graph)
+                    .iter()
+                    .any(|p| p.material(context.graph) == Some(material))
+            })
+        })
+        .collect()
+}
+
+/// Materials targeted by pointer channels.
+fn animated_materials(context: &ImportContext) -> HashSet<Material> {
+    context
+        .doc
+        .animations(context.graph)
+        .iter()
+        .flat_map(|a| a.channels(context.graph))
+        .filter_map(|c| c.get_extension::<KhrAnimationPointer>(context.graph))
+        .filter_map(|p| match p.target(context.graph) {
+            Some(PointerTarget::Material(m)) => Some(m),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Inserts [`AnimatedMaterial`] on primitives using an animated material,
+/// so they can be targeted by the curves from [`import_channel`].
+pub fn import_scene(context: &mut ImportContext, world: &mut World) {
+    #[cfg(feature = "khr_node_visibility")]
+    import_visibility_targets(context, world);
+
+    let materials = animated_materials(context);
+
+    if materials.is_empty() {
+        return;
+    }
+
+    let mut paths = HashMap::new();
+    for scene in context.doc.scenes(context.graph) {
+        for node in scene.nodes(context.graph) {
+            paths_recur(context.doc, context.graph, &[], node, &mut paths, node);
+        }
+    }
+
+    for material in materials {
+        let index = context
+            .doc
+            .material_index(context.graph, material)
+            .expect("index should exist for material");
+
+        let handles = [false, true]
+            .iter()
+            .filter_map(|inverted| context.materials.get(&(material, *inverted)))
+            .map(Handle::id)
+            .collect::<Vec<_>>();
+
+        let animated = animated_material(context, material);
+
+        for node in material_nodes(context, material) {
+            let (Some(entity), Some((_, path))) =
+                (context.node_entities.get(&node), paths.get(&node))
+            else {
+                continue;
+            };
+
+            let Some(animated_by) = world.get::<AnimatedBy>(*entity).copied() else {
+                continue;
+            };
+
+            let primitives = world
+                .get::<Children>(*entity)
+                .into_iter()
+                .flatten()
+                .copied()
+                .filter(|child| {
+                    world
+                        .get::<MeshMaterial3d<StandardMaterial>>(*child)
+                        .is_some_and(|m| handles.contains(&m.id()))
+                })
+                .collect::<Vec<_>>();
+
+            for primitive in primitives {
+                world.entity_mut(primitive).insert((
+                    animated.clone(),
+                    material_target_id(path, index),
+                    animated_by,
+                ));
+            }
+        }
+    }
+}
+
+/// Inserts [`NodeVisibility`] on nodes with an animated visible flag,
+/// so they can be targeted by the curves from [`import_channel`].
+#[cfg(feature = "khr_node_visibility")]
+fn import_visibility_targets(context: &ImportContext, world: &mut World) {
+    let nodes = context
+        .doc
+        .animations(context.graph)
+        .iter()
+        .flat_map(|a| a.channels(context.graph))
+        .filter_map(|c| c.get_extension::<KhrAnimationPointer>(context.graph))
+        .filter(|p| {
+            p.read(context.graph).pointer
+                == gltf_kun::extensions::khr_node_visibility::VISIBLE_POINTER
+        })
+        .filter_map(|p| match p.target(context.graph) {
+            Some(PointerTarget::Node(n)) => Some(n),
+            _ => None,
+        })
+        .collect::<HashSet<_>>();
+
+    for node in nodes {
+        let Some(entity) = context.node_entities.get(&node) else {
+            continue;
+        };
+
+        let mut entity = world.entity_mut(*entity);
+
+        if !entity.contains::<NodeVisibility>() {
+            entity.insert(NodeVisibility::default());
+        }
+    }
+}
+
+fn animated_material(context: &ImportContext, material: Material) -> AnimatedMaterial {
+    let weight = material.get(context.graph);
+
+    AnimatedMaterial {
+        base_color: Vec4::from_array(weight.base_color_factor),
+        emissive: Vec3::from_array(weight.emissive_factor),
+        metallic: weight.metallic_factor,
+        roughness: weight.roughness_factor,
+        alpha_cutoff: match weight.alpha_mode {
+            AlphaMode::Mask => weight.alpha_cutoff.0,
+            _ => AnimatedMaterial::default().alpha_cutoff,
+        },
+        ..default()
+    }
+}
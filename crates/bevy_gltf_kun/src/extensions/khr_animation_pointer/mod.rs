@@ -0,0 +1,107 @@
+//! Imports pointer channels targeting material factors, texture transforms,
+//! node transforms, node visibility and morph target weights.
+//!
+//! Pointers to cameras and lights, such as a camera's `yfov` or a light's `intensity`,
+//! are absolute and have no graph target to resolve, so they are skipped on import
+//! with a warning.
+
+use bevy::{
+    animation::AnimationTargetId, app::AnimationSystems, color::ColorToComponents, math::Affine2,
+    prelude::*,
+};
+
+pub mod export;
+pub mod import;
+
+pub const BASE_COLOR_POINTER: &str = "/pbrMetallicRoughness/baseColorFactor";
+pub const EMISSIVE_POINTER: &str = "/emissiveFactor";
+pub const METALLIC_POINTER: &str = "/pbrMetallicRoughness/metallicFactor";
+pub const ROUGHNESS_POINTER: &str = "/pbrMetallicRoughness/roughnessFactor";
+pub const ALPHA_CUTOFF_POINTER: &str = "/alphaCutoff";
+pub const UV_OFFSET_POINTER: &str =
+    "/pbrMetallicRoughness/baseColorTexture/extensions/KHR_texture_transform/offset";
+pub const UV_ROTATION_POINTER: &str =
+    "/pbrMetallicRoughness/baseColorTexture/extensions/KHR_texture_transform/rotation";
+pub const UV_SCALE_POINTER: &str =
+    "/pbrMetallicRoughness/baseColorTexture/extensions/KHR_texture_transform/scale";
+pub const WEIGHTS_POINTER: &str = "/weights";
+
+/// Animatable copy of a primitive's material properties.
+/// Bevy can only animate components, so curves targeting a material write here,
+/// and are copied to the [`StandardMaterial`] each frame.
+///
+/// Materials are shared assets, so every primitive using the material is affected.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub struct AnimatedMaterial {
+    /// Linear RGBA base color.
+    pub base_color: Vec4,
+    /// Linear RGB emissive color.
+    pub emissive: Vec3,
+    pub metallic: f32,
+    pub roughness: f32,
+    pub alpha_cutoff: f32,
+    pub uv_offset: Vec2,
+    pub uv_rotation: f32,
+    pub uv_scale: Vec2,
+}
+
+impl Default for AnimatedMaterial {
+    fn default() -> Self {
+        Self {
+            base_color: Vec4::ONE,
+            emissive: Vec3::ZERO,
+            metallic: 1.0,
+            roughness: 1.0,
+            alpha_cutoff: 0.5,
+            uv_offset: Vec2::ZERO,
+            uv_rotation: 0.0,
+            uv_scale: Vec2::ONE,
+        }
+    }
+}
+
+/// Animation target of the primitives using a material, under the node at `path`.
+pub fn material_target_id(path: &[Name], material_index: usize) -> AnimationTargetId {
+    let name = Name::new(format!("Material{material_index}"));
+    AnimationTargetId::from_names(path.iter().chain([&name]))
+}
+
+pub struct KhrAnimationPointerPlugin;
+
+impl Plugin for KhrAnimationPointerPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<AnimatedMaterial>()
+            .add_systems(PostUpdate, apply_animated_materials.after(AnimationSystems));
+    }
+}
+
+fn apply_animated_materials(
+    animated: Query<
+        (&AnimatedMaterial, &MeshMaterial3d<StandardMaterial>),
+        Changed<AnimatedMaterial>,
+    >,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for (animated, handle) in &animated {
+        let Some(material) = materials.get_mut(&handle.0) else {
+            continue;
+        };
+
+        material.base_color = LinearRgba::from_vec4(animated.base_color).into();
+        material.emissive = LinearRgba::from_vec3(animated.emissive);
+        material.metallic = animated.metallic;
+        material.perceptual_roughness = animated.roughness;
+
+        if let AlphaMode::Mask(_) = material.alpha_mode {
+            material.alpha_mode = AlphaMode::Mask(animated.alpha_cutoff);
+        }
+
+        // glTF texture transforms rotate counter-clockwise.
+        material.uv_transform = Affine2::from_scale_angle_translation(
+            animated.uv_scale,
+            -animated.uv_rotation,
+            animated.uv_offset,
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use bevy::{audio::PlaybackMode, ecs::system::RunSystemOnce, prelude::*};
+use gltf_kun::{
+    extensions::khr_audio_emitter::{
+        KhrAudioEmitter, add_scene_emitter,
+        audio_data::{AudioData, AudioDataWeight},
+        audio_emitter::{AudioEmitter, AudioEmitterWeight, EmitterType, PositionalEmitter},
+        audio_source::AudioSourceWeight,
+    },
+    graph::{Extensions, gltf::GltfDocument},
+};
+
+use crate::export::{extensions::BevyExtensionExport, gltf::ExportContext};
+
+impl BevyExtensionExport<GltfDocument> for KhrAudioEmitter {
+    fn bevy_export(In(context): In<ExportContext>, world: &mut World) -> ExportContext {
+        world
+            .run_system_once_with(export_audio_emitters, context)
+            .expect("export audio emitters")
+    }
+}
+
+/// Exports audio players on nodes as emitters.
+/// Spatial players are attached to their node, while non-spatial players are
+/// global emitters attached to the scene containing the node.
+pub fn export_audio_emitters(
+    In(mut context): In<ExportContext>,
+    players: Query<(&AudioPlayer, Option<&PlaybackSettings>)>,
+    parents: Query<&ChildOf>,
+    audio_sources: Res<Assets<AudioSource>>,
+) -> ExportContext {
+    let mut audio_cache = HashMap::<AssetId<AudioSource>, AudioData>::new();
+
+    for cached in &context.nodes {
+        let Ok((player, settings)) = players.get(cached.entity) else {
+            continue;
+        };
+
+        let Some(audio_source) = audio_sources.get(&player.0) else {
+            warn!("Audio source not loaded, skipping export");
+            continue;
+        };
+
+        let settings = settings.copied().unwrap_or_default();
+
+        let scene = if settings.spatial {
+            None
+        } else {
+            let Some(scene) = parents.iter_ancestors(cached.entity).find_map(|entity| {
+                context
+                    .scenes
+                    .iter()
+                    .find(|s| s.entity == entity)
+                    .map(|s| s.scene)
+            }) else {
+                warn!("Global audio player is not in an exported scene, skipping export");
+                continue;
+            };
+            Some(scene)
+        };
+
+        let ext = context
+            .doc
+            .get_extension::<KhrAudioEmitter>(&context.graph)
+            .unwrap_or_else(|| {
+                context
+                    .doc
+                    .create_extension::<KhrAudioEmitter>(&mut context.graph)
+            });
+
+        let audio = *audio_cache.entry(player.0.id()).or_insert_with(|| {
+            ext.create_audio(
+                &mut context.graph,
+                &AudioDataWeight {
+                    data: audio_source.bytes.to_vec(),
+                    ..Default::default()
+                },
+            )
+        });
+
+        let source = ext.create_source(
+            &mut context.graph,
+            &AudioSourceWeight {
+                name: None,
+                gain: settings.volume.to_linear(),
+                auto_play: !settings.paused,
+                looping: matches!(settings.mode, PlaybackMode::Loop),
+            },
+        );
+        source.set_audio(&mut context.graph, Some(audio));
+
+        let emitter = ext.create_emitter(
+            &mut context.graph,
+            &AudioEmitterWeight {
+                typ: if settings.spatial {
+                    EmitterType::Positional
+                } else {
+                    EmitterType::Global
+                },
+                positional: settings.spatial.then(PositionalEmitter::default),
+                ..Default::default()
+            },
+        );
+        emitter.add_source(&mut context.graph, source);
+
+        if let Some(scene) = scene {
+            add_scene_emitter(&mut context.graph, scene, emitter);
+        } else {
+            cached
+                .node
+                .add_extension::<AudioEmitter>(&mut context.graph, emitter);
+        }
+    }
+
+    context
+}
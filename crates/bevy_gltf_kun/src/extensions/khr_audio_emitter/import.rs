@@ -0,0 +1,127 @@
+use bevy::{
+    audio::{PlaybackMode, Volume},
+    prelude::*,
+};
+use gltf_kun::{
+    extensions::khr_audio_emitter::{
+        KhrAudioEmitter,
+        audio_data::AudioData,
+        audio_emitter::{AudioEmitter, EmitterType},
+        scene_emitters,
+    },
+    graph::{
+        ByteNode, Extensions,
+        gltf::{Scene, document::GltfDocument},
+    },
+};
+
+use crate::import::{extensions::NodeExtensionImport, gltf::document::ImportContext};
+
+impl NodeExtensionImport<GltfDocument> for AudioEmitter {
+    fn import_node(context: &mut ImportContext, entity: &mut EntityWorldMut, ext: Self) {
+        // An entity can only play one source, so extra sources are spawned as children.
+        let mut players = emitter_players(context, ext).into_iter();
+
+        if let Some(player) = players.next() {
+            entity.insert(player);
+        }
+
+        if players.len() > 0 {
+            entity.with_children(|parent| {
+                for player in players {
+                    parent.spawn(player);
+                }
+            });
+        }
+    }
+}
+
+/// Spawns the scene's emitters under the scene root.
+pub fn import_scene(context: &mut ImportContext, scene: Scene, world: &mut World) {
+    let emitters = scene_emitters(context.graph, scene);
+
+    if emitters.is_empty() {
+        return;
+    }
+
+    let Some(root) = context.scene_root else {
+        return;
+    };
+
+    for emitter in emitters {
+        for player in emitter_players(context, emitter) {
+            world.spawn((player, ChildOf(root)));
+        }
+    }
+}
+
+/// Builds a player for each source of an emitter.
+fn emitter_players(
+    context: &mut ImportContext,
+    emitter: AudioEmitter,
+) -> Vec<(AudioPlayer, PlaybackSettings)> {
+    let weight = emitter.read(context.graph);
+    let spatial = weight.typ == EmitterType::Positional;
+
+    emitter
+        .sources(context.graph)
+        .into_iter()
+        .filter_map(|source| {
+            let source_weight = source.read(context.graph);
+            let handle = audio_handle(context, source.audio(context.graph)?)?;
+
+            let settings = PlaybackSettings {
+                mode: if source_weight.looping {
+                    PlaybackMode::Loop
+                } else {
+                    PlaybackMode::Once
+                },
+                volume: Volume::Linear(weight.gain * source_weight.gain),
+                paused: !source_weight.auto_play,
+                spatial,
+                ..PlaybackSettings::ONCE
+            };
+
+            Some((AudioPlayer(handle), settings))
+        })
+        .collect()
+}
+
+fn audio_handle(context: &mut ImportContext, audio: AudioData) -> Option<Handle<AudioSource>> {
+    let ext = context
+        .doc
+        .get_extension::<KhrAudioEmitter>(context.graph)?;
+    let index = ext.audio(context.graph).iter().position(|a| *a == audio)?;
+
+    let label = audio_label(index);
+
+    if context.load_context.has_labeled_asset(label.clone()) {
+        return Some(context.load_context.get_label_handle(label));
+    }
+
+    let weight = audio.read(context.graph);
+
+    if weight.data.is_empty() {
+        // Data was not embedded, load it relative to the glTF file.
+        let uri = weight.uri?;
+
+        return match context.load_context.path().resolve_embed(&uri) {
+            Ok(path) => Some(context.load_context.load(path)),
+            Err(e) => {
+                warn!("Invalid audio URI {}: {}", uri, e);
+                None
+            }
+        };
+    }
+
+    Some(context.load_context.add_labeled_asset(
+        label,
+        AudioSource {
+            bytes: weight.data.into(),
+        },
+    ))
+}
+
+fn audio_label(index: usize) -> String {
+    format!("Audio{index}")
+}
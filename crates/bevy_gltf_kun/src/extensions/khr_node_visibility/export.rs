@@ -0,0 +1,54 @@
+use bevy::{ecs::system::RunSystemOnce, prelude::*};
+use gltf_kun::{
+    extensions::khr_node_visibility::{KhrNodeVisibility, weight::NodeVisibilityWeight},
+    graph::{ByteNode, Extensions, gltf::GltfDocument},
+};
+
+use crate::export::{extensions::BevyExtensionExport, gltf::ExportContext};
+
+impl BevyExtensionExport<GltfDocument> for KhrNodeVisibility {
+    fn bevy_export(In(context): In<ExportContext>, world: &mut World) -> ExportContext {
+        world
+            .run_system_once_with(export_visibility, context)
+            .expect("export visibility")
+    }
+}
+
+/// Exports hidden nodes.
+/// Visible nodes are left without the extension, as visible is the default.
+/// LOD levels are skipped, as their visibility is managed by the LOD.
+pub fn export_visibility(
+    In(mut context): In<ExportContext>,
+    visibilities: Query<&Visibility>,
+    #[cfg(feature = "msft_lod")] lods: Query<&crate::extensions::msft_lod::Lod>,
+) -> ExportContext {
+    #[cfg(feature = "msft_lod")]
+    let levels = lods
+        .iter()
+        .flat_map(|lod| lod.levels.iter().flatten().copied())
+        .collect::<Vec<_>>();
+
+    for cached in &context.nodes {
+        if visibilities.get(cached.entity) != Ok(&Visibility::Hidden) {
+            continue;
+        }
+
+        #[cfg(feature = "msft_lod")]
+        if levels.contains(&cached.entity) {
+            continue;
+        }
+
+        let ext = cached
+            .node
+            .get_extension::<KhrNodeVisibility>(&context.graph)
+            .unwrap_or_else(|| {
+                cached
+                    .node
+                    .create_extension::<KhrNodeVisibility>(&mut context.graph)
+            });
+
+        ext.write(&mut context.graph, &NodeVisibilityWeight { visible: false });
+    }
+
+    context
+}
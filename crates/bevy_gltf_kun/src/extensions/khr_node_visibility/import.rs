@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+use gltf_kun::{
+    extensions::khr_node_visibility::KhrNodeVisibility,
+    graph::{ByteNode, gltf::document::GltfDocument},
+};
+
+use crate::import::{extensions::NodeExtensionImport, gltf::document::ImportContext};
+
+use super::NodeVisibility;
+
+impl NodeExtensionImport<GltfDocument> for KhrNodeVisibility {
+    fn import_node(context: &mut ImportContext, entity: &mut EntityWorldMut, ext: Self) {
+        let weight = ext.read(context.graph);
+
+        entity.insert(NodeVisibility {
+            visible: weight.visible,
+        });
+
+        if !weight.visible {
+            entity.insert(Visibility::Hidden);
+        }
+    }
+}
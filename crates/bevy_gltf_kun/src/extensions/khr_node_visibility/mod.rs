@@ -0,0 +1,44 @@
+use bevy::{app::AnimationSystems, camera::visibility::VisibilitySystems, prelude::*};
+
+pub mod export;
+pub mod import;
+
+/// Animatable visible flag of a node.
+/// Bevy cannot animate [`Visibility`] directly, so curves targeting the flag write here,
+/// and it is copied to [`Visibility`] each frame.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub struct NodeVisibility {
+    pub visible: bool,
+}
+
+impl Default for NodeVisibility {
+    fn default() -> Self {
+        Self { visible: true }
+    }
+}
+
+pub struct KhrNodeVisibilityPlugin;
+
+impl Plugin for KhrNodeVisibilityPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<NodeVisibility>().add_systems(
+            PostUpdate,
+            apply_node_visibility
+                .after(AnimationSystems)
+                .before(VisibilitySystems::VisibilityPropagate),
+        );
+    }
+}
+
+fn apply_node_visibility(
+    mut nodes: Query<(&NodeVisibility, &mut Visibility), Changed<NodeVisibility>>,
+) {
+    for (node, mut visibility) in &mut nodes {
+        visibility.set_if_neq(if node.visible {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        });
+    }
+}
@@ -2,15 +2,61 @@ use bevy::prelude::*;
 
 pub use gltf_kun::extensions::DefaultExtensions;
 
+#[cfg(feature = "ext_lights_image_based")]
+pub mod ext_lights_image_based;
+#[cfg(feature = "ext_mesh_gpu_instancing")]
+pub mod ext_mesh_gpu_instancing;
+#[cfg(feature = "khr_animation_pointer")]
+pub mod khr_animation_pointer;
+#[cfg(feature = "khr_audio_emitter")]
+pub mod khr_audio_emitter;
+#[cfg(feature = "khr_node_visibility")]
+pub mod khr_node_visibility;
+#[cfg(feature = "msft_lod")]
+pub mod msft_lod;
+#[cfg(feature = "omi_link")]
+pub mod omi_link;
 #[cfg(feature = "omi_physics")]
 pub mod omi_physics;
+#[cfg(feature = "omi_seat")]
+pub mod omi_seat;
+#[cfg(feature = "omi_spawn_point")]
+pub mod omi_spawn_point;
+#[cfg(feature = "vrmc_spring_bone")]
+pub mod vrmc_spring_bone;
+#[cfg(feature = "vrmc_vrm")]
+pub mod vrmc_vrm;
 
 /// Adds support for extensions.
 pub struct ExtensionsPlugin;
 
 impl Plugin for ExtensionsPlugin {
     fn build(&self, app: &mut App) {
+        #[cfg(feature = "khr_animation_pointer")]
+        app.add_plugins(khr_animation_pointer::KhrAnimationPointerPlugin);
+
+        #[cfg(feature = "khr_node_visibility")]
+        app.add_plugins(khr_node_visibility::KhrNodeVisibilityPlugin);
+
+        #[cfg(feature = "msft_lod")]
+        app.add_plugins(msft_lod::MsftLodPlugin);
+
+        #[cfg(feature = "omi_link")]
+        app.add_plugins(omi_link::OmiLinkPlugin);
+
         #[cfg(feature = "omi_physics")]
         app.add_plugins(omi_physics::OmiPhysicsPlugin);
+
+        #[cfg(feature = "omi_seat")]
+        app.add_plugins(omi_seat::OmiSeatPlugin);
+
+        #[cfg(feature = "omi_spawn_point")]
+        app.add_plugins(omi_spawn_point::OmiSpawnPointPlugin);
+
+        #[cfg(feature = "vrmc_spring_bone")]
+        app.add_plugins(vrmc_spring_bone::VrmcSpringBonePlugin);
+
+        #[cfg(feature = "vrmc_vrm")]
+        app.add_plugins(vrmc_vrm::VrmcVrmPlugin);
     }
 }
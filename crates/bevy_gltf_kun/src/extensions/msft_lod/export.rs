@@ -0,0 +1,88 @@
+use bevy::{ecs::system::RunSystemOnce, prelude::*};
+use gltf_kun::{
+    extensions::msft_lod::{MsftLod, weight::MsftLodWeight},
+    graph::{ByteNode, Extensions, GraphNodeWeight, gltf::GltfDocument},
+};
+
+use crate::export::{extensions::BevyExtensionExport, gltf::ExportContext};
+
+use super::{Lod, LodMode};
+
+impl BevyExtensionExport<GltfDocument> for MsftLod {
+    fn bevy_export(In(context): In<ExportContext>, world: &mut World) -> ExportContext {
+        world
+            .run_system_once_with(export_lods, context)
+            .expect("export lods")
+    }
+}
+
+/// Exports [`Lod`] components.
+///
+/// Each lower level must be a single node entity.
+/// Levels are moved out of the node hierarchy, as required by `MSFT_lod`.
+/// Distance thresholds cannot be represented, and are not exported.
+pub fn export_lods(
+    In(mut context): In<ExportContext>,
+    lods: Query<(&Lod, &Transform)>,
+    transforms: Query<&Transform>,
+) -> ExportContext {
+    for i in 0..context.nodes.len() {
+        let cached = &context.nodes[i];
+        let (node, entity) = (cached.node, cached.entity);
+
+        let Ok((lod, parent_transform)) = lods.get(entity) else {
+            continue;
+        };
+
+        let mut levels = Vec::new();
+
+        for entities in lod.levels.iter().skip(1) {
+            let [level_entity] = entities.as_slice() else {
+                warn!("LOD levels must be a single entity to export");
+                break;
+            };
+
+            let Some(mut level) = context
+                .nodes
+                .iter()
+                .find(|c| c.entity == *level_entity)
+                .map(|c| c.node)
+            else {
+                warn!("LOD level {} is not an exported node", level_entity);
+                break;
+            };
+
+            node.remove_child(&mut context.graph, &level);
+
+            let transform = transforms
+                .get(*level_entity)
+                .map_or(*parent_transform, |t| *parent_transform * *t);
+
+            let weight = level.get_mut(&mut context.graph);
+            weight.translation = transform.translation.to_array().into();
+            weight.rotation = Quat::from_array(transform.rotation.to_array());
+            weight.scale = transform.scale.to_array().into();
+
+            levels.push(level);
+        }
+
+        let screen_coverage = match lod.mode {
+            LodMode::ScreenCoverage => lod
+                .thresholds
+                .iter()
+                .take(levels.len() + 2)
+                .copied()
+                .collect(),
+            LodMode::Distance => Vec::new(),
+        };
+
+        let ext = node
+            .get_extension::<MsftLod>(&context.graph)
+            .unwrap_or_else(|| node.create_extension::<MsftLod>(&mut context.graph));
+
+        ext.write(&mut context.graph, &MsftLodWeight { screen_coverage });
+        ext.set_levels(&mut context.graph, &levels);
+    }
+
+    context
+}
@@ -0,0 +1,79 @@
+use bevy::prelude::*;
+use gltf_kun::{
+    extensions::msft_lod::MsftLod,
+    graph::{
+        ByteNode, Extensions,
+        gltf::{GltfDocument, Node},
+    },
+};
+
+use crate::import::{extensions::NodeExtensionImport, gltf::document::ImportContext};
+
+use super::{DEFAULT_LOD_DISTANCE, Lod, LodMode};
+
+/// Lower levels of detail for a node, imported as children of it.
+pub fn lod_levels(context: &ImportContext, node: Node) -> Vec<Node> {
+    node.get_extension::<MsftLod>(context.graph)
+        .map(|ext| ext.levels(context.graph))
+        .unwrap_or_default()
+}
+
+impl NodeExtensionImport<GltfDocument> for MsftLod {
+    fn import_node(context: &mut ImportContext, entity: &mut EntityWorldMut, ext: Self) {
+        let levels = ext
+            .levels::<Node>(context.graph)
+            .iter()
+            .filter_map(|node| context.node_entities.get(node).copied())
+            .collect::<Vec<_>>();
+
+        let highest = entity
+            .get::<Children>()
+            .map(|children| {
+                children
+                    .iter()
+                    .filter(|c| !levels.contains(c))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        let parent_matrix = entity
+            .get::<Transform>()
+            .copied()
+            .unwrap_or_default()
+            .to_matrix();
+
+        entity.world_scope(|world| {
+            for level in &levels {
+                let mut level = world.entity_mut(*level);
+
+                // Level nodes replace the highest level node,
+                // so their transform is relative to its parent.
+                if let Some(mut transform) = level.get_mut::<Transform>() {
+                    *transform =
+                        Transform::from_matrix(parent_matrix.inverse() * transform.to_matrix());
+                }
+
+                level.insert(Visibility::Hidden);
+            }
+        });
+
+        let screen_coverage = ext.read(context.graph).screen_coverage;
+
+        let (mode, thresholds) = if screen_coverage.is_empty() {
+            let thresholds = (1..=levels.len())
+                .map(|i| i as f32 * DEFAULT_LOD_DISTANCE)
+                .collect();
+            (LodMode::Distance, thresholds)
+        } else {
+            (LodMode::ScreenCoverage, screen_coverage)
+        };
+
+        entity.insert(Lod {
+            levels: std::iter::once(highest)
+                .chain(levels.into_iter().map(|e| vec![e]))
+                .collect(),
+            mode,
+            thresholds,
+        });
+    }
+}
@@ -0,0 +1,134 @@
+use bevy::{camera::primitives::Aabb, prelude::*};
+
+pub mod export;
+pub mod import;
+
+/// Camera distance between levels, when no thresholds are given.
+pub const DEFAULT_LOD_DISTANCE: f32 = 10.0;
+
+/// Level of detail switching.
+/// Shows one level at a time, hiding the others.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct Lod {
+    /// Entities of each level, from highest to lowest detail.
+    pub levels: Vec<Vec<Entity>>,
+    pub mode: LodMode,
+    /// Threshold for each level.
+    /// An additional final value culls the object past that threshold.
+    pub thresholds: Vec<f32>,
+}
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Reflect)]
+pub enum LodMode {
+    /// Thresholds are the minimum fraction of the viewport height
+    /// covered by the highest level's bounding sphere.
+    #[default]
+    ScreenCoverage,
+    /// Thresholds are the maximum distance to the camera.
+    Distance,
+}
+
+impl Lod {
+    /// Returns the level to show for the given screen coverage or distance,
+    /// or `None` if the object is culled.
+    pub fn select_level(&self, value: f32) -> Option<usize> {
+        let passes = |threshold: f32| match self.mode {
+            LodMode::ScreenCoverage => value >= threshold,
+            LodMode::Distance => value <= threshold,
+        };
+
+        if let Some(level) = self
+            .thresholds
+            .iter()
+            .take(self.levels.len())
+            .position(|t| passes(*t))
+        {
+            return Some(level);
+        }
+
+        if self.thresholds.len() > self.levels.len() {
+            None
+        } else {
+            self.levels.len().checked_sub(1)
+        }
+    }
+}
+
+pub struct MsftLodPlugin;
+
+impl Plugin for MsftLodPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Lod>().add_systems(Update, update_lods);
+    }
+}
+
+fn update_lods(
+    cameras: Query<(&Camera, &GlobalTransform, &Projection)>,
+    lods: Query<(&Lod, &GlobalTransform)>,
+    bounds: Query<(&Aabb, &GlobalTransform)>,
+    mut visibilities: Query<&mut Visibility>,
+) {
+    let Some((_, camera_transform, projection)) =
+        cameras.iter().find(|(camera, _, _)| camera.is_active)
+    else {
+        return;
+    };
+
+    for (lod, transform) in &lods {
+        let center = transform.translation();
+        let distance = camera_transform.translation().distance(center);
+
+        let value = match lod.mode {
+            LodMode::Distance => distance,
+            LodMode::ScreenCoverage => {
+                let radius = bounding_radius(lod, center, &bounds);
+                screen_coverage(projection, radius, distance)
+            }
+        };
+
+        let selected = lod.select_level(value);
+
+        for (i, entities) in lod.levels.iter().enumerate() {
+            let visibility = if selected == Some(i) {
+                Visibility::Inherited
+            } else {
+                Visibility::Hidden
+            };
+
+            for entity in entities {
+                if let Ok(mut v) = visibilities.get_mut(*entity) {
+                    v.set_if_neq(visibility);
+                }
+            }
+        }
+    }
+}
+
+/// Radius of a sphere around `center` containing the highest level's bounds.
+fn bounding_radius(lod: &Lod, center: Vec3, bounds: &Query<(&Aabb, &GlobalTransform)>) -> f32 {
+    lod.levels
+        .first()
+        .into_iter()
+        .flatten()
+        .filter_map(|entity| bounds.get(*entity).ok())
+        .map(|(aabb, transform)| {
+            let scale = transform.scale().abs().max_element();
+            let aabb_center = transform.transform_point(aabb.center.into());
+            Vec3::from(aabb.half_extents)
+                .length()
+                .mul_add(scale, center.distance(aabb_center))
+        })
+        .reduce(f32::max)
+        .unwrap_or(0.5)
+}
+
+fn screen_coverage(projection: &Projection, radius: f32, distance: f32) -> f32 {
+    let coverage = match projection {
+        Projection::Orthographic(ortho) => 2.0 * radius / ortho.area.height(),
+        Projection::Perspective(perspective) => radius / (distance * (perspective.fov / 2.0).tan()),
+        Projection::Custom(_) => radius / (distance * std::f32::consts::FRAC_PI_8.tan()),
+    };
+
+    coverage.clamp(0.0, 1.0)
+}
@@ -0,0 +1,40 @@
+use bevy::{ecs::system::RunSystemOnce, prelude::*};
+use gltf_kun::{
+    extensions::omi_link::{OmiLink, weight::OmiLinkWeight},
+    graph::{ByteNode, Extensions, gltf::GltfDocument},
+};
+
+use crate::export::{extensions::BevyExtensionExport, gltf::ExportContext};
+
+use super::Link;
+
+impl BevyExtensionExport<GltfDocument> for OmiLink {
+    fn bevy_export(In(context): In<ExportContext>, world: &mut World) -> ExportContext {
+        world
+            .run_system_once_with(export_links, context)
+            .expect("export links")
+    }
+}
+
+pub fn export_links(In(mut context): In<ExportContext>, links: Query<&Link>) -> ExportContext {
+    for cached in &context.nodes {
+        let Ok(link) = links.get(cached.entity) else {
+            continue;
+        };
+
+        let ext = cached
+            .node
+            .get_extension::<OmiLink>(&context.graph)
+            .unwrap_or_else(|| cached.node.create_extension::<OmiLink>(&mut context.graph));
+
+        ext.write(
+            &mut context.graph,
+            &OmiLinkWeight {
+                uri: link.uri.clone(),
+                title: link.title.clone(),
+            },
+        );
+    }
+
+    context
+}
@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+use gltf_kun::{
+    extensions::omi_link::OmiLink,
+    graph::{ByteNode, gltf::document::GltfDocument},
+};
+
+use crate::import::{extensions::NodeExtensionImport, gltf::document::ImportContext};
+
+use super::Link;
+
+impl NodeExtensionImport<GltfDocument> for OmiLink {
+    fn import_node(context: &mut ImportContext, entity: &mut EntityWorldMut, ext: Self) {
+        let weight = ext.read(context.graph);
+
+        entity.insert(Link {
+            uri: weight.uri,
+            title: weight.title,
+        });
+    }
+}
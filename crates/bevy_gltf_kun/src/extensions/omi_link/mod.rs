@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+
+pub mod export;
+pub mod import;
+
+/// A hyperlink, such as a portal to another world.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct Link {
+    pub uri: String,
+    pub title: Option<String>,
+}
+
+pub struct OmiLinkPlugin;
+
+impl Plugin for OmiLinkPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Link>();
+    }
+}
@@ -4,7 +4,7 @@ use gltf_kun::{
     extensions::{
         omi_physics_body::{
             OmiPhysicsBody,
-            weight::{BodyType, Motion},
+            weight::{BodyType, CollisionFilter, CombineMode, Motion, PhysicsMaterial},
         },
         omi_physics_shape::{
             OmiPhysicsShape,
@@ -18,6 +18,8 @@ use gltf_kun::{
 
 use crate::export::{extensions::BevyExtensionExport, gltf::ExportContext};
 
+use super::import::CollisionSystems;
+
 impl BevyExtensionExport<GltfDocument> for OmiPhysicsBody {
     fn bevy_export(In(context): In<ExportContext>, world: &mut World) -> ExportContext {
         world
@@ -37,6 +39,9 @@ pub fn export_physics_bodies(
         &CenterOfMass,
     )>,
     colliders: Query<&Collider>,
+    materials: Query<(Option<&Friction>, Option<&Restitution>)>,
+    layers: Query<&CollisionLayers>,
+    systems: Option<Res<CollisionSystems>>,
 ) -> ExportContext {
     for cached in &context.nodes {
         let body = bodies.get(cached.entity);
@@ -83,6 +88,24 @@ pub fn export_physics_bodies(
             ext.write(&mut context.graph, &weight);
         }
 
+        let physics_material = materials
+            .get(cached.entity)
+            .ok()
+            .and_then(|(friction, restitution)| export_physics_material(friction, restitution));
+
+        let collision_filter = layers
+            .get(cached.entity)
+            .ok()
+            .filter(|layers| **layers != CollisionLayers::default())
+            .map(|layers| export_collision_layers(*layers, systems.as_deref()));
+
+        if physics_material.is_some() || collision_filter.is_some() {
+            let mut weight = ext.read(&context.graph);
+            weight.physics_material = physics_material;
+            weight.collision_filter = collision_filter;
+            ext.write(&mut context.graph, &weight);
+        }
+
         if let Ok(collider) = collider {
             let collider_shape = collider.shape();
 
@@ -135,3 +158,71 @@ pub fn export_physics_bodies(
 
     context
 }
+
+fn export_physics_material(
+    friction: Option<&Friction>,
+    restitution: Option<&Restitution>,
+) -> Option<PhysicsMaterial> {
+    if friction.is_none() && restitution.is_none() {
+        return None;
+    }
+
+    let mut material = PhysicsMaterial::default();
+
+    if let Some(friction) = friction {
+        material.dynamic_friction = friction.dynamic_coefficient;
+        material.static_friction = friction.static_coefficient;
+        material.friction_combine = export_combine_rule(friction.combine_rule);
+    }
+
+    if let Some(restitution) = restitution {
+        material.restitution = restitution.coefficient;
+        material.restitution_combine = export_combine_rule(restitution.combine_rule);
+    }
+
+    Some(material)
+}
+
+const fn export_combine_rule(rule: CoefficientCombine) -> Option<CombineMode> {
+    match rule {
+        CoefficientCombine::Average | CoefficientCombine::GeometricMean => None,
+        CoefficientCombine::Min => Some(CombineMode::Minimum),
+        CoefficientCombine::Max => Some(CombineMode::Maximum),
+        CoefficientCombine::Multiply => Some(CombineMode::Multiply),
+    }
+}
+
+fn export_collision_layers(
+    layers: CollisionLayers,
+    systems: Option<&CollisionSystems>,
+) -> CollisionFilter {
+    // The first bit is the default layer, which has no system name.
+    let names = |mask: LayerMask| {
+        (1..u32::BITS)
+            .filter(|bit| mask.0 & (1 << bit) != 0)
+            .map(|bit| {
+                systems
+                    .and_then(|s| s.name(bit))
+                    .map_or_else(|| format!("layer{bit}"), str::to_string)
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut filter = CollisionFilter {
+        collision_systems: names(layers.memberships),
+        ..Default::default()
+    };
+
+    if layers.filters != LayerMask::ALL {
+        let excluded = LayerMask(!layers.filters.0);
+
+        // Use whichever list is shorter.
+        if excluded.0.count_ones() < layers.filters.0.count_ones() {
+            filter.not_collide_with_systems = names(excluded);
+        } else {
+            filter.collide_with_systems = names(layers.filters);
+        }
+    }
+
+    filter
+}
@@ -2,7 +2,10 @@ use avian3d::prelude::*;
 use bevy::prelude::*;
 use gltf_kun::{
     extensions::{
-        omi_physics_body::{OmiPhysicsBody, weight::BodyType},
+        omi_physics_body::{
+            OmiPhysicsBody,
+            weight::{BodyType, CombineMode},
+        },
         omi_physics_shape::physics_shape::{
             BoxShape, CapsuleShape, CylinderShape, PhysicsShapeWeight, SphereShape,
         },
@@ -94,6 +97,158 @@ pub fn insert_rigid_bodies(mut commands: Commands, to_insert: Query<(Entity, &Ri
     }
 }
 
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct PhysicsMaterialMarker {
+    dynamic_friction: f32,
+    friction_combine: CombineRule,
+    restitution: f32,
+    restitution_combine: CombineRule,
+    static_friction: f32,
+}
+
+impl Default for PhysicsMaterialMarker {
+    fn default() -> Self {
+        Self {
+            dynamic_friction: 0.6,
+            friction_combine: CombineRule::Average,
+            restitution: 0.0,
+            restitution_combine: CombineRule::Average,
+            static_friction: 0.6,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Reflect)]
+pub enum CombineRule {
+    Average,
+    Minimum,
+    Maximum,
+    Multiply,
+}
+
+impl From<Option<CombineMode>> for CombineRule {
+    fn from(value: Option<CombineMode>) -> Self {
+        match value.unwrap_or_default() {
+            CombineMode::Average => Self::Average,
+            CombineMode::Minimum => Self::Minimum,
+            CombineMode::Maximum => Self::Maximum,
+            CombineMode::Multiply => Self::Multiply,
+        }
+    }
+}
+
+impl From<CombineRule> for CoefficientCombine {
+    fn from(value: CombineRule) -> Self {
+        match value {
+            CombineRule::Average => Self::Average,
+            CombineRule::Minimum => Self::Min,
+            CombineRule::Maximum => Self::Max,
+            CombineRule::Multiply => Self::Multiply,
+        }
+    }
+}
+
+pub fn insert_physics_materials(
+    mut commands: Commands,
+    to_insert: Query<(Entity, &PhysicsMaterialMarker)>,
+) {
+    for (entity, marker) in to_insert {
+        commands
+            .entity(entity)
+            .try_remove::<PhysicsMaterialMarker>()
+            .try_insert((
+                Friction {
+                    dynamic_coefficient: marker.dynamic_friction,
+                    static_coefficient: marker.static_friction,
+                    combine_rule: marker.friction_combine.into(),
+                },
+                Restitution {
+                    coefficient: marker.restitution,
+                    combine_rule: marker.restitution_combine.into(),
+                },
+            ));
+    }
+}
+
+#[derive(Component, Default, Reflect)]
+#[reflect(Component)]
+pub struct CollisionFilterMarker {
+    memberships: Vec<String>,
+    collide_with: Vec<String>,
+    not_collide_with: Vec<String>,
+}
+
+/// Maps named collision systems to [`LayerMask`] bits.
+/// Bits are assigned in the order systems are encountered, starting after
+/// [`LayerMask::DEFAULT`], which is used for colliders without any systems.
+#[derive(Default, Resource)]
+pub struct CollisionSystems {
+    names: Vec<String>,
+}
+
+impl CollisionSystems {
+    /// Maximum number of distinct collision systems.
+    pub const MAX_SYSTEMS: usize = u32::BITS as usize - 1;
+
+    /// Get the layer bit for a system, assigning a new one if needed.
+    pub fn layer(&mut self, name: &str) -> Option<u32> {
+        if let Some(i) = self.names.iter().position(|n| n == name) {
+            return Some(i as u32 + 1);
+        }
+
+        if self.names.len() >= Self::MAX_SYSTEMS {
+            warn!("Too many collision systems, ignoring {}", name);
+            return None;
+        }
+
+        self.names.push(name.to_string());
+        Some(self.names.len() as u32)
+    }
+
+    /// Get the name of the system assigned to a layer bit.
+    pub fn name(&self, layer: u32) -> Option<&str> {
+        let idx = layer.checked_sub(1)?;
+        self.names.get(idx as usize).map(String::as_str)
+    }
+
+    fn mask(&mut self, names: &[String]) -> LayerMask {
+        LayerMask(
+            names
+                .iter()
+                .filter_map(|n| self.layer(n))
+                .fold(0, |acc, bit| acc | (1 << bit)),
+        )
+    }
+}
+
+pub fn insert_collision_layers(
+    mut commands: Commands,
+    mut systems: ResMut<CollisionSystems>,
+    to_insert: Query<(Entity, &CollisionFilterMarker)>,
+) {
+    for (entity, marker) in to_insert {
+        let memberships = if marker.memberships.is_empty() {
+            LayerMask::DEFAULT
+        } else {
+            systems.mask(&marker.memberships)
+        };
+
+        let filters = if !marker.collide_with.is_empty() {
+            systems.mask(&marker.collide_with)
+        } else if !marker.not_collide_with.is_empty() {
+            LayerMask(!systems.mask(&marker.not_collide_with).0)
+        } else {
+            LayerMask::ALL
+        };
+
+        commands
+            .entity(entity)
+            .try_remove::<CollisionFilterMarker>()
+            .try_insert(CollisionLayers::new(memberships, filters));
+    }
+}
+
 impl NodeExtensionImport<GltfDocument> for OmiPhysicsBody {
     fn import_node(context: &mut ImportContext, entity: &mut EntityWorldMut, ext: Self) {
         if let Some(collider) = ext.collider(context.graph) {
@@ -125,6 +280,24 @@ impl NodeExtensionImport<GltfDocument> for OmiPhysicsBody {
 
         let weight = ext.read(context.graph);
 
+        if let Some(material) = weight.physics_material {
+            entity.insert(PhysicsMaterialMarker {
+                dynamic_friction: material.dynamic_friction,
+                friction_combine: material.friction_combine.into(),
+                restitution: material.restitution,
+                restitution_combine: material.restitution_combine.into(),
+                static_friction: material.static_friction,
+            });
+        }
+
+        if let Some(filter) = weight.collision_filter {
+            entity.insert(CollisionFilterMarker {
+                memberships: filter.collision_systems,
+                collide_with: filter.collide_with_systems,
+                not_collide_with: filter.not_collide_with_systems,
+            });
+        }
+
         if let Some(motion) = weight.motion {
             let typ = match motion.typ {
                 BodyType::Static => RigidBodyType::Static,
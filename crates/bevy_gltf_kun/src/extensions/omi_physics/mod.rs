@@ -7,11 +7,19 @@ pub struct OmiPhysicsPlugin;
 
 impl Plugin for OmiPhysicsPlugin {
     fn build(&self, app: &mut App) {
-        app.register_type::<import::ColliderMarker>()
+        app.init_resource::<import::CollisionSystems>()
+            .register_type::<import::ColliderMarker>()
+            .register_type::<import::CollisionFilterMarker>()
+            .register_type::<import::PhysicsMaterialMarker>()
             .register_type::<import::RigidBodyMarker>()
             .add_systems(
                 Update,
-                (import::insert_colliders, import::insert_rigid_bodies),
+                (
+                    import::insert_colliders,
+                    import::insert_collision_layers,
+                    import::insert_physics_materials,
+                    import::insert_rigid_bodies,
+                ),
             );
     }
 }
@@ -0,0 +1,42 @@
+use bevy::{ecs::system::RunSystemOnce, prelude::*};
+use gltf_kun::{
+    extensions::omi_seat::{OmiSeat, weight::OmiSeatWeight},
+    graph::{ByteNode, Extensions, gltf::GltfDocument},
+};
+
+use crate::export::{extensions::BevyExtensionExport, gltf::ExportContext};
+
+use super::Seat;
+
+impl BevyExtensionExport<GltfDocument> for OmiSeat {
+    fn bevy_export(In(context): In<ExportContext>, world: &mut World) -> ExportContext {
+        world
+            .run_system_once_with(export_seats, context)
+            .expect("export seats")
+    }
+}
+
+pub fn export_seats(In(mut context): In<ExportContext>, seats: Query<&Seat>) -> ExportContext {
+    for cached in &context.nodes {
+        let Ok(seat) = seats.get(cached.entity) else {
+            continue;
+        };
+
+        let ext = cached
+            .node
+            .get_extension::<OmiSeat>(&context.graph)
+            .unwrap_or_else(|| cached.node.create_extension::<OmiSeat>(&mut context.graph));
+
+        ext.write(
+            &mut context.graph,
+            &OmiSeatWeight {
+                back: seat.back.to_array(),
+                foot: seat.foot.to_array(),
+                knee: seat.knee.to_array(),
+                angle: seat.angle,
+            },
+        );
+    }
+
+    context
+}
@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+use gltf_kun::{
+    extensions::omi_seat::OmiSeat,
+    graph::{ByteNode, gltf::document::GltfDocument},
+};
+
+use crate::import::{extensions::NodeExtensionImport, gltf::document::ImportContext};
+
+use super::Seat;
+
+impl NodeExtensionImport<GltfDocument> for OmiSeat {
+    fn import_node(context: &mut ImportContext, entity: &mut EntityWorldMut, ext: Self) {
+        let weight = ext.read(context.graph);
+
+        entity.insert(Seat {
+            back: weight.back.into(),
+            foot: weight.foot.into(),
+            knee: weight.knee.into(),
+            angle: weight.angle,
+        });
+    }
+}
@@ -0,0 +1,37 @@
+use std::f32::consts::FRAC_PI_2;
+
+use bevy::prelude::*;
+
+pub mod export;
+pub mod import;
+
+/// A seat, with IK targets for the seated character.
+/// Targets are in the local space of the seat entity.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub struct Seat {
+    pub back: Vec3,
+    pub foot: Vec3,
+    pub knee: Vec3,
+    /// Angle between the spine and the back-knee line, in radians.
+    pub angle: f32,
+}
+
+impl Default for Seat {
+    fn default() -> Self {
+        Self {
+            back: Vec3::ZERO,
+            foot: Vec3::ZERO,
+            knee: Vec3::ZERO,
+            angle: FRAC_PI_2,
+        }
+    }
+}
+
+pub struct OmiSeatPlugin;
+
+impl Plugin for OmiSeatPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Seat>();
+    }
+}
@@ -0,0 +1,48 @@
+use bevy::{ecs::system::RunSystemOnce, prelude::*};
+use gltf_kun::{
+    extensions::omi_spawn_point::{OmiSpawnPoint, weight::OmiSpawnPointWeight},
+    graph::{ByteNode, Extensions, gltf::GltfDocument},
+};
+
+use crate::export::{extensions::BevyExtensionExport, gltf::ExportContext};
+
+use super::SpawnPoint;
+
+impl BevyExtensionExport<GltfDocument> for OmiSpawnPoint {
+    fn bevy_export(In(context): In<ExportContext>, world: &mut World) -> ExportContext {
+        world
+            .run_system_once_with(export_spawn_points, context)
+            .expect("export spawn points")
+    }
+}
+
+pub fn export_spawn_points(
+    In(mut context): In<ExportContext>,
+    spawn_points: Query<&SpawnPoint>,
+) -> ExportContext {
+    for cached in &context.nodes {
+        let Ok(spawn_point) = spawn_points.get(cached.entity) else {
+            continue;
+        };
+
+        let ext = cached
+            .node
+            .get_extension::<OmiSpawnPoint>(&context.graph)
+            .unwrap_or_else(|| {
+                cached
+                    .node
+                    .create_extension::<OmiSpawnPoint>(&mut context.graph)
+            });
+
+        ext.write(
+            &mut context.graph,
+            &OmiSpawnPointWeight {
+                title: spawn_point.title.clone(),
+                team: spawn_point.team.clone(),
+                group: spawn_point.group.clone(),
+            },
+        );
+    }
+
+    context
+}
@@ -0,0 +1,21 @@
+use bevy::prelude::*;
+use gltf_kun::{
+    extensions::omi_spawn_point::OmiSpawnPoint,
+    graph::{ByteNode, gltf::document::GltfDocument},
+};
+
+use crate::import::{extensions::NodeExtensionImport, gltf::document::ImportContext};
+
+use super::SpawnPoint;
+
+impl NodeExtensionImport<GltfDocument> for OmiSpawnPoint {
+    fn import_node(context: &mut ImportContext, entity: &mut EntityWorldMut, ext: Self) {
+        let weight = ext.read(context.graph);
+
+        entity.insert(SpawnPoint {
+            title: weight.title,
+            team: weight.team,
+            group: weight.group,
+        });
+    }
+}
@@ -0,0 +1,21 @@
+use bevy::prelude::*;
+
+pub mod export;
+pub mod import;
+
+/// A location where players can spawn.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct SpawnPoint {
+    pub title: Option<String>,
+    pub team: Option<String>,
+    pub group: Option<String>,
+}
+
+pub struct OmiSpawnPointPlugin;
+
+impl Plugin for OmiSpawnPointPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<SpawnPoint>();
+    }
+}
@@ -0,0 +1,102 @@
+use bevy::prelude::*;
+use gltf_kun::{
+    extensions::vrmc_spring_bone::{
+        VrmcSpringBone,
+        collider::{ColliderGroup, ColliderShape},
+        spring::Spring,
+    },
+    graph::{ByteNode, Extensions},
+};
+
+use crate::import::gltf::document::ImportContext;
+
+use super::{SpringBoneCollider, SpringBoneJoint, SpringBones, SpringChain, SpringColliderShape};
+
+/// Inserts [`SpringBones`] on the scene root.
+pub fn import_scene(context: &mut ImportContext, world: &mut World) {
+    let Some(ext) = context.doc.get_extension::<VrmcSpringBone>(context.graph) else {
+        return;
+    };
+
+    let Some(root) = context.scene_root else {
+        return;
+    };
+
+    let chains = ext
+        .springs(context.graph)
+        .into_iter()
+        .map(|spring| import_spring(context, spring))
+        .filter(|chain| chain.joints.len() > 1)
+        .collect();
+
+    world.entity_mut(root).insert(SpringBones(chains));
+}
+
+fn import_spring(context: &ImportContext, spring: Spring) -> SpringChain {
+    let joints = spring
+        .joints(context.graph)
+        .into_iter()
+        .map_while(|joint| {
+            let node = joint.node(context.graph)?;
+            let entity = context.node_entities.get(&node)?;
+            let weight = joint.read(context.graph);
+
+            Some(SpringBoneJoint::new(
+                *entity,
+                weight.hit_radius,
+                weight.stiffness,
+                weight.gravity_power,
+                Vec3::from_array(weight.gravity_dir),
+                weight.drag_force,
+            ))
+        })
+        .collect();
+
+    let colliders = spring
+        .collider_groups(context.graph)
+        .into_iter()
+        .flat_map(|group| import_collider_group(context, group))
+        .collect();
+
+    SpringChain {
+        name: spring.read(context.graph).name,
+        joints,
+        colliders,
+        center: spring
+            .center(context.graph)
+            .and_then(|node| context.node_entities.get(&node).copied()),
+    }
+}
+
+fn import_collider_group(context: &ImportContext, group: ColliderGroup) -> Vec<SpringBoneCollider> {
+    group
+        .colliders(context.graph)
+        .into_iter()
+        .filter_map(|collider| {
+            let node = collider.node(context.graph)?;
+            let entity = context.node_entities.get(&node)?;
+
+            let shape = match collider.read(context.graph).shape {
+                ColliderShape::Sphere { offset, radius } => SpringColliderShape {
+                    offset: Vec3::from_array(offset),
+                    radius,
+                    tail: None,
+                },
+                ColliderShape::Capsule {
+                    offset,
+                    radius,
+                    tail,
+                } => SpringColliderShape {
+                    offset: Vec3::from_array(offset),
+                    radius,
+                    tail: Some(Vec3::from_array(tail)),
+                },
+            };
+
+            Some(SpringBoneCollider {
+                entity: *entity,
+                shape,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,252 @@
+use bevy::{app::AnimationSystems, math::Affine3A, prelude::*, transform::TransformSystems};
+
+pub mod import;
+
+/// Spring bone chains of a VRM avatar, simulated each frame.
+/// Inserted on the scene root.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct SpringBones(pub Vec<SpringChain>);
+
+#[derive(Clone, Debug, Default, Reflect)]
+pub struct SpringChain {
+    pub name: Option<String>,
+    /// Joints from root to tail.
+    /// The last joint is only used as the tail of the previous one.
+    pub joints: Vec<SpringBoneJoint>,
+    pub colliders: Vec<SpringBoneCollider>,
+    /// Entity whose space the simulation runs in, so its movement does not cause inertia.
+    pub center: Option<Entity>,
+}
+
+#[derive(Clone, Debug, Reflect)]
+pub struct SpringBoneJoint {
+    pub entity: Entity,
+    pub hit_radius: f32,
+    pub stiffness: f32,
+    pub gravity_power: f32,
+    pub gravity_dir: Vec3,
+    pub drag_force: f32,
+    #[reflect(ignore)]
+    state: Option<JointState>,
+}
+
+impl SpringBoneJoint {
+    pub const fn new(
+        entity: Entity,
+        hit_radius: f32,
+        stiffness: f32,
+        gravity_power: f32,
+        gravity_dir: Vec3,
+        drag_force: f32,
+    ) -> Self {
+        Self {
+            entity,
+            hit_radius,
+            stiffness,
+            gravity_power,
+            gravity_dir,
+            drag_force,
+            state: None,
+        }
+    }
+
+    /// Clears the simulation state, so the joint restarts from its current pose.
+    pub const fn reset(&mut self) {
+        self.state = None;
+    }
+}
+
+/// Simulation state, initialized from the pose on the first update.
+#[derive(Clone, Debug)]
+struct JointState {
+    initial_rotation: Quat,
+    bone_axis: Vec3,
+    length: f32,
+    /// Tail positions, in center space.
+    current_tail: Vec3,
+    prev_tail: Vec3,
+}
+
+#[derive(Clone, Debug, Reflect)]
+pub struct SpringBoneCollider {
+    pub entity: Entity,
+    pub shape: SpringColliderShape,
+}
+
+/// Collider shape, in the local space of its entity.
+/// A sphere, or a capsule if it has a tail.
+#[derive(Clone, Debug, Default, Reflect)]
+pub struct SpringColliderShape {
+    pub offset: Vec3,
+    pub radius: f32,
+    pub tail: Option<Vec3>,
+}
+
+pub struct VrmcSpringBonePlugin;
+
+impl Plugin for VrmcSpringBonePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<SpringBones>().add_systems(
+            PostUpdate,
+            update_spring_bones
+                .after(AnimationSystems)
+                .before(TransformSystems::Propagate),
+        );
+    }
+}
+
+/// A collider shape in world space.
+struct WorldCollider {
+    start: Vec3,
+    end: Vec3,
+    radius: f32,
+}
+
+impl WorldCollider {
+    fn new(transform: &GlobalTransform, shape: &SpringColliderShape) -> Self {
+        let start = transform.transform_point(shape.offset);
+
+        Self {
+            start,
+            end: shape
+                .tail
+                .map_or(start, |tail| transform.transform_point(tail)),
+            radius: shape.radius,
+        }
+    }
+
+    /// Returns the point pushed out of the collider, if it is inside.
+    fn push_out(&self, point: Vec3, hit_radius: f32) -> Option<Vec3> {
+        let segment = self.end - self.start;
+        let t = if segment.length_squared() > 0.0 {
+            ((point - self.start).dot(segment) / segment.length_squared()).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        let closest = self.start + segment * t;
+        let offset = point - closest;
+        let radius = self.radius + hit_radius;
+
+        if offset.length_squared() >= radius * radius {
+            return None;
+        }
+
+        Some(closest + offset.normalize_or(Vec3::Y) * radius)
+    }
+}
+
+fn update_spring_bones(
+    time: Res<Time>,
+    mut roots: Query<&mut SpringBones>,
+    mut transforms: Query<&mut Transform>,
+    globals: Query<&GlobalTransform>,
+    parents: Query<&ChildOf>,
+) {
+    let delta = time.delta_secs();
+
+    if delta <= 0.0 {
+        return;
+    }
+
+    for mut bones in &mut roots {
+        for chain in &mut bones.0 {
+            update_chain(chain, delta, &mut transforms, &globals, &parents);
+        }
+    }
+}
+
+fn update_chain(
+    chain: &mut SpringChain,
+    delta: f32,
+    transforms: &mut Query<&mut Transform>,
+    globals: &Query<&GlobalTransform>,
+    parents: &Query<&ChildOf>,
+) {
+    let Some(first) = chain.joints.first() else {
+        return;
+    };
+
+    let mut parent_world = parents
+        .get(first.entity)
+        .ok()
+        .and_then(|p| globals.get(p.parent()).ok())
+        .copied()
+        .unwrap_or_default();
+
+    let center = chain
+        .center
+        .and_then(|e| globals.get(e).ok())
+        .map_or(Affine3A::IDENTITY, GlobalTransform::affine);
+    let center_inverse = center.inverse();
+
+    let colliders = chain
+        .colliders
+        .iter()
+        .filter_map(|c| {
+            let transform = globals.get(c.entity).ok()?;
+            Some(WorldCollider::new(transform, &c.shape))
+        })
+        .collect::<Vec<_>>();
+
+    for i in 0..chain.joints.len().saturating_sub(1) {
+        let Ok(tail_local) = transforms
+            .get(chain.joints[i + 1].entity)
+            .map(|t| t.translation)
+        else {
+            return;
+        };
+
+        let joint = &mut chain.joints[i];
+
+        let Ok(mut transform) = transforms.get_mut(joint.entity) else {
+            return;
+        };
+
+        let state = joint.state.get_or_insert_with(|| {
+            let world = parent_world.mul_transform(*transform);
+            let head = world.translation();
+            let tail = world.transform_point(tail_local);
+            let tail_center = center_inverse.transform_point3(tail);
+
+            JointState {
+                initial_rotation: transform.rotation,
+                bone_axis: tail_local.normalize_or(Vec3::Y),
+                length: head.distance(tail),
+                current_tail: tail_center,
+                prev_tail: tail_center,
+            }
+        });
+
+        let head = parent_world.transform_point(transform.translation);
+        let rest_rotation = parent_world.rotation() * state.initial_rotation;
+        let rest_dir = rest_rotation * state.bone_axis;
+
+        let current = center.transform_point3(state.current_tail);
+        let prev = center.transform_point3(state.prev_tail);
+
+        let mut next = current
+            + (current - prev) * (1.0 - joint.drag_force)
+            + rest_dir * joint.stiffness * delta
+            + joint.gravity_dir * joint.gravity_power * delta;
+
+        // Keep the bone length.
+        next = head + (next - head).normalize_or(rest_dir) * state.length;
+
+        for collider in &colliders {
+            if let Some(point) = collider.push_out(next, joint.hit_radius) {
+                next = head + (point - head).normalize_or(rest_dir) * state.length;
+            }
+        }
+
+        state.prev_tail = center_inverse.transform_point3(current);
+        state.current_tail = center_inverse.transform_point3(next);
+
+        let dir = (next - head).normalize_or(rest_dir);
+        let world_rotation = Quat::from_rotation_arc(rest_dir, dir) * rest_rotation;
+        transform.rotation = parent_world.rotation().inverse() * world_rotation;
+
+        parent_world = parent_world.mul_transform(*transform);
+    }
+}
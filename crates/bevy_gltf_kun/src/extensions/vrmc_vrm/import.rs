@@ -0,0 +1,69 @@
+use bevy::{platform::collections::HashMap, prelude::*};
+use gltf_kun::{
+    extensions::vrmc_vrm::VrmcVrm,
+    graph::{ByteNode, Extensions},
+};
+
+use crate::import::gltf::document::ImportContext;
+
+use super::{HumanoidBones, VrmExpression, VrmExpressions, VrmMorphTargetBind};
+
+/// Inserts [`HumanoidBones`] and [`VrmExpressions`] on the scene root.
+pub fn import_scene(context: &mut ImportContext, world: &mut World) {
+    let Some(ext) = context.doc.get_extension::<VrmcVrm>(context.graph) else {
+        return;
+    };
+
+    let Some(root) = context.scene_root else {
+        return;
+    };
+
+    let bones = ext
+        .humanoid_bones(context.graph)
+        .into_iter()
+        .filter_map(|b| {
+            let node = b.node(context.graph)?;
+            let entity = context.node_entities.get(&node)?;
+            Some((b.read(context.graph).bone, *entity))
+        })
+        .collect::<HashMap<_, _>>();
+
+    let expressions = ext
+        .expressions(context.graph)
+        .into_iter()
+        .map(|expression| {
+            let weight = expression.read(context.graph);
+
+            let binds = expression
+                .morph_target_binds(context.graph)
+                .into_iter()
+                .filter_map(|bind| {
+                    let node = bind.node(context.graph)?;
+                    let entity = context.node_entities.get(&node)?;
+                    let bind_weight = bind.read(context.graph);
+
+                    Some(VrmMorphTargetBind {
+                        entity: *entity,
+                        index: bind_weight.index,
+                        weight: bind_weight.weight,
+                    })
+                })
+                .collect();
+
+            VrmExpression {
+                name: weight.name,
+                preset: weight.preset,
+                is_binary: weight.is_binary,
+                override_blink: weight.override_blink.into(),
+                override_look_at: weight.override_look_at.into(),
+                override_mouth: weight.override_mouth.into(),
+                binds,
+                weight: 0.0,
+            }
+        })
+        .collect();
+
+    world
+        .entity_mut(root)
+        .insert((HumanoidBones(bones), VrmExpressions(expressions)));
+}
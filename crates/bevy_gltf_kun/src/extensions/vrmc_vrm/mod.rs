@@ -0,0 +1,189 @@
+use bevy::{app::AnimationSystems, platform::collections::HashMap, prelude::*};
+use gltf_kun::extensions::vrmc_vrm::expression::ExpressionOverride;
+
+pub use gltf_kun::extensions::vrmc_vrm::human_bone::HumanBone;
+
+pub mod import;
+
+const BLINK_EXPRESSIONS: [&str; 3] = ["blink", "blinkLeft", "blinkRight"];
+const LOOK_AT_EXPRESSIONS: [&str; 4] = ["lookUp", "lookDown", "lookLeft", "lookRight"];
+const MOUTH_EXPRESSIONS: [&str; 5] = ["aa", "ih", "ou", "ee", "oh"];
+
+/// Maps each human bone of a VRM avatar to its entity.
+/// Inserted on the scene root.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct HumanoidBones(pub HashMap<HumanBone, Entity>);
+
+impl HumanoidBones {
+    pub fn get(&self, bone: HumanBone) -> Option<Entity> {
+        self.0.get(&bone).copied()
+    }
+}
+
+/// Expressions of a VRM avatar.
+/// Inserted on the scene root.
+///
+/// Set an expression's weight to drive the morph targets it is bound to.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct VrmExpressions(pub Vec<VrmExpression>);
+
+impl VrmExpressions {
+    pub fn get(&self, name: &str) -> Option<&VrmExpression> {
+        self.0.iter().find(|e| e.name == name)
+    }
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut VrmExpression> {
+        self.0.iter_mut().find(|e| e.name == name)
+    }
+
+    /// Returns the weight of an expression, or 0 if it does not exist.
+    pub fn weight(&self, name: &str) -> f32 {
+        self.get(name).map_or(0.0, |e| e.weight)
+    }
+    /// Sets the weight of an expression, if it exists.
+    pub fn set_weight(&mut self, name: &str, weight: f32) {
+        if let Some(expression) = self.get_mut(name) {
+            expression.weight = weight;
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Reflect)]
+pub struct VrmExpression {
+    pub name: String,
+    pub preset: bool,
+    pub is_binary: bool,
+    pub override_blink: VrmExpressionOverride,
+    pub override_look_at: VrmExpressionOverride,
+    pub override_mouth: VrmExpressionOverride,
+    pub binds: Vec<VrmMorphTargetBind>,
+    /// Current weight, from 0 to 1.
+    pub weight: f32,
+}
+
+impl VrmExpression {
+    fn applied_weight(&self) -> f32 {
+        let weight = self.weight.clamp(0.0, 1.0);
+
+        if self.is_binary {
+            if weight > 0.5 { 1.0 } else { 0.0 }
+        } else {
+            weight
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Reflect)]
+pub enum VrmExpressionOverride {
+    #[default]
+    None,
+    Block,
+    Blend,
+}
+
+impl From<ExpressionOverride> for VrmExpressionOverride {
+    fn from(value: ExpressionOverride) -> Self {
+        match value {
+            ExpressionOverride::None => Self::None,
+            ExpressionOverride::Block => Self::Block,
+            ExpressionOverride::Blend => Self::Blend,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Reflect)]
+pub struct VrmMorphTargetBind {
+    /// Entity with the [`MorphWeights`] to drive.
+    pub entity: Entity,
+    pub index: usize,
+    pub weight: f32,
+}
+
+pub struct VrmcVrmPlugin;
+
+impl Plugin for VrmcVrmPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<HumanoidBones>()
+            .register_type::<VrmExpressions>()
+            .add_systems(PostUpdate, apply_expressions.after(AnimationSystems));
+    }
+}
+
+/// Multipliers for the blink, look-at and mouth expressions,
+/// from the overrides of the other active expressions.
+fn override_factors(expressions: &VrmExpressions) -> [f32; 3] {
+    let mut factors = [1.0_f32; 3];
+
+    for expression in &expressions.0 {
+        let weight = expression.applied_weight();
+
+        if weight <= 0.0 {
+            continue;
+        }
+
+        let overrides = [
+            expression.override_blink,
+            expression.override_look_at,
+            expression.override_mouth,
+        ];
+
+        for (factor, value) in factors.iter_mut().zip(overrides) {
+            match value {
+                VrmExpressionOverride::None => {}
+                VrmExpressionOverride::Block => *factor = 0.0,
+                VrmExpressionOverride::Blend => *factor *= 1.0 - weight,
+            }
+        }
+    }
+
+    factors
+}
+
+fn apply_expressions(
+    expressions: Query<&VrmExpressions>,
+    mut morph_weights: Query<&mut MorphWeights>,
+) {
+    for expressions in &expressions {
+        let [blink, look_at, mouth] = override_factors(expressions);
+
+        // Reset bound weights, so expressions can be summed.
+        for bind in expressions.0.iter().flat_map(|e| &e.binds) {
+            if let Ok(mut weights) = morph_weights.get_mut(bind.entity)
+                && let Some(w) = weights.weights_mut().get_mut(bind.index)
+            {
+                *w = 0.0;
+            }
+        }
+
+        for expression in &expressions.0 {
+            let name = expression.name.as_str();
+
+            let factor = if !expression.preset {
+                1.0
+            } else if BLINK_EXPRESSIONS.contains(&name) {
+                blink
+            } else if LOOK_AT_EXPRESSIONS.contains(&name) {
+                look_at
+            } else if MOUTH_EXPRESSIONS.contains(&name) {
+                mouth
+            } else {
+                1.0
+            };
+
+            let weight = expression.applied_weight() * factor;
+
+            if weight <= 0.0 {
+                continue;
+            }
+
+            for bind in &expression.binds {
+                if let Ok(mut weights) = morph_weights.get_mut(bind.entity)
+                    && let Some(w) = weights.weights_mut().get_mut(bind.index)
+                {
+                    *w += bind.weight * weight;
+                }
+            }
+        }
+    }
+}
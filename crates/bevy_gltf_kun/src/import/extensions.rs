@@ -46,12 +46,41 @@ impl BevyExtensionImport<GltfDocument> for DefaultExtensions {
     }
 
     fn import_node(context: &mut ImportContext, entity: &mut EntityWorldMut, node: Node) {
+        #[cfg(feature = "ext_mesh_gpu_instancing")]
+        gltf_kun::extensions::ext_mesh_gpu_instancing::ExtMeshGpuInstancing::try_import_node(
+            context, entity, node,
+        );
+
+        #[cfg(feature = "khr_audio_emitter")]
+        gltf_kun::extensions::khr_audio_emitter::audio_emitter::AudioEmitter::try_import_node(
+            context, entity, node,
+        );
+
+        #[cfg(feature = "khr_node_visibility")]
+        gltf_kun::extensions::khr_node_visibility::KhrNodeVisibility::try_import_node(
+            context, entity, node,
+        );
+
+        #[cfg(feature = "msft_lod")]
+        gltf_kun::extensions::msft_lod::MsftLod::try_import_node(context, entity, node);
+
+        #[cfg(feature = "omi_link")]
+        gltf_kun::extensions::omi_link::OmiLink::try_import_node(context, entity, node);
+
         #[cfg(feature = "omi_physics")]
         {
             gltf_kun::extensions::omi_physics_body::OmiPhysicsBody::try_import_node(
                 context, entity, node,
             );
         }
+
+        #[cfg(feature = "omi_seat")]
+        gltf_kun::extensions::omi_seat::OmiSeat::try_import_node(context, entity, node);
+
+        #[cfg(feature = "omi_spawn_point")]
+        gltf_kun::extensions::omi_spawn_point::OmiSpawnPoint::try_import_node(
+            context, entity, node,
+        );
     }
 
     fn import_primitive(
@@ -61,5 +90,24 @@ impl BevyExtensionImport<GltfDocument> for DefaultExtensions {
     ) {
     }
     fn import_root(_context: &mut ImportContext) {}
-    fn import_scene(_context: &mut ImportContext, _scene: Scene, _world: &mut World) {}
+    #[cfg_attr(
+        not(any(feature = "ext_lights_image_based", feature = "khr_audio_emitter")),
+        allow(unused_variables)
+    )]
+    fn import_scene(context: &mut ImportContext, scene: Scene, world: &mut World) {
+        #[cfg(feature = "ext_lights_image_based")]
+        crate::extensions::ext_lights_image_based::import::import_scene(context, scene, world);
+
+        #[cfg(feature = "khr_audio_emitter")]
+        crate::extensions::khr_audio_emitter::import::import_scene(context, scene, world);
+
+        #[cfg(feature = "khr_animation_pointer")]
+        crate::extensions::khr_animation_pointer::import::import_scene(context, world);
+
+        #[cfg(feature = "vrmc_spring_bone")]
+        crate::extensions::vrmc_spring_bone::import::import_scene(context, world);
+
+        #[cfg(feature = "vrmc_vrm")]
+        crate::extensions::vrmc_vrm::import::import_scene(context, world);
+    }
 }
@@ -19,6 +19,9 @@ use gltf_kun::graph::{
     },
 };
 
+#[cfg(feature = "khr_animation_pointer")]
+use gltf_kun::{extensions::khr_animation_pointer::KhrAnimationPointer, graph::Extensions};
+
 use super::{document::ImportContext, node::node_name};
 
 /// Raw animation curve data before conversion to Bevy's `AnimatableCurve`.
@@ -99,6 +102,27 @@ pub fn import_animation(
 
         let output_iter = output.to_iter(context.graph)?;
 
+        #[cfg(feature = "khr_animation_pointer")]
+        if let Some(pointer) = channel.get_extension::<KhrAnimationPointer>(context.graph) {
+            match crate::extensions::khr_animation_pointer::import::import_channel(
+                context,
+                paths,
+                pointer,
+                &keyframe_timestamps,
+                output_iter,
+                sampler_weight.interpolation,
+            ) {
+                Ok(curves) => {
+                    for c in curves {
+                        roots.insert(c.root);
+                        clip.add_variable_curve_to_target(c.target, c.curve);
+                    }
+                }
+                Err(e) => warn!("Failed to import pointer channel: {}", e),
+            }
+            continue;
+        }
+
         let (maybe_curve, raw_data) = match &channel_weight.path {
             TargetPath::Translation => {
                 let translations: Vec<Vec3> = match output_iter {
@@ -1,6 +1,6 @@
 use bevy::platform::collections::{HashMap, HashSet};
 use bevy::{asset::LoadContext, mesh::skinning::SkinnedMeshInverseBindposes, prelude::*};
-use gltf_kun::graph::gltf::{Material, Skin};
+use gltf_kun::graph::gltf::{Material, Node, Skin};
 use gltf_kun::graph::{Graph, gltf::GltfDocument};
 use thiserror::Error;
 
@@ -31,6 +31,10 @@ pub struct ImportContext<'a, 'b> {
 
     pub skin_matrices: HashMap<Skin, Handle<SkinnedMeshInverseBindposes>>,
     pub materials: HashMap<(Material, bool), Handle<StandardMaterial>>,
+    /// Entities of the nodes in the scene currently being imported.
+    pub node_entities: HashMap<Node, Entity>,
+    /// Root entity of the scene currently being imported.
+    pub scene_root: Option<Entity>,
 }
 
 pub fn import_gltf_document<E: BevyExtensionImport<GltfDocument>>(
@@ -73,6 +73,11 @@ pub enum GltfError {
     ReadAssetBytesError(#[from] ReadAssetBytesError),
     #[error("failed to parse gltf: {0}")]
     SerdeJson(#[from] serde_json::Error),
+    #[cfg(feature = "khr_materials_pbr_specular_glossiness")]
+    #[error("failed to convert specular-glossiness materials: {0}")]
+    SpecularGlossiness(
+        #[from] gltf_kun::extensions::khr_materials_pbr_specular_glossiness::convert::ConvertError,
+    ),
 }
 
 impl<E> AssetLoader for GltfLoader<E>
@@ -102,13 +107,18 @@ where
             let mut graph = Graph::default();
 
             let format = GltfFormat {
-                json: serde_json::from_slice(&bytes)?,
+                json: GltfFormat::parse_json(&bytes)?,
                 resources: std::collections::HashMap::new(),
             };
             let resolver = BevyAssetResolver { load_context };
 
             let mut doc = GltfImport::<E>::import(&mut graph, format, Some(resolver)).await?;
 
+            #[cfg(feature = "khr_materials_pbr_specular_glossiness")]
+            gltf_kun::extensions::khr_materials_pbr_specular_glossiness::convert::convert(
+                &mut graph, &doc,
+            )?;
+
             let mut gltf = GltfKun::new(&mut graph, &mut doc);
 
             let mut context = ImportContext {
@@ -120,6 +130,8 @@ where
 
                 materials: HashMap::default(),
                 skin_matrices: HashMap::default(),
+                node_entities: HashMap::default(),
+                scene_root: None,
             };
 
             import_gltf_document::<E>(&mut context)?;
@@ -163,6 +175,11 @@ where
 
             let mut doc = GlbImport::<E>::import_slice(&mut graph, &bytes).await?;
 
+            #[cfg(feature = "khr_materials_pbr_specular_glossiness")]
+            gltf_kun::extensions::khr_materials_pbr_specular_glossiness::convert::convert(
+                &mut graph, &doc,
+            )?;
+
             let mut gltf = GltfKun::new(&mut graph, &mut doc);
 
             let mut context = ImportContext {
@@ -174,6 +191,8 @@ where
 
                 materials: HashMap::default(),
                 skin_matrices: HashMap::default(),
+                node_entities: HashMap::default(),
+                scene_root: None,
             };
 
             import_gltf_document::<E>(&mut context)?;
@@ -33,6 +33,7 @@ pub enum ImportNodeError {
     MorphBuildEror(#[from] MorphBuildError),
 }
 
+#[allow(clippy::too_many_lines)]
 pub fn import_node<E: BevyExtensionImport<GltfDocument>, S: BuildHasher>(
     context: &mut ImportContext<'_, '_>,
     node_entities: &mut HashMap<Handle<GltfNode>, Entity, S>,
@@ -126,6 +127,23 @@ pub fn import_node<E: BevyExtensionImport<GltfDocument>, S: BuildHasher>(
                 warn!("Failed to import node: {}", e);
             }
         }
+
+        // Lower levels of detail are not part of the node tree, so import them as children.
+        #[cfg(feature = "msft_lod")]
+        for mut level in crate::extensions::msft_lod::import::lod_levels(context, *n) {
+            if let Err(e) = import_node::<E, _>(
+                context,
+                node_entities,
+                node_primitive_entities,
+                parent,
+                &world_transform,
+                path.clone(),
+                Some(root_node),
+                &mut level,
+            ) {
+                warn!("Failed to import LOD node: {}", e);
+            }
+        }
     });
 
     let node = GltfNode {
@@ -143,6 +161,7 @@ pub fn import_node<E: BevyExtensionImport<GltfDocument>, S: BuildHasher>(
     context.gltf.nodes.insert(index, handle.clone());
 
     node_entities.insert(handle.clone(), ent.id());
+    context.node_entities.insert(*n, ent.id());
     node_primitive_entities.insert(handle.clone(), primitive_entities);
 
     // Load extensions.
@@ -34,7 +34,9 @@ pub fn import_scene<E: BevyExtensionImport<GltfDocument>>(
     let mut node_primitive_entities = HashMap::<Handle<GltfNode>, Vec<Entity>>::default();
     let mut root_nodes = Vec::new();
 
-    world
+    context.node_entities.clear();
+
+    let root = world
         .spawn((Transform::default(), Visibility::default()))
         .with_children(|parent| {
             for mut node in s.nodes(context.graph) {
@@ -56,7 +58,10 @@ pub fn import_scene<E: BevyExtensionImport<GltfDocument>>(
                     }
                 }
             }
-        });
+        })
+        .id();
+
+    context.scene_root = Some(root);
 
     for node in context.doc.nodes(context.graph) {
         if animation_roots.contains(&node) {
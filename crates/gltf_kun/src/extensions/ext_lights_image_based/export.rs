@@ -0,0 +1,101 @@
+use std::error::Error;
+
+use crate::{
+    extensions::ExtensionExport,
+    graph::{ByteNode, Extensions, Graph, gltf::document::GltfDocument},
+    io::format::gltf::GltfFormat,
+};
+
+use super::{
+    EXTENSION_NAME, ExtLightsImageBased,
+    json::{LightJson, RootExtension, SceneExtension},
+    light::ImageBasedLight,
+};
+
+impl ExtensionExport<GltfDocument, GltfFormat> for ExtLightsImageBased {
+    fn export(
+        graph: &mut Graph,
+        doc: &GltfDocument,
+        format: &mut GltfFormat,
+    ) -> Result<(), Box<dyn Error>> {
+        let Some(ext) = doc.get_extension::<Self>(graph) else {
+            return Ok(());
+        };
+
+        let lights = ext.lights(graph);
+
+        if lights.is_empty() {
+            return Ok(());
+        }
+
+        let images = doc.images(graph);
+
+        let root_lights = lights
+            .iter()
+            .map(|light| {
+                let weight = light.read(graph);
+
+                let specular_images = light
+                    .specular_images(graph)
+                    .iter()
+                    .map_while(|faces| {
+                        let faces = faces.map(|f| images.iter().position(|x| *x == f));
+                        faces
+                            .iter()
+                            .all(Option::is_some)
+                            .then(|| faces.map(|f| f.expect("face should exist")))
+                    })
+                    .collect();
+
+                LightJson {
+                    name: weight.name,
+                    rotation: weight.rotation,
+                    intensity: weight.intensity,
+                    irradiance_coefficients: weight.irradiance_coefficients,
+                    specular_image_size: weight.specular_image_size,
+                    specular_images,
+                }
+            })
+            .collect();
+
+        for (i, scene) in doc.scenes(graph).iter().enumerate() {
+            let Some(light) = scene
+                .get_extension::<ImageBasedLight>(graph)
+                .and_then(|l| lights.iter().position(|x| *x == l))
+            else {
+                continue;
+            };
+
+            let scene = format
+                .json
+                .scenes
+                .get_mut(i)
+                .expect("Scene index out of bounds");
+
+            scene
+                .extensions
+                .get_or_insert_with(gltf::json::extensions::scene::Scene::default)
+                .others
+                .insert(
+                    EXTENSION_NAME.to_string(),
+                    serde_json::to_value(SceneExtension { light })?,
+                );
+        }
+
+        let extensions = format
+            .json
+            .extensions
+            .get_or_insert_with(gltf::json::extensions::Root::default);
+
+        extensions.others.insert(
+            EXTENSION_NAME.to_string(),
+            serde_json::to_value(RootExtension {
+                lights: root_lights,
+            })?,
+        );
+
+        format.json.extensions_used.push(EXTENSION_NAME.to_string());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+use std::error::Error;
+
+use tracing::warn;
+
+use crate::{
+    extensions::ExtensionImport,
+    graph::{Extensions, Graph, gltf::document::GltfDocument},
+    io::format::gltf::GltfFormat,
+};
+
+use super::{
+    EXTENSION_NAME, ExtLightsImageBased,
+    json::{RootExtension, SceneExtension},
+    light::ImageBasedLightWeight,
+};
+
+impl ExtensionImport<GltfDocument, GltfFormat> for ExtLightsImageBased {
+    fn supported_extensions() -> Vec<&'static str> {
+        vec![EXTENSION_NAME]
+    }
+
+    fn import(
+        graph: &mut Graph,
+        format: &mut GltfFormat,
+        doc: &GltfDocument,
+    ) -> Result<(), Box<dyn Error>> {
+        let Some(value) = format
+            .json
+            .extensions
+            .as_ref()
+            .and_then(|e| e.others.get(EXTENSION_NAME))
+        else {
+            return Ok(());
+        };
+
+        let root = serde_json::from_value::<RootExtension>(value.clone())?;
+
+        let ext = doc
+            .get_extension::<Self>(graph)
+            .unwrap_or_else(|| doc.create_extension::<Self>(graph));
+
+        let images = doc.images(graph);
+
+        let lights = root
+            .lights
+            .into_iter()
+            .map(|json| {
+                let light = ext.create_light(
+                    graph,
+                    &ImageBasedLightWeight {
+                        name: json.name,
+                        rotation: json.rotation,
+                        intensity: json.intensity,
+                        irradiance_coefficients: json.irradiance_coefficients,
+                        specular_image_size: json.specular_image_size,
+                    },
+                );
+
+                // Stop at the first mip with a missing image, to keep the chain valid.
+                let mips = json
+                    .specular_images
+                    .iter()
+                    .map_while(|faces| {
+                        let faces = faces.map(|i| images.get(i).copied());
+                        if faces.iter().any(Option::is_none) {
+                            warn!("Specular image index out of bounds");
+                            return None;
+                        }
+                        Some(faces.map(|f| f.expect("face should exist")))
+                    })
+                    .collect::<Vec<_>>();
+
+                light.set_specular_images(graph, &mips);
+
+                light
+            })
+            .collect::<Vec<_>>();
+
+        let scenes = doc.scenes(graph);
+
+        for (i, scene) in format.json.scenes.iter().enumerate() {
+            let Some(value) = scene
+                .extensions
+                .as_ref()
+                .and_then(|e| e.others.get(EXTENSION_NAME))
+            else {
+                continue;
+            };
+
+            let json = serde_json::from_value::<SceneExtension>(value.clone())?;
+
+            let Some(light) = lights.get(json.light) else {
+                warn!("Image based light index {} out of bounds", json.light);
+                continue;
+            };
+
+            let scene = scenes.get(i).expect("Scene index out of bounds");
+            scene.add_extension(graph, *light);
+        }
+
+        Ok(())
+    }
+}
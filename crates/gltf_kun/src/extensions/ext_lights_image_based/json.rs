@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+
+use super::light::CUBE_FACES;
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct RootExtension {
+    pub lights: Vec<LightJson>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LightJson {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default = "default_rotation")]
+    pub rotation: [f32; 4],
+    #[serde(default = "default_intensity")]
+    pub intensity: f32,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub irradiance_coefficients: Vec<[f32; 3]>,
+    pub specular_image_size: u32,
+    pub specular_images: Vec<[usize; CUBE_FACES]>,
+}
+
+const fn default_rotation() -> [f32; 4] {
+    [0.0, 0.0, 0.0, 1.0]
+}
+
+const fn default_intensity() -> f32 {
+    1.0
+}
+
+#[derive(Copy, Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SceneExtension {
+    pub light: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn light_serde() {
+        let json = r#"{
+            "lights": [{
+                "intensity": 2.0,
+                "irradianceCoefficients": [[0.1, 0.2, 0.3], [0.0, 0.0, 0.0]],
+                "specularImageSize": 256,
+                "specularImages": [[0, 1, 2, 3, 4, 5], [6, 7, 8, 9, 10, 11]]
+            }]
+        }"#;
+
+        let root = serde_json::from_str::<RootExtension>(json).expect("json should deserialize");
+        let light = &root.lights[0];
+
+        assert!((light.rotation[3] - 1.0).abs() < f32::EPSILON);
+        assert!((light.intensity - 2.0).abs() < f32::EPSILON);
+        assert_eq!(light.irradiance_coefficients.len(), 2);
+        assert_eq!(light.specular_images[1][0], 6);
+
+        let json_2 = serde_json::to_string(&root).expect("json should serialize");
+        let root_2 =
+            serde_json::from_str::<RootExtension>(&json_2).expect("json should deserialize");
+        assert_eq!(root, root_2);
+    }
+}
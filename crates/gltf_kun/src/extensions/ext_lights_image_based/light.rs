@@ -0,0 +1,115 @@
+use petgraph::graph::NodeIndex;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    extensions::Extension,
+    graph::{ByteNode, Graph, OtherEdgeHelpers, Weight, gltf::image::Image},
+};
+
+use super::EXTENSION_NAME;
+
+pub const SPECULAR_EDGE: &str = "EXT_lights_image_based/light/specular";
+
+/// Number of faces in a cubemap, in the order +X, -X, +Y, -Y, +Z, -Z.
+pub const CUBE_FACES: usize = 6;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ImageBasedLightWeight {
+    pub name: Option<String>,
+    /// Quaternion rotation of the light, as `[x, y, z, w]`.
+    pub rotation: [f32; 4],
+    pub intensity: f32,
+    /// Spherical harmonic coefficients for irradiance, up to l=2.
+    pub irradiance_coefficients: Vec<[f32; 3]>,
+    /// Size of the largest specular mip level.
+    pub specular_image_size: u32,
+}
+
+impl Default for ImageBasedLightWeight {
+    fn default() -> Self {
+        Self {
+            name: None,
+            rotation: [0.0, 0.0, 0.0, 1.0],
+            intensity: 1.0,
+            irradiance_coefficients: Vec::new(),
+            specular_image_size: 0,
+        }
+    }
+}
+
+impl From<&Vec<u8>> for ImageBasedLightWeight {
+    fn from(bytes: &Vec<u8>) -> Self {
+        if bytes.is_empty() {
+            return Self::default();
+        }
+        serde_json::from_slice(bytes).expect("Failed to deserialize weight")
+    }
+}
+
+impl From<&ImageBasedLightWeight> for Vec<u8> {
+    fn from(value: &ImageBasedLightWeight) -> Self {
+        serde_json::to_vec(value).expect("Failed to serialize weight")
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ImageBasedLight(pub NodeIndex);
+
+impl From<NodeIndex> for ImageBasedLight {
+    fn from(index: NodeIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl From<ImageBasedLight> for NodeIndex {
+    fn from(light: ImageBasedLight) -> Self {
+        light.0
+    }
+}
+
+impl ByteNode<ImageBasedLightWeight> for ImageBasedLight {}
+impl OtherEdgeHelpers for ImageBasedLight {}
+
+impl Extension for ImageBasedLight {
+    fn name() -> &'static str {
+        EXTENSION_NAME
+    }
+}
+
+impl ImageBasedLight {
+    pub fn new(graph: &mut Graph, weight: &ImageBasedLightWeight) -> Self {
+        let index = graph.add_node(Weight::Bytes(weight.into()));
+        Self(index)
+    }
+
+    /// Specular cubemap faces for each mip level, from largest to smallest.
+    pub fn specular_images(&self, graph: &Graph) -> Vec<[Image; CUBE_FACES]> {
+        let images = (0..)
+            .map_while(|i| self.find_property::<Image>(graph, &specular_edge(i)))
+            .collect::<Vec<_>>();
+
+        images
+            .chunks_exact(CUBE_FACES)
+            .map(|faces| faces.try_into().expect("chunk should have 6 faces"))
+            .collect()
+    }
+    pub fn set_specular_images(&self, graph: &mut Graph, mips: &[[Image; CUBE_FACES]]) {
+        let mut i = 0;
+        while self
+            .find_other_edges(graph, &specular_edge(i))
+            .next()
+            .is_some()
+        {
+            self.set_property::<NodeIndex>(graph, specular_edge(i), None);
+            i += 1;
+        }
+
+        for (i, image) in mips.iter().flatten().enumerate() {
+            self.add_property(graph, specular_edge(i), *image);
+        }
+    }
+}
+
+fn specular_edge(index: usize) -> String {
+    format!("{SPECULAR_EDGE}/{index}")
+}
@@ -0,0 +1,65 @@
+//! [EXT_lights_image_based](https://github.com/KhronosGroup/glTF/tree/main/extensions/2.0/Vendor/EXT_lights_image_based)
+//! extension.
+
+use petgraph::graph::NodeIndex;
+
+use crate::graph::{Graph, OtherEdgeHelpers};
+
+use self::light::{ImageBasedLight, ImageBasedLightWeight};
+
+use super::Extension;
+
+pub mod export;
+pub mod import;
+pub mod json;
+pub mod light;
+
+pub const EXTENSION_NAME: &str = "EXT_lights_image_based";
+
+pub const LIGHT_EDGE: &str = "EXT_lights_image_based/light";
+
+/// Document-level image based lights.
+/// Scenes reference a light by adding it as an extension.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ExtLightsImageBased(pub NodeIndex);
+
+impl From<NodeIndex> for ExtLightsImageBased {
+    fn from(index: NodeIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl From<ExtLightsImageBased> for NodeIndex {
+    fn from(lights: ExtLightsImageBased) -> Self {
+        lights.0
+    }
+}
+
+impl OtherEdgeHelpers for ExtLightsImageBased {}
+
+impl Extension for ExtLightsImageBased {
+    fn name() -> &'static str {
+        EXTENSION_NAME
+    }
+}
+
+impl ExtLightsImageBased {
+    pub fn lights(&self, graph: &Graph) -> Vec<ImageBasedLight> {
+        self.find_properties(graph, LIGHT_EDGE)
+    }
+    pub fn add_light(&self, graph: &mut Graph, light: ImageBasedLight) {
+        self.add_property(graph, LIGHT_EDGE.to_string(), light);
+    }
+    pub fn remove_light(&self, graph: &mut Graph, light: ImageBasedLight) {
+        self.remove_property(graph, LIGHT_EDGE, light);
+    }
+    pub fn create_light(
+        &self,
+        graph: &mut Graph,
+        weight: &ImageBasedLightWeight,
+    ) -> ImageBasedLight {
+        let light = ImageBasedLight::new(graph, weight);
+        self.add_light(graph, light);
+        light
+    }
+}
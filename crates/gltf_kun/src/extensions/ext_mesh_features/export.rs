@@ -0,0 +1,77 @@
+use std::error::Error;
+
+use crate::{
+    extensions::ExtensionExport,
+    graph::{ByteNode, Extensions, Graph, gltf::document::GltfDocument},
+    io::format::gltf::GltfFormat,
+};
+
+use super::{
+    EXTENSION_NAME, ExtMeshFeatures,
+    json::{FeatureIdJson, FeatureIdTextureJson, PrimitiveExtension},
+};
+
+impl ExtensionExport<GltfDocument, GltfFormat> for ExtMeshFeatures {
+    fn export(
+        graph: &mut Graph,
+        doc: &GltfDocument,
+        format: &mut GltfFormat,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut used = false;
+
+        for (mesh, mesh_json) in doc.meshes(graph).iter().zip(format.json.meshes.iter_mut()) {
+            let primitives = mesh.primitives(graph);
+
+            for (primitive, primitive_json) in
+                primitives.iter().zip(mesh_json.primitives.iter_mut())
+            {
+                let Some(ext) = primitive.get_extension::<Self>(graph) else {
+                    continue;
+                };
+
+                let feature_ids = ext
+                    .feature_ids(graph)
+                    .iter()
+                    .map(|feature_id| {
+                        let weight = feature_id.read(graph);
+
+                        let texture = feature_id
+                            .texture(graph)
+                            .and_then(|t| doc.texture_index(graph, t))
+                            .map(|index| FeatureIdTextureJson {
+                                index,
+                                tex_coord: weight.tex_coord,
+                                channels: weight.channels,
+                            });
+
+                        FeatureIdJson {
+                            feature_count: weight.feature_count,
+                            null_feature_id: weight.null_feature_id,
+                            label: weight.label,
+                            attribute: weight.attribute,
+                            texture,
+                            property_table: weight.property_table,
+                        }
+                    })
+                    .collect();
+
+                primitive_json
+                    .extensions
+                    .get_or_insert_with(gltf::json::extensions::mesh::Primitive::default)
+                    .others
+                    .insert(
+                        EXTENSION_NAME.to_string(),
+                        serde_json::to_value(PrimitiveExtension { feature_ids })?,
+                    );
+
+                used = true;
+            }
+        }
+
+        if used {
+            format.json.extensions_used.push(EXTENSION_NAME.to_string());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+use petgraph::graph::NodeIndex;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::graph::{
+    ByteNode, Graph, OtherEdgeHelpers, Weight,
+    gltf::{
+        Primitive, Texture,
+        accessor::iter::{AccessorElement, AccessorIterCreateError},
+        primitive::Semantic,
+    },
+};
+
+pub const TEXTURE_EDGE: &str = "EXT_mesh_features/feature_id/texture";
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct FeatureIdWeight {
+    /// Number of unique features, excluding the null feature ID.
+    pub feature_count: u32,
+    /// Value indicating that no feature is associated with a vertex or texel.
+    pub null_feature_id: Option<u32>,
+    pub label: Option<String>,
+    /// Set index of the `_FEATURE_ID_n` vertex attribute.
+    pub attribute: Option<u32>,
+    /// Texture coordinate set of the feature ID texture.
+    pub tex_coord: u32,
+    /// Texture channels containing feature IDs, from least to most significant byte.
+    pub channels: Vec<u8>,
+    /// Index of a property table in the `EXT_structural_metadata` extension.
+    pub property_table: Option<usize>,
+}
+
+impl Default for FeatureIdWeight {
+    fn default() -> Self {
+        Self {
+            feature_count: 0,
+            null_feature_id: None,
+            label: None,
+            attribute: None,
+            tex_coord: 0,
+            channels: vec![0],
+            property_table: None,
+        }
+    }
+}
+
+impl From<&Vec<u8>> for FeatureIdWeight {
+    fn from(bytes: &Vec<u8>) -> Self {
+        if bytes.is_empty() {
+            return Self::default();
+        }
+        serde_json::from_slice(bytes).expect("Failed to deserialize weight")
+    }
+}
+
+impl From<&FeatureIdWeight> for Vec<u8> {
+    fn from(value: &FeatureIdWeight) -> Self {
+        serde_json::to_vec(value).expect("Failed to serialize weight")
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ReadFeatureIdsError {
+    #[error("primitive has no {0:?} attribute")]
+    MissingAttribute(Semantic),
+    #[error(transparent)]
+    AccessorIter(#[from] AccessorIterCreateError),
+    #[error("feature ID attribute must be a scalar, got {0:?}")]
+    InvalidElement(AccessorElement),
+    #[error("feature IDs are stored in a texture")]
+    Texture,
+}
+
+/// A set of feature IDs, stored in a vertex attribute, a texture,
+/// or implicitly as the vertex index.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct FeatureId(pub NodeIndex);
+
+impl From<NodeIndex> for FeatureId {
+    fn from(index: NodeIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl From<FeatureId> for NodeIndex {
+    fn from(feature_id: FeatureId) -> Self {
+        feature_id.0
+    }
+}
+
+impl ByteNode<FeatureIdWeight> for FeatureId {}
+impl OtherEdgeHelpers for FeatureId {}
+
+impl FeatureId {
+    pub fn new(graph: &mut Graph, weight: &FeatureIdWeight) -> Self {
+        let index = graph.add_node(Weight::Bytes(weight.into()));
+        Self(index)
+    }
+
+    pub fn texture(&self, graph: &Graph) -> Option<Texture> {
+        self.find_property(graph, TEXTURE_EDGE)
+    }
+    pub fn set_texture(&self, graph: &mut Graph, texture: Option<Texture>) {
+        self.set_property(graph, TEXTURE_EDGE.to_string(), texture);
+    }
+
+    /// Reads the feature ID of each vertex in the primitive.
+    pub fn read_ids(
+        &self,
+        graph: &Graph,
+        primitive: Primitive,
+    ) -> Result<Vec<u32>, ReadFeatureIdsError> {
+        if self.texture(graph).is_some() {
+            return Err(ReadFeatureIdsError::Texture);
+        }
+
+        let Some(set) = self.read(graph).attribute else {
+            let count = primitive
+                .attribute(graph, Semantic::Positions)
+                .map(|a| a.count(graph))
+                .unwrap_or_default();
+            return Ok((0..count as u32).collect());
+        };
+
+        let semantic = feature_id_semantic(set);
+        let accessor = primitive
+            .attribute(graph, semantic.clone())
+            .ok_or(ReadFeatureIdsError::MissingAttribute(semantic))?;
+
+        accessor
+            .to_iter(graph)?
+            .elements()
+            .map(|element| match element {
+                AccessorElement::U8(id) => Ok(u32::from(id)),
+                AccessorElement::U16(id) => Ok(u32::from(id)),
+                AccessorElement::U32(id) => Ok(id),
+                // Float IDs are exact for any ID that fits in the mantissa.
+                AccessorElement::F32(id) => Ok(id as u32),
+                element => Err(ReadFeatureIdsError::InvalidElement(element)),
+            })
+            .collect()
+    }
+}
+
+/// Vertex attribute semantic for the feature ID set, `_FEATURE_ID_n`.
+pub fn feature_id_semantic(set: u32) -> Semantic {
+    Semantic::Extras(format!("FEATURE_ID_{set}"))
+}
@@ -0,0 +1,73 @@
+use std::error::Error;
+
+use tracing::warn;
+
+use crate::{
+    extensions::ExtensionImport,
+    graph::{Extensions, Graph, gltf::document::GltfDocument},
+    io::format::gltf::GltfFormat,
+};
+
+use super::{
+    EXTENSION_NAME, ExtMeshFeatures, feature_id::FeatureIdWeight, json::PrimitiveExtension,
+};
+
+impl ExtensionImport<GltfDocument, GltfFormat> for ExtMeshFeatures {
+    fn supported_extensions() -> Vec<&'static str> {
+        vec![EXTENSION_NAME]
+    }
+
+    fn import(
+        graph: &mut Graph,
+        format: &mut GltfFormat,
+        doc: &GltfDocument,
+    ) -> Result<(), Box<dyn Error>> {
+        let meshes = doc.meshes(graph);
+        let textures = doc.textures(graph);
+
+        for (mesh, mesh_json) in meshes.iter().zip(format.json.meshes.iter()) {
+            let primitives = mesh.primitives(graph);
+
+            for (primitive, primitive_json) in primitives.iter().zip(mesh_json.primitives.iter()) {
+                let Some(value) = primitive_json
+                    .extensions
+                    .as_ref()
+                    .and_then(|e| e.others.get(EXTENSION_NAME))
+                else {
+                    continue;
+                };
+
+                let json = serde_json::from_value::<PrimitiveExtension>(value.clone())?;
+
+                let ext = primitive.create_extension::<Self>(graph);
+
+                for feature_id_json in json.feature_ids {
+                    let texture = feature_id_json.texture.as_ref();
+
+                    let feature_id = ext.create_feature_id(
+                        graph,
+                        &FeatureIdWeight {
+                            feature_count: feature_id_json.feature_count,
+                            null_feature_id: feature_id_json.null_feature_id,
+                            label: feature_id_json.label,
+                            attribute: feature_id_json.attribute,
+                            tex_coord: texture.map(|t| t.tex_coord).unwrap_or_default(),
+                            channels: texture.map_or_else(|| vec![0], |t| t.channels.clone()),
+                            property_table: feature_id_json.property_table,
+                        },
+                    );
+
+                    if let Some(texture) = texture {
+                        if let Some(t) = textures.get(texture.index) {
+                            feature_id.set_texture(graph, Some(*t));
+                        } else {
+                            warn!("Feature ID texture index {} out of bounds", texture.index);
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrimitiveExtension {
+    pub feature_ids: Vec<FeatureIdJson>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeatureIdJson {
+    pub feature_count: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub null_feature_id: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attribute: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub texture: Option<FeatureIdTextureJson>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub property_table: Option<usize>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeatureIdTextureJson {
+    pub index: usize,
+    #[serde(default)]
+    pub tex_coord: u32,
+    #[serde(default = "default_channels")]
+    pub channels: Vec<u8>,
+}
+
+fn default_channels() -> Vec<u8> {
+    vec![0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn feature_ids_serde() {
+        let json = r#"{
+            "featureIds": [
+                { "featureCount": 4, "attribute": 0, "propertyTable": 0 },
+                { "featureCount": 2, "nullFeatureId": 255, "texture": { "index": 1 } }
+            ]
+        }"#;
+
+        let ext =
+            serde_json::from_str::<PrimitiveExtension>(json).expect("json should deserialize");
+
+        assert_eq!(ext.feature_ids[0].attribute, Some(0));
+        assert_eq!(ext.feature_ids[0].property_table, Some(0));
+
+        let texture = ext.feature_ids[1]
+            .texture
+            .as_ref()
+            .expect("texture should exist");
+        assert_eq!(texture.index, 1);
+        assert_eq!(texture.channels, vec![0]);
+
+        let json_2 = serde_json::to_string(&ext).expect("json should serialize");
+        let ext_2 =
+            serde_json::from_str::<PrimitiveExtension>(&json_2).expect("json should deserialize");
+        assert_eq!(ext, ext_2);
+    }
+}
@@ -0,0 +1,60 @@
+//! [EXT_mesh_features](https://github.com/CesiumGS/glTF/tree/3d-tiles-next/extensions/2.0/Vendor/EXT_mesh_features)
+//! extension.
+
+use petgraph::graph::NodeIndex;
+
+use crate::graph::{Graph, OtherEdgeHelpers};
+
+use self::feature_id::{FeatureId, FeatureIdWeight};
+
+use super::Extension;
+
+pub mod export;
+pub mod feature_id;
+pub mod import;
+pub mod json;
+
+pub const EXTENSION_NAME: &str = "EXT_mesh_features";
+
+pub const FEATURE_ID_EDGE: &str = "EXT_mesh_features/feature_id";
+
+/// Feature IDs of a primitive, added as a primitive extension.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ExtMeshFeatures(pub NodeIndex);
+
+impl From<NodeIndex> for ExtMeshFeatures {
+    fn from(index: NodeIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl From<ExtMeshFeatures> for NodeIndex {
+    fn from(features: ExtMeshFeatures) -> Self {
+        features.0
+    }
+}
+
+impl OtherEdgeHelpers for ExtMeshFeatures {}
+
+impl Extension for ExtMeshFeatures {
+    fn name() -> &'static str {
+        EXTENSION_NAME
+    }
+}
+
+impl ExtMeshFeatures {
+    pub fn feature_ids(&self, graph: &Graph) -> Vec<FeatureId> {
+        self.find_properties(graph, FEATURE_ID_EDGE)
+    }
+    pub fn add_feature_id(&self, graph: &mut Graph, feature_id: FeatureId) {
+        self.add_property(graph, FEATURE_ID_EDGE.to_string(), feature_id);
+    }
+    pub fn remove_feature_id(&self, graph: &mut Graph, feature_id: FeatureId) {
+        self.remove_property(graph, FEATURE_ID_EDGE, feature_id);
+    }
+    pub fn create_feature_id(&self, graph: &mut Graph, weight: &FeatureIdWeight) -> FeatureId {
+        let feature_id = FeatureId::new(graph, weight);
+        self.add_feature_id(graph, feature_id);
+        feature_id
+    }
+}
@@ -0,0 +1,56 @@
+use std::error::Error;
+
+use crate::{
+    extensions::ExtensionExport,
+    graph::{Extensions, Graph, gltf::document::GltfDocument},
+    io::format::gltf::GltfFormat,
+};
+
+use super::{EXTENSION_NAME, ExtMeshGpuInstancing, json::InstancingJson};
+
+impl ExtensionExport<GltfDocument, GltfFormat> for ExtMeshGpuInstancing {
+    fn export(
+        graph: &mut Graph,
+        doc: &GltfDocument,
+        format: &mut GltfFormat,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut added_extension = false;
+
+        for (i, node) in doc.nodes(graph).iter().enumerate() {
+            let Some(ext) = node.get_extension::<Self>(graph) else {
+                continue;
+            };
+
+            let attributes = ext
+                .attributes(graph)
+                .into_iter()
+                .filter_map(|(name, accessor)| {
+                    doc.accessor_index(graph, accessor)
+                        .map(|index| (name, index))
+                })
+                .collect();
+
+            let node = format
+                .json
+                .nodes
+                .get_mut(i)
+                .expect("Node index out of bounds");
+
+            node.extensions
+                .get_or_insert_with(gltf::json::extensions::scene::Node::default)
+                .others
+                .insert(
+                    EXTENSION_NAME.to_string(),
+                    serde_json::to_value(InstancingJson { attributes })?,
+                );
+
+            added_extension = true;
+        }
+
+        if added_extension {
+            format.json.extensions_used.push(EXTENSION_NAME.to_string());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use std::error::Error;
+
+use tracing::warn;
+
+use crate::{
+    extensions::ExtensionImport,
+    graph::{Extensions, Graph, gltf::document::GltfDocument},
+    io::format::gltf::GltfFormat,
+};
+
+use super::{EXTENSION_NAME, ExtMeshGpuInstancing, json::InstancingJson};
+
+impl ExtensionImport<GltfDocument, GltfFormat> for ExtMeshGpuInstancing {
+    fn supported_extensions() -> Vec<&'static str> {
+        vec![EXTENSION_NAME]
+    }
+
+    fn import(
+        graph: &mut Graph,
+        format: &mut GltfFormat,
+        doc: &GltfDocument,
+    ) -> Result<(), Box<dyn Error>> {
+        let nodes = doc.nodes(graph);
+        let accessors = doc.accessors(graph);
+
+        for (i, n) in format.json.nodes.iter().enumerate() {
+            let Some(value) = n
+                .extensions
+                .as_ref()
+                .and_then(|e| e.others.get(EXTENSION_NAME))
+            else {
+                continue;
+            };
+
+            let json = serde_json::from_value::<InstancingJson>(value.clone())?;
+
+            let ext = nodes[i].create_extension::<Self>(graph);
+
+            for (name, index) in json.attributes {
+                let Some(accessor) = accessors.get(index).copied() else {
+                    warn!(
+                        "Instance attribute {} accessor index {} out of bounds",
+                        name, index
+                    );
+                    continue;
+                };
+
+                ext.set_attribute(graph, &name, Some(accessor));
+            }
+        }
+
+        Ok(())
+    }
+}
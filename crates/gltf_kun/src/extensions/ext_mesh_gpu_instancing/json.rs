@@ -0,0 +1,25 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct InstancingJson {
+    /// Accessor index of each instance attribute.
+    pub attributes: BTreeMap<String, usize>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn instancing_serde() {
+        let json = r#"{"attributes":{"ROTATION":2,"TRANSLATION":1}}"#;
+        let instancing =
+            serde_json::from_str::<InstancingJson>(json).expect("json should deserialize");
+        assert_eq!(instancing.attributes.get("TRANSLATION"), Some(&1));
+
+        let json_2 = serde_json::to_string(&instancing).expect("json should serialize");
+        assert_eq!(json, json_2);
+    }
+}
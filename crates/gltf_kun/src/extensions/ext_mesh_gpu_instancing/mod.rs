@@ -0,0 +1,77 @@
+//! [EXT_mesh_gpu_instancing](https://github.com/KhronosGroup/glTF/tree/main/extensions/2.0/Vendor/EXT_mesh_gpu_instancing)
+//! extension.
+
+use petgraph::{graph::NodeIndex, visit::EdgeRef};
+
+use crate::graph::{Edge, Graph, OtherEdgeHelpers, gltf::Accessor};
+
+use super::Extension;
+
+pub mod export;
+pub mod import;
+pub mod json;
+
+pub const EXTENSION_NAME: &str = "EXT_mesh_gpu_instancing";
+
+pub const ATTRIBUTE_EDGE: &str = "EXT_mesh_gpu_instancing/attribute";
+
+pub const TRANSLATION: &str = "TRANSLATION";
+pub const ROTATION: &str = "ROTATION";
+pub const SCALE: &str = "SCALE";
+
+/// Per-instance attributes of a node's mesh, added as a node extension.
+///
+/// Each instance is drawn with the node's transform, followed by its own
+/// `TRANSLATION`, `ROTATION` and `SCALE`.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ExtMeshGpuInstancing(pub NodeIndex);
+
+impl From<NodeIndex> for ExtMeshGpuInstancing {
+    fn from(index: NodeIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl From<ExtMeshGpuInstancing> for NodeIndex {
+    fn from(instancing: ExtMeshGpuInstancing) -> Self {
+        instancing.0
+    }
+}
+
+impl OtherEdgeHelpers for ExtMeshGpuInstancing {}
+
+impl Extension for ExtMeshGpuInstancing {
+    fn name() -> &'static str {
+        EXTENSION_NAME
+    }
+}
+
+impl ExtMeshGpuInstancing {
+    /// Instance attributes, sorted by name.
+    pub fn attributes(&self, graph: &Graph) -> Vec<(String, Accessor)> {
+        let prefix = format!("{ATTRIBUTE_EDGE}/");
+
+        let mut attributes = graph
+            .edges(self.0)
+            .filter_map(|edge| match edge.weight() {
+                Edge::Other(name) => name
+                    .strip_prefix(&prefix)
+                    .map(|name| (name.to_string(), Accessor(edge.target()))),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        attributes.sort();
+        attributes
+    }
+    pub fn attribute(&self, graph: &Graph, name: &str) -> Option<Accessor> {
+        self.find_property(graph, &attribute_edge(name))
+    }
+    pub fn set_attribute(&self, graph: &mut Graph, name: &str, accessor: Option<Accessor>) {
+        self.set_property(graph, attribute_edge(name), accessor);
+    }
+}
+
+fn attribute_edge(name: &str) -> String {
+    format!("{ATTRIBUTE_EDGE}/{name}")
+}
@@ -0,0 +1,156 @@
+use std::error::Error;
+
+use gltf::json::validation::USize64;
+
+use crate::{
+    extensions::ExtensionExport,
+    graph::{ByteNode, Extensions, Graph, gltf::document::GltfDocument},
+    io::format::gltf::GltfFormat,
+};
+
+use super::{
+    EXTENSION_NAME, ExtStructuralMetadata,
+    json::{
+        PrimitiveExtension, PropertyTableJson, PropertyTablePropertyJson, PropertyTextureJson,
+        PropertyTexturePropertyJson, RootExtension,
+    },
+    primitive::PrimitiveMetadata,
+};
+
+impl ExtensionExport<GltfDocument, GltfFormat> for ExtStructuralMetadata {
+    #[allow(clippy::too_many_lines)]
+    fn export(
+        graph: &mut Graph,
+        doc: &GltfDocument,
+        format: &mut GltfFormat,
+    ) -> Result<(), Box<dyn Error>> {
+        let Some(ext) = doc.get_extension::<Self>(graph) else {
+            return Ok(());
+        };
+
+        let weight = ext.read(graph);
+        let tables = ext.property_tables(graph);
+
+        if !tables.is_empty() && format.json.buffers.is_empty() {
+            let uri = "buffer_0.bin".to_string();
+            format.resources.insert(uri.clone(), Vec::new());
+            format.json.buffers.push(gltf::json::Buffer {
+                extensions: None,
+                extras: None,
+                name: None,
+
+                byte_length: USize64(0),
+                uri: Some(uri),
+            });
+        }
+
+        let property_tables = tables
+            .iter()
+            .map(|table| {
+                let table_weight = table.read(graph);
+
+                let properties = table_weight
+                    .properties
+                    .into_iter()
+                    .filter_map(|(id, property)| {
+                        let values = format.push_buffer_view(0, table.values(graph, &id)?)?;
+
+                        let array_offsets = table
+                            .array_offsets(graph, &id)
+                            .and_then(|data| format.push_buffer_view(0, data));
+                        let string_offsets = table
+                            .string_offsets(graph, &id)
+                            .and_then(|data| format.push_buffer_view(0, data));
+
+                        let json = PropertyTablePropertyJson {
+                            values,
+                            array_offsets,
+                            string_offsets,
+                            property,
+                        };
+
+                        Some((id, json))
+                    })
+                    .collect();
+
+                PropertyTableJson {
+                    name: table_weight.name,
+                    class: table_weight.class,
+                    count: table_weight.count,
+                    properties,
+                }
+            })
+            .collect();
+
+        let property_textures = ext
+            .property_textures(graph)
+            .iter()
+            .map(|texture| {
+                let texture_weight = texture.read(graph);
+
+                let properties = texture_weight
+                    .properties
+                    .into_iter()
+                    .filter_map(|(id, property)| {
+                        let index = texture
+                            .texture(graph, &id)
+                            .and_then(|t| doc.texture_index(graph, t))?;
+                        Some((id, PropertyTexturePropertyJson { index, property }))
+                    })
+                    .collect();
+
+                PropertyTextureJson {
+                    name: texture_weight.name,
+                    class: texture_weight.class,
+                    properties,
+                }
+            })
+            .collect();
+
+        for (mesh, mesh_json) in doc.meshes(graph).iter().zip(format.json.meshes.iter_mut()) {
+            let primitives = mesh.primitives(graph);
+
+            for (primitive, primitive_json) in
+                primitives.iter().zip(mesh_json.primitives.iter_mut())
+            {
+                let Some(metadata) = primitive.get_extension::<PrimitiveMetadata>(graph) else {
+                    continue;
+                };
+
+                let metadata = metadata.read(graph);
+
+                primitive_json
+                    .extensions
+                    .get_or_insert_with(gltf::json::extensions::mesh::Primitive::default)
+                    .others
+                    .insert(
+                        EXTENSION_NAME.to_string(),
+                        serde_json::to_value(PrimitiveExtension {
+                            property_textures: metadata.property_textures,
+                            property_attributes: metadata.property_attributes,
+                        })?,
+                    );
+            }
+        }
+
+        let extensions = format
+            .json
+            .extensions
+            .get_or_insert_with(gltf::json::extensions::Root::default);
+
+        extensions.others.insert(
+            EXTENSION_NAME.to_string(),
+            serde_json::to_value(RootExtension {
+                schema: weight.schema,
+                schema_uri: weight.schema_uri,
+                property_tables,
+                property_textures,
+                property_attributes: weight.property_attributes,
+            })?,
+        );
+
+        format.json.extensions_used.push(EXTENSION_NAME.to_string());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,143 @@
+use std::error::Error;
+
+use tracing::warn;
+
+use crate::{
+    extensions::ExtensionImport,
+    graph::{ByteNode, Extensions, Graph, gltf::document::GltfDocument},
+    io::format::gltf::GltfFormat,
+};
+
+use super::{
+    EXTENSION_NAME, ExtStructuralMetadata, StructuralMetadataWeight,
+    json::{PrimitiveExtension, RootExtension},
+    primitive::{PrimitiveMetadata, PrimitiveMetadataWeight},
+    property_table::PropertyTableWeight,
+    property_texture::PropertyTextureWeight,
+};
+
+impl ExtensionImport<GltfDocument, GltfFormat> for ExtStructuralMetadata {
+    fn supported_extensions() -> Vec<&'static str> {
+        vec![EXTENSION_NAME]
+    }
+
+    fn import(
+        graph: &mut Graph,
+        format: &mut GltfFormat,
+        doc: &GltfDocument,
+    ) -> Result<(), Box<dyn Error>> {
+        let Some(value) = format
+            .json
+            .extensions
+            .as_ref()
+            .and_then(|e| e.others.get(EXTENSION_NAME))
+        else {
+            return Ok(());
+        };
+
+        let root = serde_json::from_value::<RootExtension>(value.clone())?;
+
+        let ext = doc
+            .get_extension::<Self>(graph)
+            .unwrap_or_else(|| doc.create_extension::<Self>(graph));
+
+        ext.write(
+            graph,
+            &StructuralMetadataWeight {
+                schema: root.schema,
+                schema_uri: root.schema_uri,
+                property_attributes: root.property_attributes,
+            },
+        );
+
+        for table_json in root.property_tables {
+            let table = ext.create_property_table(
+                graph,
+                &PropertyTableWeight {
+                    name: table_json.name,
+                    class: table_json.class,
+                    count: table_json.count,
+                    properties: table_json
+                        .properties
+                        .iter()
+                        .map(|(id, p)| (id.clone(), p.property.clone()))
+                        .collect(),
+                },
+            );
+
+            for (id, property) in &table_json.properties {
+                let read = |view: usize| {
+                    let data = format.read_buffer_view(view).map(<[u8]>::to_vec);
+                    if data.is_none() {
+                        warn!("Failed to read property table buffer view {}", view);
+                    }
+                    data
+                };
+
+                table.set_values(graph, id, read(property.values));
+
+                if let Some(view) = property.array_offsets {
+                    table.set_array_offsets(graph, id, read(view));
+                }
+
+                if let Some(view) = property.string_offsets {
+                    table.set_string_offsets(graph, id, read(view));
+                }
+            }
+        }
+
+        let textures = doc.textures(graph);
+
+        for texture_json in root.property_textures {
+            let property_texture = ext.create_property_texture(
+                graph,
+                &PropertyTextureWeight {
+                    name: texture_json.name,
+                    class: texture_json.class,
+                    properties: texture_json
+                        .properties
+                        .iter()
+                        .map(|(id, p)| (id.clone(), p.property.clone()))
+                        .collect(),
+                },
+            );
+
+            for (id, property) in &texture_json.properties {
+                if let Some(t) = textures.get(property.index) {
+                    property_texture.set_texture(graph, id, Some(*t));
+                } else {
+                    warn!("Property texture index {} out of bounds", property.index);
+                }
+            }
+        }
+
+        let meshes = doc.meshes(graph);
+
+        for (mesh, mesh_json) in meshes.iter().zip(format.json.meshes.iter()) {
+            let primitives = mesh.primitives(graph);
+
+            for (primitive, primitive_json) in primitives.iter().zip(mesh_json.primitives.iter()) {
+                let Some(value) = primitive_json
+                    .extensions
+                    .as_ref()
+                    .and_then(|e| e.others.get(EXTENSION_NAME))
+                else {
+                    continue;
+                };
+
+                let json = serde_json::from_value::<PrimitiveExtension>(value.clone())?;
+
+                let metadata = PrimitiveMetadata::new(
+                    graph,
+                    &PrimitiveMetadataWeight {
+                        property_textures: json.property_textures,
+                        property_attributes: json.property_attributes,
+                    },
+                );
+                primitive.add_extension(graph, metadata);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,163 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{
+    property_table::PropertyTableProperty, property_texture::PropertyTextureProperty,
+    schema::Schema,
+};
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RootExtension {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<Schema>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_uri: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub property_tables: Vec<PropertyTableJson>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub property_textures: Vec<PropertyTextureJson>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub property_attributes: Vec<PropertyAttributeJson>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PropertyTableJson {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub class: String,
+    pub count: usize,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub properties: BTreeMap<String, PropertyTablePropertyJson>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PropertyTablePropertyJson {
+    pub values: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub array_offsets: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub string_offsets: Option<usize>,
+    #[serde(flatten)]
+    pub property: PropertyTableProperty,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PropertyTextureJson {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub class: String,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub properties: BTreeMap<String, PropertyTexturePropertyJson>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PropertyTexturePropertyJson {
+    pub index: usize,
+    #[serde(flatten)]
+    pub property: PropertyTextureProperty,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PropertyAttributeJson {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub class: String,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub properties: BTreeMap<String, PropertyAttributePropertyJson>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PropertyAttributePropertyJson {
+    /// Name of the primitive attribute containing the property.
+    pub attribute: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offset: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scale: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<Value>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrimitiveExtension {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub property_textures: Vec<usize>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub property_attributes: Vec<usize>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extensions::ext_structural_metadata::schema::{PropertyComponentType, PropertyType};
+
+    #[test]
+    fn metadata_serde() {
+        let json = r#"{
+            "schema": {
+                "id": "buildings",
+                "classes": {
+                    "building": {
+                        "properties": {
+                            "height": { "type": "SCALAR", "componentType": "FLOAT32", "required": true },
+                            "kind": { "type": "ENUM", "enumType": "kind" }
+                        }
+                    }
+                },
+                "enums": {
+                    "kind": { "values": [{ "name": "House", "value": 0 }] }
+                }
+            },
+            "propertyTables": [{
+                "class": "building",
+                "count": 2,
+                "properties": {
+                    "height": { "values": 0, "offset": 1.0 },
+                    "kind": { "values": 1, "arrayOffsets": 2, "arrayOffsetType": "UINT16" }
+                }
+            }],
+            "propertyTextures": [{
+                "class": "building",
+                "properties": { "height": { "index": 0, "channels": [0, 1] } }
+            }]
+        }"#;
+
+        let root = serde_json::from_str::<RootExtension>(json).expect("json should deserialize");
+
+        let schema = root.schema.as_ref().expect("schema should exist");
+        let height = &schema.classes["building"].properties["height"];
+        assert_eq!(height.type_, PropertyType::Scalar);
+        assert_eq!(height.component_type, Some(PropertyComponentType::Float32));
+        assert!(height.required);
+        assert_eq!(
+            schema.enums["kind"].value_type,
+            PropertyComponentType::Uint16
+        );
+
+        let table = &root.property_tables[0];
+        assert_eq!(
+            table.properties["height"].property.array_offset_type,
+            PropertyComponentType::Uint32
+        );
+        assert_eq!(table.properties["kind"].array_offsets, Some(2));
+        assert_eq!(
+            table.properties["kind"].property.array_offset_type,
+            PropertyComponentType::Uint16
+        );
+
+        let texture = &root.property_textures[0].properties["height"];
+        assert_eq!(texture.property.channels, vec![0, 1]);
+
+        let json_2 = serde_json::to_string(&root).expect("json should serialize");
+        let root_2 =
+            serde_json::from_str::<RootExtension>(&json_2).expect("json should deserialize");
+        assert_eq!(root, root_2);
+    }
+}
@@ -0,0 +1,120 @@
+//! [EXT_structural_metadata](https://github.com/CesiumGS/glTF/tree/3d-tiles-next/extensions/2.0/Vendor/EXT_structural_metadata)
+//! extension.
+
+use petgraph::graph::NodeIndex;
+use serde::{Deserialize, Serialize};
+
+use crate::graph::{ByteNode, Graph, OtherEdgeHelpers};
+
+use self::{
+    json::PropertyAttributeJson,
+    property_table::{PropertyTable, PropertyTableWeight},
+    property_texture::{PropertyTexture, PropertyTextureWeight},
+    schema::Schema,
+};
+
+use super::Extension;
+
+pub mod export;
+pub mod import;
+pub mod json;
+pub mod primitive;
+pub mod property_table;
+pub mod property_texture;
+pub mod schema;
+
+pub const EXTENSION_NAME: &str = "EXT_structural_metadata";
+
+pub const PROPERTY_TABLE_EDGE: &str = "EXT_structural_metadata/property_table";
+pub const PROPERTY_TEXTURE_EDGE: &str = "EXT_structural_metadata/property_texture";
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct StructuralMetadataWeight {
+    pub schema: Option<Schema>,
+    /// URI of an external schema, used instead of an embedded one.
+    pub schema_uri: Option<String>,
+    /// Property attributes, which reference primitive attributes by name.
+    pub property_attributes: Vec<PropertyAttributeJson>,
+}
+
+impl From<&Vec<u8>> for StructuralMetadataWeight {
+    fn from(bytes: &Vec<u8>) -> Self {
+        if bytes.is_empty() {
+            return Self::default();
+        }
+        serde_json::from_slice(bytes).expect("Failed to deserialize weight")
+    }
+}
+
+impl From<&StructuralMetadataWeight> for Vec<u8> {
+    fn from(value: &StructuralMetadataWeight) -> Self {
+        serde_json::to_vec(value).expect("Failed to serialize weight")
+    }
+}
+
+/// Document-level metadata schema, property tables, and property textures.
+///
+/// Property tables and textures are referenced by index, in creation order.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ExtStructuralMetadata(pub NodeIndex);
+
+impl From<NodeIndex> for ExtStructuralMetadata {
+    fn from(index: NodeIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl From<ExtStructuralMetadata> for NodeIndex {
+    fn from(metadata: ExtStructuralMetadata) -> Self {
+        metadata.0
+    }
+}
+
+impl ByteNode<StructuralMetadataWeight> for ExtStructuralMetadata {}
+impl OtherEdgeHelpers for ExtStructuralMetadata {}
+
+impl Extension for ExtStructuralMetadata {
+    fn name() -> &'static str {
+        EXTENSION_NAME
+    }
+}
+
+impl ExtStructuralMetadata {
+    pub fn property_tables(&self, graph: &Graph) -> Vec<PropertyTable> {
+        self.find_properties(graph, PROPERTY_TABLE_EDGE)
+    }
+    pub fn add_property_table(&self, graph: &mut Graph, table: PropertyTable) {
+        self.add_property(graph, PROPERTY_TABLE_EDGE.to_string(), table);
+    }
+    pub fn remove_property_table(&self, graph: &mut Graph, table: PropertyTable) {
+        self.remove_property(graph, PROPERTY_TABLE_EDGE, table);
+    }
+    pub fn create_property_table(
+        &self,
+        graph: &mut Graph,
+        weight: &PropertyTableWeight,
+    ) -> PropertyTable {
+        let table = PropertyTable::new(graph, weight);
+        self.add_property_table(graph, table);
+        table
+    }
+
+    pub fn property_textures(&self, graph: &Graph) -> Vec<PropertyTexture> {
+        self.find_properties(graph, PROPERTY_TEXTURE_EDGE)
+    }
+    pub fn add_property_texture(&self, graph: &mut Graph, texture: PropertyTexture) {
+        self.add_property(graph, PROPERTY_TEXTURE_EDGE.to_string(), texture);
+    }
+    pub fn remove_property_texture(&self, graph: &mut Graph, texture: PropertyTexture) {
+        self.remove_property(graph, PROPERTY_TEXTURE_EDGE, texture);
+    }
+    pub fn create_property_texture(
+        &self,
+        graph: &mut Graph,
+        weight: &PropertyTextureWeight,
+    ) -> PropertyTexture {
+        let texture = PropertyTexture::new(graph, weight);
+        self.add_property_texture(graph, texture);
+        texture
+    }
+}
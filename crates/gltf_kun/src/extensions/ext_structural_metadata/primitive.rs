@@ -0,0 +1,63 @@
+use petgraph::graph::NodeIndex;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    extensions::Extension,
+    graph::{ByteNode, Graph, Weight},
+};
+
+use super::EXTENSION_NAME;
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PrimitiveMetadataWeight {
+    /// Indices of property textures in the document extension.
+    pub property_textures: Vec<usize>,
+    /// Indices of property attributes in the document extension.
+    pub property_attributes: Vec<usize>,
+}
+
+impl From<&Vec<u8>> for PrimitiveMetadataWeight {
+    fn from(bytes: &Vec<u8>) -> Self {
+        if bytes.is_empty() {
+            return Self::default();
+        }
+        serde_json::from_slice(bytes).expect("Failed to deserialize weight")
+    }
+}
+
+impl From<&PrimitiveMetadataWeight> for Vec<u8> {
+    fn from(value: &PrimitiveMetadataWeight) -> Self {
+        serde_json::to_vec(value).expect("Failed to serialize weight")
+    }
+}
+
+/// Property textures and attributes used by a primitive, added as a primitive extension.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct PrimitiveMetadata(pub NodeIndex);
+
+impl From<NodeIndex> for PrimitiveMetadata {
+    fn from(index: NodeIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl From<PrimitiveMetadata> for NodeIndex {
+    fn from(metadata: PrimitiveMetadata) -> Self {
+        metadata.0
+    }
+}
+
+impl ByteNode<PrimitiveMetadataWeight> for PrimitiveMetadata {}
+
+impl Extension for PrimitiveMetadata {
+    fn name() -> &'static str {
+        EXTENSION_NAME
+    }
+}
+
+impl PrimitiveMetadata {
+    pub fn new(graph: &mut Graph, weight: &PrimitiveMetadataWeight) -> Self {
+        let index = graph.add_node(Weight::Bytes(weight.into()));
+        Self(index)
+    }
+}
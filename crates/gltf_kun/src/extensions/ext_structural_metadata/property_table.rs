@@ -0,0 +1,678 @@
+use std::{collections::BTreeMap, ops::Range};
+
+use byteorder::{ByteOrder, LE};
+use petgraph::graph::NodeIndex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::graph::{
+    ByteNode, Graph, OtherEdgeHelpers, Weight,
+    gltf::accessor::iter::{AccessorElement, AccessorIter, AccessorIterCreateError},
+};
+
+use super::schema::{PropertyComponentType, PropertyType, Schema};
+
+pub const VALUES_EDGE: &str = "EXT_structural_metadata/property_table/values";
+pub const ARRAY_OFFSETS_EDGE: &str = "EXT_structural_metadata/property_table/array_offsets";
+pub const STRING_OFFSETS_EDGE: &str = "EXT_structural_metadata/property_table/string_offsets";
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PropertyTableWeight {
+    pub name: Option<String>,
+    /// ID of the schema class this table conforms to.
+    pub class: String,
+    /// Number of rows in the table.
+    pub count: usize,
+    pub properties: BTreeMap<String, PropertyTableProperty>,
+}
+
+impl From<&Vec<u8>> for PropertyTableWeight {
+    fn from(bytes: &Vec<u8>) -> Self {
+        if bytes.is_empty() {
+            return Self::default();
+        }
+        serde_json::from_slice(bytes).expect("Failed to deserialize weight")
+    }
+}
+
+impl From<&PropertyTableWeight> for Vec<u8> {
+    fn from(value: &PropertyTableWeight) -> Self {
+        serde_json::to_vec(value).expect("Failed to serialize weight")
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PropertyTableProperty {
+    #[serde(default = "default_offset_type")]
+    pub array_offset_type: PropertyComponentType,
+    #[serde(default = "default_offset_type")]
+    pub string_offset_type: PropertyComponentType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offset: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scale: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<Value>,
+}
+
+impl Default for PropertyTableProperty {
+    fn default() -> Self {
+        Self {
+            array_offset_type: default_offset_type(),
+            string_offset_type: default_offset_type(),
+            offset: None,
+            scale: None,
+            max: None,
+            min: None,
+        }
+    }
+}
+
+const fn default_offset_type() -> PropertyComponentType {
+    PropertyComponentType::Uint32
+}
+
+/// A value read from a property table.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PropertyValue {
+    /// Scalar, vector, matrix, or enum value.
+    Element(AccessorElement),
+    /// Components of a value with INT32 or INT64 components,
+    /// which accessors cannot represent.
+    Int(Vec<i64>),
+    /// Components of a value with UINT64 components.
+    Uint(Vec<u64>),
+    /// Components of a value with FLOAT64 components,
+    /// or normalized INT32, INT64 or UINT64 components.
+    Float(Vec<f64>),
+    String(String),
+    Boolean(bool),
+    Array(Vec<Self>),
+}
+
+#[derive(Debug, Error)]
+pub enum ReadPropertyError {
+    #[error("class {0} not found in schema")]
+    MissingClass(String),
+    #[error("property {0} not found")]
+    MissingProperty(String),
+    #[error("enum {0} not found in schema")]
+    MissingEnum(String),
+    #[error("property {0} has no {1} buffer")]
+    MissingBuffer(String, &'static str),
+    #[error("numeric property {0} has no component type")]
+    MissingComponentType(String),
+    #[error("unsupported component type {0:?}")]
+    UnsupportedComponentType(PropertyComponentType),
+    #[error(transparent)]
+    AccessorIter(#[from] AccessorIterCreateError),
+    #[error("buffer is too short for the table")]
+    OutOfBounds,
+    #[error(transparent)]
+    Utf8(#[from] std::string::FromUtf8Error),
+}
+
+/// Raw binary data of a property table column.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct PropertyBuffer(pub NodeIndex);
+
+impl From<NodeIndex> for PropertyBuffer {
+    fn from(index: NodeIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl From<PropertyBuffer> for NodeIndex {
+    fn from(buffer: PropertyBuffer) -> Self {
+        buffer.0
+    }
+}
+
+impl PropertyBuffer {
+    pub fn new(graph: &mut Graph, data: Vec<u8>) -> Self {
+        let index = graph.add_node(Weight::Bytes(data));
+        Self(index)
+    }
+
+    pub fn data(self, graph: &Graph) -> &[u8] {
+        match &graph[self.0] {
+            Weight::Bytes(bytes) => bytes,
+            _ => panic!("Incorrect weight type"),
+        }
+    }
+}
+
+/// Metadata stored in binary columns, with one row per feature.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct PropertyTable(pub NodeIndex);
+
+impl From<NodeIndex> for PropertyTable {
+    fn from(index: NodeIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl From<PropertyTable> for NodeIndex {
+    fn from(table: PropertyTable) -> Self {
+        table.0
+    }
+}
+
+impl ByteNode<PropertyTableWeight> for PropertyTable {}
+impl OtherEdgeHelpers for PropertyTable {}
+
+impl PropertyTable {
+    pub fn new(graph: &mut Graph, weight: &PropertyTableWeight) -> Self {
+        let index = graph.add_node(Weight::Bytes(weight.into()));
+        Self(index)
+    }
+
+    /// Values of the property, as tightly packed elements.
+    /// Strings are UTF-8 bytes, and booleans a bitstream.
+    pub fn values<'a>(&self, graph: &'a Graph, property: &str) -> Option<&'a [u8]> {
+        self.buffer(graph, VALUES_EDGE, property)
+    }
+    pub fn set_values(&self, graph: &mut Graph, property: &str, data: Option<Vec<u8>>) {
+        self.set_buffer(graph, VALUES_EDGE, property, data);
+    }
+
+    /// Offsets of each array, for variable-length array properties.
+    pub fn array_offsets<'a>(&self, graph: &'a Graph, property: &str) -> Option<&'a [u8]> {
+        self.buffer(graph, ARRAY_OFFSETS_EDGE, property)
+    }
+    pub fn set_array_offsets(&self, graph: &mut Graph, property: &str, data: Option<Vec<u8>>) {
+        self.set_buffer(graph, ARRAY_OFFSETS_EDGE, property, data);
+    }
+
+    /// Byte offsets of each string, for string properties.
+    pub fn string_offsets<'a>(&self, graph: &'a Graph, property: &str) -> Option<&'a [u8]> {
+        self.buffer(graph, STRING_OFFSETS_EDGE, property)
+    }
+    pub fn set_string_offsets(&self, graph: &mut Graph, property: &str, data: Option<Vec<u8>>) {
+        self.set_buffer(graph, STRING_OFFSETS_EDGE, property, data);
+    }
+
+    fn buffer<'a>(self, graph: &'a Graph, edge: &str, property: &str) -> Option<&'a [u8]> {
+        self.find_property::<PropertyBuffer>(graph, &buffer_edge(edge, property))
+            .map(|b| b.data(graph))
+    }
+    fn set_buffer(self, graph: &mut Graph, edge: &str, property: &str, data: Option<Vec<u8>>) {
+        let edge = buffer_edge(edge, property);
+
+        if let Some(buffer) = self.find_property::<PropertyBuffer>(graph, &edge) {
+            graph.remove_node(buffer.0);
+        }
+
+        if let Some(data) = data {
+            let buffer = PropertyBuffer::new(graph, data);
+            self.add_property(graph, edge, buffer);
+        }
+    }
+
+    /// Reads every row of a property, using its class definition from the schema.
+    /// Values are returned as stored, without applying offset or scale.
+    /// Components that accessors cannot represent are read into [`PropertyValue::Int`],
+    /// [`PropertyValue::Uint`] or [`PropertyValue::Float`].
+    pub fn read_property(
+        &self,
+        graph: &Graph,
+        schema: &Schema,
+        property: &str,
+    ) -> Result<Vec<PropertyValue>, ReadPropertyError> {
+        let weight = self.read(graph);
+
+        let class = schema
+            .classes
+            .get(&weight.class)
+            .ok_or_else(|| ReadPropertyError::MissingClass(weight.class.clone()))?;
+        let definition = class
+            .properties
+            .get(property)
+            .ok_or_else(|| ReadPropertyError::MissingProperty(property.to_string()))?;
+        let table_property = weight
+            .properties
+            .get(property)
+            .ok_or_else(|| ReadPropertyError::MissingProperty(property.to_string()))?;
+
+        let missing = |name| ReadPropertyError::MissingBuffer(property.to_string(), name);
+        let values = self
+            .values(graph, property)
+            .ok_or_else(|| missing("values"))?;
+
+        // Range of elements in each row.
+        let rows: Vec<Range<usize>> = match (definition.array, definition.count) {
+            (false, _) => (0..weight.count).map(|i| i..i + 1).collect(),
+            (true, Some(count)) => (0..weight.count)
+                .map(|i| i * count..(i + 1) * count)
+                .collect(),
+            (true, None) => {
+                let offsets = self
+                    .array_offsets(graph, property)
+                    .ok_or_else(|| missing("array offsets"))?;
+                read_offsets(offsets, table_property.array_offset_type, weight.count + 1)?
+                    .windows(2)
+                    .map(|w| w[0]..w[1])
+                    .collect()
+            }
+        };
+
+        let len = rows.iter().map(|r| r.end).max().unwrap_or_default();
+
+        let elements = match definition.type_ {
+            PropertyType::String => {
+                let offsets = self
+                    .string_offsets(graph, property)
+                    .ok_or_else(|| missing("string offsets"))?;
+
+                read_offsets(offsets, table_property.string_offset_type, len + 1)?
+                    .windows(2)
+                    .map(|w| {
+                        let bytes = values
+                            .get(w[0]..w[1])
+                            .ok_or(ReadPropertyError::OutOfBounds)?;
+                        Ok(PropertyValue::String(String::from_utf8(bytes.to_vec())?))
+                    })
+                    .collect::<Result<Vec<_>, ReadPropertyError>>()?
+            }
+            PropertyType::Boolean => (0..len)
+                .map(|i| {
+                    values
+                        .get(i / 8)
+                        .map(|byte| PropertyValue::Boolean((byte >> (i % 8)) & 1 == 1))
+                        .ok_or(ReadPropertyError::OutOfBounds)
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            type_ => {
+                let component_type = if type_ == PropertyType::Enum {
+                    let id = definition.enum_type.clone().unwrap_or_default();
+                    schema
+                        .enums
+                        .get(&id)
+                        .ok_or(ReadPropertyError::MissingEnum(id))?
+                        .value_type
+                } else {
+                    definition.component_type.ok_or_else(|| {
+                        ReadPropertyError::MissingComponentType(property.to_string())
+                    })?
+                };
+
+                read_elements(values, component_type, type_, definition.normalized, len)?
+            }
+        };
+
+        rows.into_iter()
+            .map(|range| {
+                let mut row = elements
+                    .get(range)
+                    .ok_or(ReadPropertyError::OutOfBounds)?
+                    .to_vec();
+
+                if definition.array {
+                    Ok(PropertyValue::Array(row))
+                } else {
+                    Ok(row.pop().expect("row should have one element"))
+                }
+            })
+            .collect()
+    }
+}
+
+fn buffer_edge(edge: &str, property: &str) -> String {
+    format!("{edge}/{property}")
+}
+
+/// Reads `len` numeric elements from tightly packed values.
+fn read_elements(
+    values: &[u8],
+    component_type: PropertyComponentType,
+    type_: PropertyType,
+    normalized: bool,
+    len: usize,
+) -> Result<Vec<PropertyValue>, ReadPropertyError> {
+    let element_type = type_.element_type().expect("numeric type");
+
+    let Some(accessor_component_type) = component_type.accessor_component_type() else {
+        return read_wide_elements(
+            values,
+            component_type,
+            element_type.multiplicity(),
+            normalized,
+            len,
+        );
+    };
+
+    let stride = accessor_component_type.size() * element_type.multiplicity();
+    let slice = values
+        .get(..len * stride)
+        .ok_or(ReadPropertyError::OutOfBounds)?;
+
+    Ok(
+        AccessorIter::new(slice, accessor_component_type, element_type, normalized)?
+            .elements()
+            .map(PropertyValue::Element)
+            .collect(),
+    )
+}
+
+/// Reads `len` elements with components that accessors cannot represent.
+fn read_wide_elements(
+    values: &[u8],
+    component_type: PropertyComponentType,
+    multiplicity: usize,
+    normalized: bool,
+    len: usize,
+) -> Result<Vec<PropertyValue>, ReadPropertyError> {
+    let size = component_type.size();
+    let stride = size * multiplicity;
+    let slice = values
+        .get(..len * stride)
+        .ok_or(ReadPropertyError::OutOfBounds)?;
+
+    slice
+        .chunks_exact(stride)
+        .map(|element| {
+            let components = element.chunks_exact(size);
+
+            match (component_type, normalized) {
+                (PropertyComponentType::Int32, false) => Ok(PropertyValue::Int(
+                    components.map(|c| i64::from(LE::read_i32(c))).collect(),
+                )),
+                (PropertyComponentType::Int32, true) => Ok(PropertyValue::Float(
+                    components
+                        .map(|c| (f64::from(LE::read_i32(c)) / f64::from(i32::MAX)).max(-1.0))
+                        .collect(),
+                )),
+                (PropertyComponentType::Int64, false) => {
+                    Ok(PropertyValue::Int(components.map(LE::read_i64).collect()))
+                }
+                (PropertyComponentType::Int64, true) => Ok(PropertyValue::Float(
+                    components
+                        .map(|c| (LE::read_i64(c) as f64 / i64::MAX as f64).max(-1.0))
+                        .collect(),
+                )),
+                (PropertyComponentType::Uint64, false) => {
+                    Ok(PropertyValue::Uint(components.map(LE::read_u64).collect()))
+                }
+                (PropertyComponentType::Uint64, true) => Ok(PropertyValue::Float(
+                    components
+                        .map(|c| LE::read_u64(c) as f64 / u64::MAX as f64)
+                        .collect(),
+                )),
+                (PropertyComponentType::Float64, _) => {
+                    Ok(PropertyValue::Float(components.map(LE::read_f64).collect()))
+                }
+                (other, _) => Err(ReadPropertyError::UnsupportedComponentType(other)),
+            }
+        })
+        .collect()
+}
+
+fn read_offsets(
+    data: &[u8],
+    offset_type: PropertyComponentType,
+    count: usize,
+) -> Result<Vec<usize>, ReadPropertyError> {
+    let size = offset_type.size();
+    let data = data
+        .get(..count * size)
+        .ok_or(ReadPropertyError::OutOfBounds)?;
+
+    data.chunks_exact(size)
+        .map(|chunk| match offset_type {
+            PropertyComponentType::Uint8 => Ok(usize::from(chunk[0])),
+            PropertyComponentType::Uint16 => Ok(usize::from(LE::read_u16(chunk))),
+            PropertyComponentType::Uint32 => Ok(LE::read_u32(chunk) as usize),
+            PropertyComponentType::Uint64 => Ok(LE::read_u64(chunk) as usize),
+            other => Err(ReadPropertyError::UnsupportedComponentType(other)),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extensions::ext_structural_metadata::schema::{Class, ClassProperty};
+
+    fn schema() -> Schema {
+        let mut class = Class::default();
+
+        class.properties.insert(
+            "height".to_string(),
+            ClassProperty {
+                type_: PropertyType::Scalar,
+                component_type: Some(PropertyComponentType::Float32),
+                ..Default::default()
+            },
+        );
+        class.properties.insert(
+            "name".to_string(),
+            ClassProperty {
+                type_: PropertyType::String,
+                ..Default::default()
+            },
+        );
+        class.properties.insert(
+            "floors".to_string(),
+            ClassProperty {
+                type_: PropertyType::Scalar,
+                component_type: Some(PropertyComponentType::Uint8),
+                array: true,
+                ..Default::default()
+            },
+        );
+        class.properties.insert(
+            "occupied".to_string(),
+            ClassProperty {
+                type_: PropertyType::Boolean,
+                ..Default::default()
+            },
+        );
+
+        class.properties.insert(
+            "id".to_string(),
+            ClassProperty {
+                type_: PropertyType::Scalar,
+                component_type: Some(PropertyComponentType::Uint64),
+                ..Default::default()
+            },
+        );
+        class.properties.insert(
+            "origin".to_string(),
+            ClassProperty {
+                type_: PropertyType::Vec2,
+                component_type: Some(PropertyComponentType::Float64),
+                ..Default::default()
+            },
+        );
+        class.properties.insert(
+            "offset".to_string(),
+            ClassProperty {
+                type_: PropertyType::Scalar,
+                component_type: Some(PropertyComponentType::Int32),
+                normalized: true,
+                ..Default::default()
+            },
+        );
+
+        let mut schema = Schema::default();
+        schema.classes.insert("building".to_string(), class);
+        schema
+    }
+
+    fn table(graph: &mut Graph) -> PropertyTable {
+        let properties = [
+            "height", "name", "floors", "occupied", "id", "origin", "offset",
+        ]
+        .into_iter()
+        .map(|p| (p.to_string(), PropertyTableProperty::default()))
+        .collect();
+
+        let table = PropertyTable::new(
+            graph,
+            &PropertyTableWeight {
+                name: None,
+                class: "building".to_string(),
+                count: 2,
+                properties,
+            },
+        );
+
+        let heights = [10.0f32, 25.5];
+        table.set_values(
+            graph,
+            "height",
+            Some(heights.iter().flat_map(|h| h.to_le_bytes()).collect()),
+        );
+
+        table.set_values(graph, "name", Some(b"ab".to_vec()));
+        table.set_string_offsets(
+            graph,
+            "name",
+            Some([0u32, 1, 2].iter().flat_map(|o| o.to_le_bytes()).collect()),
+        );
+
+        table.set_values(graph, "floors", Some(vec![1, 2, 3]));
+        table.set_array_offsets(
+            graph,
+            "floors",
+            Some([0u32, 1, 3].iter().flat_map(|o| o.to_le_bytes()).collect()),
+        );
+
+        table.set_values(graph, "occupied", Some(vec![0b10]));
+
+        let ids = [u64::MAX, 7];
+        table.set_values(
+            graph,
+            "id",
+            Some(ids.iter().flat_map(|id| id.to_le_bytes()).collect()),
+        );
+
+        let origins = [1.5f64, -2.0, 0.25, 8.0];
+        table.set_values(
+            graph,
+            "origin",
+            Some(origins.iter().flat_map(|o| o.to_le_bytes()).collect()),
+        );
+
+        let offsets = [i32::MIN, i32::MAX];
+        table.set_values(
+            graph,
+            "offset",
+            Some(offsets.iter().flat_map(|o| o.to_le_bytes()).collect()),
+        );
+
+        table
+    }
+
+    #[test]
+    fn read_properties() {
+        let mut graph = Graph::default();
+        let table = table(&mut graph);
+        let schema = schema();
+
+        let heights = table
+            .read_property(&graph, &schema, "height")
+            .expect("heights should read");
+        assert_eq!(
+            heights,
+            vec![
+                PropertyValue::Element(AccessorElement::F32(10.0)),
+                PropertyValue::Element(AccessorElement::F32(25.5)),
+            ]
+        );
+
+        let names = table
+            .read_property(&graph, &schema, "name")
+            .expect("names should read");
+        assert_eq!(
+            names,
+            vec![
+                PropertyValue::String("a".to_string()),
+                PropertyValue::String("b".to_string()),
+            ]
+        );
+
+        let floors = table
+            .read_property(&graph, &schema, "floors")
+            .expect("floors should read");
+        assert_eq!(
+            floors,
+            vec![
+                PropertyValue::Array(vec![PropertyValue::Element(AccessorElement::U8(1))]),
+                PropertyValue::Array(vec![
+                    PropertyValue::Element(AccessorElement::U8(2)),
+                    PropertyValue::Element(AccessorElement::U8(3)),
+                ]),
+            ]
+        );
+
+        let occupied = table
+            .read_property(&graph, &schema, "occupied")
+            .expect("occupied should read");
+        assert_eq!(
+            occupied,
+            vec![PropertyValue::Boolean(false), PropertyValue::Boolean(true)]
+        );
+    }
+
+    #[test]
+    fn read_wide_properties() {
+        let mut graph = Graph::default();
+        let table = table(&mut graph);
+        let schema = schema();
+
+        let ids = table
+            .read_property(&graph, &schema, "id")
+            .expect("ids should read");
+        assert_eq!(
+            ids,
+            vec![
+                PropertyValue::Uint(vec![u64::MAX]),
+                PropertyValue::Uint(vec![7])
+            ]
+        );
+
+        let origins = table
+            .read_property(&graph, &schema, "origin")
+            .expect("origins should read");
+        assert_eq!(
+            origins,
+            vec![
+                PropertyValue::Float(vec![1.5, -2.0]),
+                PropertyValue::Float(vec![0.25, 8.0]),
+            ]
+        );
+
+        let offsets = table
+            .read_property(&graph, &schema, "offset")
+            .expect("offsets should read");
+        assert_eq!(
+            offsets,
+            vec![
+                PropertyValue::Float(vec![-1.0]),
+                PropertyValue::Float(vec![1.0])
+            ]
+        );
+    }
+
+    #[test]
+    fn replace_values() {
+        let mut graph = Graph::default();
+        let table = table(&mut graph);
+        let nodes = graph.node_count();
+
+        table.set_values(&mut graph, "height", Some(vec![0; 8]));
+        assert_eq!(graph.node_count(), nodes);
+
+        table.set_values(&mut graph, "height", None);
+        assert_eq!(graph.node_count(), nodes - 1);
+        assert!(table.values(&graph, "height").is_none());
+
+        let err = table.read_property(&graph, &schema(), "height");
+        assert!(matches!(err, Err(ReadPropertyError::MissingBuffer(..))));
+    }
+}
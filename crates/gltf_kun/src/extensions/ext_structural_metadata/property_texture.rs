@@ -0,0 +1,104 @@
+use std::collections::BTreeMap;
+
+use petgraph::graph::NodeIndex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::graph::{ByteNode, Graph, OtherEdgeHelpers, Weight, gltf::Texture};
+
+pub const TEXTURE_EDGE: &str = "EXT_structural_metadata/property_texture/texture";
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PropertyTextureWeight {
+    pub name: Option<String>,
+    /// ID of the schema class this texture conforms to.
+    pub class: String,
+    pub properties: BTreeMap<String, PropertyTextureProperty>,
+}
+
+impl From<&Vec<u8>> for PropertyTextureWeight {
+    fn from(bytes: &Vec<u8>) -> Self {
+        if bytes.is_empty() {
+            return Self::default();
+        }
+        serde_json::from_slice(bytes).expect("Failed to deserialize weight")
+    }
+}
+
+impl From<&PropertyTextureWeight> for Vec<u8> {
+    fn from(value: &PropertyTextureWeight) -> Self {
+        serde_json::to_vec(value).expect("Failed to serialize weight")
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PropertyTextureProperty {
+    #[serde(default)]
+    pub tex_coord: u32,
+    /// Texture channels containing the property, from least to most significant byte.
+    #[serde(default = "default_channels")]
+    pub channels: Vec<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offset: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scale: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<Value>,
+}
+
+impl Default for PropertyTextureProperty {
+    fn default() -> Self {
+        Self {
+            tex_coord: 0,
+            channels: default_channels(),
+            offset: None,
+            scale: None,
+            max: None,
+            min: None,
+        }
+    }
+}
+
+fn default_channels() -> Vec<u8> {
+    vec![0]
+}
+
+/// Metadata stored in the channels of textures.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct PropertyTexture(pub NodeIndex);
+
+impl From<NodeIndex> for PropertyTexture {
+    fn from(index: NodeIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl From<PropertyTexture> for NodeIndex {
+    fn from(texture: PropertyTexture) -> Self {
+        texture.0
+    }
+}
+
+impl ByteNode<PropertyTextureWeight> for PropertyTexture {}
+impl OtherEdgeHelpers for PropertyTexture {}
+
+impl PropertyTexture {
+    pub fn new(graph: &mut Graph, weight: &PropertyTextureWeight) -> Self {
+        let index = graph.add_node(Weight::Bytes(weight.into()));
+        Self(index)
+    }
+
+    pub fn texture(&self, graph: &Graph, property: &str) -> Option<Texture> {
+        self.find_property(graph, &texture_edge(property))
+    }
+    pub fn set_texture(&self, graph: &mut Graph, property: &str, texture: Option<Texture>) {
+        self.set_property(graph, texture_edge(property), texture);
+    }
+}
+
+fn texture_edge(property: &str) -> String {
+    format!("{TEXTURE_EDGE}/{property}")
+}
@@ -0,0 +1,172 @@
+//! Metadata schema, describing the classes and enums used by property tables,
+//! textures, and attributes.
+
+use std::collections::BTreeMap;
+
+use gltf::json::accessor::{ComponentType, Type};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Schema {
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub classes: BTreeMap<String, Class>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub enums: BTreeMap<String, Enum>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Class {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub properties: BTreeMap<String, ClassProperty>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClassProperty {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(rename = "type")]
+    pub type_: PropertyType,
+    /// Required for numeric types.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub component_type: Option<PropertyComponentType>,
+    /// Required for enum types.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enum_type: Option<String>,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub array: bool,
+    /// Length of fixed-length arrays. Arrays without a count are variable-length.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub count: Option<usize>,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub normalized: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offset: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scale: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<Value>,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub required: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub no_data: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub semantic: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PropertyType {
+    #[default]
+    Scalar,
+    Vec2,
+    Vec3,
+    Vec4,
+    Mat2,
+    Mat3,
+    Mat4,
+    String,
+    Boolean,
+    Enum,
+}
+
+impl PropertyType {
+    /// Accessor element type of the property, if it is stored as numeric elements.
+    pub const fn element_type(self) -> Option<Type> {
+        match self {
+            Self::Scalar | Self::Enum => Some(Type::Scalar),
+            Self::Vec2 => Some(Type::Vec2),
+            Self::Vec3 => Some(Type::Vec3),
+            Self::Vec4 => Some(Type::Vec4),
+            Self::Mat2 => Some(Type::Mat2),
+            Self::Mat3 => Some(Type::Mat3),
+            Self::Mat4 => Some(Type::Mat4),
+            Self::String | Self::Boolean => None,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PropertyComponentType {
+    Int8,
+    Uint8,
+    Int16,
+    #[default]
+    Uint16,
+    Int32,
+    Uint32,
+    Int64,
+    Uint64,
+    Float32,
+    Float64,
+}
+
+impl PropertyComponentType {
+    /// Equivalent accessor component type.
+    /// Returns `None` for types that glTF accessors cannot represent.
+    pub const fn accessor_component_type(self) -> Option<ComponentType> {
+        match self {
+            Self::Int8 => Some(ComponentType::I8),
+            Self::Uint8 => Some(ComponentType::U8),
+            Self::Int16 => Some(ComponentType::I16),
+            Self::Uint16 => Some(ComponentType::U16),
+            Self::Uint32 => Some(ComponentType::U32),
+            Self::Float32 => Some(ComponentType::F32),
+            Self::Int32 | Self::Int64 | Self::Uint64 | Self::Float64 => None,
+        }
+    }
+
+    /// Size of the component in bytes.
+    pub const fn size(self) -> usize {
+        match self {
+            Self::Int8 | Self::Uint8 => 1,
+            Self::Int16 | Self::Uint16 => 2,
+            Self::Int32 | Self::Uint32 | Self::Float32 => 4,
+            Self::Int64 | Self::Uint64 | Self::Float64 => 8,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Enum {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub value_type: PropertyComponentType,
+    pub values: Vec<EnumValue>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct EnumValue {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub value: i64,
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+const fn is_false(value: &bool) -> bool {
+    !*value
+}
@@ -0,0 +1,84 @@
+use std::error::Error;
+
+use tracing::warn;
+
+use crate::{
+    extensions::ExtensionExport,
+    graph::{Extensions, Graph, GraphNodeWeight, gltf::document::GltfDocument},
+    io::format::gltf::GltfFormat,
+};
+
+use super::{
+    EXTENSION_NAME, KhrAnimationPointer,
+    json::{ChannelJson, RootExtension},
+};
+
+impl ExtensionExport<GltfDocument, GltfFormat> for KhrAnimationPointer {
+    fn export(
+        graph: &mut Graph,
+        doc: &GltfDocument,
+        format: &mut GltfFormat,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut channels = Vec::new();
+
+        for (i, animation) in doc.animations(graph).iter().enumerate() {
+            // Samplers are exported in the order they are first used by a channel.
+            let mut samplers = Vec::new();
+
+            for channel in animation.channels(graph) {
+                let Some(sampler) = channel.sampler(graph) else {
+                    continue;
+                };
+
+                let sampler_index =
+                    samplers
+                        .iter()
+                        .position(|s| *s == sampler)
+                        .unwrap_or_else(|| {
+                            samplers.push(sampler);
+                            samplers.len() - 1
+                        });
+
+                let Some(ext) = channel.get_extension::<Self>(graph) else {
+                    continue;
+                };
+
+                let Some(pointer) = ext.resolve(graph, doc) else {
+                    warn!("Pointer target not found in document");
+                    continue;
+                };
+
+                let extras = channel
+                    .get(graph)
+                    .extras
+                    .as_ref()
+                    .and_then(|extras| serde_json::from_str(extras.get()).ok());
+
+                channels.push(ChannelJson {
+                    animation: i,
+                    sampler: sampler_index,
+                    pointer,
+                    extras,
+                });
+            }
+        }
+
+        if channels.is_empty() {
+            return Ok(());
+        }
+
+        let extensions = format
+            .json
+            .extensions
+            .get_or_insert_with(gltf::json::extensions::Root::default);
+
+        extensions.others.insert(
+            EXTENSION_NAME.to_string(),
+            serde_json::to_value(RootExtension { channels })?,
+        );
+
+        format.json.extensions_used.push(EXTENSION_NAME.to_string());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+use std::{
+    collections::{HashMap, hash_map::Entry},
+    error::Error,
+};
+
+use gltf::json::validation::Checked;
+use tracing::warn;
+
+use crate::{
+    extensions::ExtensionImport,
+    graph::{
+        ByteNode, Extensions, Graph, GraphNodeWeight,
+        gltf::{animation::AnimationSampler, document::GltfDocument},
+    },
+    io::format::gltf::GltfFormat,
+};
+
+use super::{
+    EXTENSION_NAME, KhrAnimationPointer, KhrAnimationPointerWeight, PointerTarget,
+    json::RootExtension,
+};
+
+impl ExtensionImport<GltfDocument, GltfFormat> for KhrAnimationPointer {
+    fn supported_extensions() -> Vec<&'static str> {
+        vec![EXTENSION_NAME]
+    }
+
+    fn import(
+        graph: &mut Graph,
+        format: &mut GltfFormat,
+        doc: &GltfDocument,
+    ) -> Result<(), Box<dyn Error>> {
+        let Some(value) = format
+            .json
+            .extensions
+            .as_mut()
+            .and_then(|e| e.others.remove(EXTENSION_NAME))
+        else {
+            return Ok(());
+        };
+
+        let root = serde_json::from_value::<RootExtension>(value)?;
+
+        let animations = doc.animations(graph);
+        let accessors = doc.accessors(graph);
+
+        // Reuse the samplers created for core channels, which are imported in order.
+        let mut samplers = HashMap::new();
+
+        for (i, (animation, json)) in animations.iter().zip(&format.json.animations).enumerate() {
+            for (channel, channel_json) in animation.channels(graph).iter().zip(&json.channels) {
+                if let Some(sampler) = channel.sampler(graph) {
+                    samplers.insert((i, channel_json.sampler.value()), sampler);
+                }
+            }
+        }
+
+        for json in root.channels {
+            let (Some(animation), Some(sampler_json)) = (
+                animations.get(json.animation),
+                format
+                    .json
+                    .animations
+                    .get(json.animation)
+                    .and_then(|a| a.samplers.get(json.sampler)),
+            ) else {
+                warn!("Pointer channel {} has an invalid sampler", json.pointer);
+                continue;
+            };
+
+            let sampler = match samplers.entry((json.animation, json.sampler)) {
+                Entry::Occupied(entry) => *entry.get(),
+                Entry::Vacant(entry) => {
+                    let mut sampler = AnimationSampler::new(graph);
+                    let weight = sampler.get_mut(graph);
+                    weight.extras.clone_from(&sampler_json.extras);
+                    if let Checked::Valid(interpolation) = sampler_json.interpolation {
+                        weight.interpolation = interpolation;
+                    }
+                    sampler.set_input(graph, accessors.get(sampler_json.input.value()).copied());
+                    sampler.set_output(graph, accessors.get(sampler_json.output.value()).copied());
+                    *entry.insert(sampler)
+                }
+            };
+
+            let mut channel = animation.create_channel(graph);
+            channel.set_sampler(graph, Some(sampler));
+            channel.get_mut(graph).extras = json
+                .extras
+                .and_then(|extras| serde_json::value::to_raw_value(&extras).ok());
+
+            let (target, pointer) = split_pointer(graph, *doc, &json.pointer);
+
+            let ext = channel.create_extension::<Self>(graph);
+            ext.write(graph, &KhrAnimationPointerWeight { pointer });
+            ext.set_target(graph, target);
+        }
+
+        Ok(())
+    }
+}
+
+/// Split an absolute pointer into a graph target and a pointer relative to it.
+fn split_pointer(
+    graph: &Graph,
+    doc: GltfDocument,
+    pointer: &str,
+) -> (Option<PointerTarget>, String) {
+    let mut parts = pointer.splitn(4, '/').skip(1);
+
+    let (Some(collection), Some(index)) = (parts.next(), parts.next()) else {
+        return (None, pointer.to_string());
+    };
+
+    let Ok(index) = index.parse::<usize>() else {
+        return (None, pointer.to_string());
+    };
+
+    let target = match collection {
+        "materials" => doc
+            .materials(graph)
+            .get(index)
+            .copied()
+            .map(PointerTarget::Material),
+        "meshes" => doc
+            .meshes(graph)
+            .get(index)
+            .copied()
+            .map(PointerTarget::Mesh),
+        "nodes" => doc
+            .nodes(graph)
+            .get(index)
+            .copied()
+            .map(PointerTarget::Node),
+        _ => None,
+    };
+
+    target.map_or_else(
+        || (None, pointer.to_string()),
+        |target| {
+            let rest = parts.next().map_or_else(String::new, |p| format!("/{p}"));
+            (Some(target), rest)
+        },
+    )
+}
@@ -0,0 +1,186 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use super::EXTENSION_NAME;
+
+/// Channel target path used by pointer channels.
+pub const POINTER_PATH: &str = "pointer";
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+pub struct TargetExtension {
+    pub pointer: String,
+}
+
+/// Pointer channels, stored here while the document is in the JSON format.
+///
+/// `gltf::json` requires every channel to target a node, so pointer channels are
+/// moved out of their animations. See [`take_channels`] and [`restore_channels`].
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+pub struct RootExtension {
+    pub channels: Vec<ChannelJson>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct ChannelJson {
+    pub animation: usize,
+    pub sampler: usize,
+    pub pointer: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extras: Option<Value>,
+}
+
+/// Move pointer channels from the animations into the root extensions.
+/// Returns `true` if any channels were moved.
+pub fn take_channels(root: &mut Value) -> bool {
+    let mut channels = Vec::new();
+
+    let animations = root
+        .get_mut("animations")
+        .and_then(Value::as_array_mut)
+        .into_iter()
+        .flatten();
+
+    for (i, animation) in animations.enumerate() {
+        let Some(list) = animation.get_mut("channels").and_then(Value::as_array_mut) else {
+            continue;
+        };
+
+        list.retain(|channel| {
+            let target = &channel["target"];
+
+            if target["path"] != POINTER_PATH {
+                return true;
+            }
+
+            let pointer = target["extensions"][EXTENSION_NAME]["pointer"].as_str();
+            let sampler = channel["sampler"].as_u64();
+
+            if let (Some(pointer), Some(sampler)) = (pointer, sampler) {
+                channels.push(ChannelJson {
+                    animation: i,
+                    sampler: usize::try_from(sampler).unwrap_or(usize::MAX),
+                    pointer: pointer.to_string(),
+                    extras: channel.get("extras").cloned(),
+                });
+            }
+
+            false
+        });
+    }
+
+    if channels.is_empty() {
+        return false;
+    }
+
+    let Some(object) = root.as_object_mut() else {
+        return false;
+    };
+
+    let extensions = object
+        .entry("extensions")
+        .or_insert_with(|| Value::Object(Map::new()));
+
+    if let Some(extensions) = extensions.as_object_mut() {
+        extensions.insert(
+            EXTENSION_NAME.to_string(),
+            serde_json::to_value(RootExtension { channels }).unwrap_or_default(),
+        );
+    }
+
+    true
+}
+
+/// Move pointer channels from the root extensions back into their animations.
+/// Returns `true` if any channels were moved.
+pub fn restore_channels(root: &mut Value) -> bool {
+    let Some(extensions) = root.get_mut("extensions").and_then(Value::as_object_mut) else {
+        return false;
+    };
+
+    let Some(value) = extensions.remove(EXTENSION_NAME) else {
+        return false;
+    };
+
+    if extensions.is_empty()
+        && let Some(object) = root.as_object_mut()
+    {
+        object.remove("extensions");
+    }
+
+    let Ok(ext) = serde_json::from_value::<RootExtension>(value) else {
+        return false;
+    };
+
+    for channel in ext.channels {
+        let Some(list) = root
+            .get_mut("animations")
+            .and_then(|a| a.get_mut(channel.animation))
+            .and_then(|a| a.get_mut("channels"))
+            .and_then(Value::as_array_mut)
+        else {
+            continue;
+        };
+
+        let mut json = serde_json::json!({
+            "sampler": channel.sampler,
+            "target": {
+                "path": POINTER_PATH,
+                "extensions": {
+                    EXTENSION_NAME: TargetExtension { pointer: channel.pointer },
+                },
+            },
+        });
+
+        if let Some(extras) = channel.extras {
+            json["extras"] = extras;
+        }
+
+        list.push(json);
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn channels_round_trip() {
+        let json = serde_json::json!({
+            "animations": [{
+                "channels": [
+                    { "sampler": 0, "target": { "node": 0, "path": "translation" } },
+                    {
+                        "sampler": 1,
+                        "target": {
+                            "path": "pointer",
+                            "extensions": {
+                                "KHR_animation_pointer": {
+                                    "pointer": "/materials/0/emissiveFactor"
+                                }
+                            }
+                        }
+                    }
+                ],
+                "samplers": []
+            }]
+        });
+
+        let mut value = json.clone();
+        assert!(take_channels(&mut value));
+        assert_eq!(
+            value["animations"][0]["channels"].as_array().map(Vec::len),
+            Some(1)
+        );
+
+        let ext =
+            serde_json::from_value::<RootExtension>(value["extensions"][EXTENSION_NAME].clone())
+                .expect("extension should deserialize");
+        assert_eq!(ext.channels[0].pointer, "/materials/0/emissiveFactor");
+        assert_eq!(ext.channels[0].sampler, 1);
+
+        assert!(restore_channels(&mut value));
+        assert_eq!(value, json);
+    }
+}
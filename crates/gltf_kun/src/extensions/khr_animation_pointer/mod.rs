@@ -0,0 +1,136 @@
+//! [KHR_animation_pointer](https://github.com/KhronosGroup/glTF/tree/main/extensions/2.0/Khronos/KHR_animation_pointer)
+//! extension.
+
+use petgraph::graph::NodeIndex;
+use serde::{Deserialize, Serialize};
+
+use crate::graph::{
+    ByteNode, Graph, OtherEdgeHelpers, Weight,
+    gltf::{GltfWeight, Material, Mesh, Node, document::GltfDocument},
+};
+
+use super::Extension;
+
+pub mod export;
+pub mod import;
+pub mod json;
+
+pub const EXTENSION_NAME: &str = "KHR_animation_pointer";
+
+pub const TARGET_EDGE: &str = "KHR_animation_pointer/target";
+
+/// A graph property that a pointer can be relative to.
+/// Other objects, such as cameras or lights, are referenced by absolute pointers.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum PointerTarget {
+    Material(Material),
+    Mesh(Mesh),
+    Node(Node),
+}
+
+impl From<PointerTarget> for NodeIndex {
+    fn from(target: PointerTarget) -> Self {
+        match target {
+            PointerTarget::Material(m) => m.0,
+            PointerTarget::Mesh(m) => m.0,
+            PointerTarget::Node(n) => n.0,
+        }
+    }
+}
+
+impl PointerTarget {
+    /// Name of the root JSON array containing the target.
+    #[must_use]
+    pub const fn collection(&self) -> &'static str {
+        match self {
+            Self::Material(_) => "materials",
+            Self::Mesh(_) => "meshes",
+            Self::Node(_) => "nodes",
+        }
+    }
+
+    /// Index of the target within the document.
+    pub fn index(&self, graph: &Graph, doc: &GltfDocument) -> Option<usize> {
+        match self {
+            Self::Material(m) => doc.material_index(graph, *m),
+            Self::Mesh(m) => doc.mesh_index(graph, *m),
+            Self::Node(n) => doc.node_index(graph, *n),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+pub struct KhrAnimationPointerWeight {
+    /// JSON pointer to the animated property.
+    /// If the extension has a [`PointerTarget`], this is relative to it.
+    pub pointer: String,
+}
+
+impl From<&Vec<u8>> for KhrAnimationPointerWeight {
+    fn from(bytes: &Vec<u8>) -> Self {
+        if bytes.is_empty() {
+            return Self::default();
+        }
+        serde_json::from_slice(bytes).expect("Failed to deserialize weight")
+    }
+}
+
+impl From<&KhrAnimationPointerWeight> for Vec<u8> {
+    fn from(value: &KhrAnimationPointerWeight) -> Self {
+        serde_json::to_vec(value).expect("Failed to serialize weight")
+    }
+}
+
+/// Animation channel extension, targeting a JSON pointer instead of a node path.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct KhrAnimationPointer(pub NodeIndex);
+
+impl From<NodeIndex> for KhrAnimationPointer {
+    fn from(index: NodeIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl From<KhrAnimationPointer> for NodeIndex {
+    fn from(pointer: KhrAnimationPointer) -> Self {
+        pointer.0
+    }
+}
+
+impl ByteNode<KhrAnimationPointerWeight> for KhrAnimationPointer {}
+impl OtherEdgeHelpers for KhrAnimationPointer {}
+
+impl Extension for KhrAnimationPointer {
+    fn name() -> &'static str {
+        EXTENSION_NAME
+    }
+}
+
+impl KhrAnimationPointer {
+    pub fn target(&self, graph: &Graph) -> Option<PointerTarget> {
+        let index = self.find_property::<NodeIndex>(graph, TARGET_EDGE)?;
+
+        match &graph[index] {
+            Weight::Gltf(GltfWeight::Material(_)) => Some(PointerTarget::Material(index.into())),
+            Weight::Gltf(GltfWeight::Mesh(_)) => Some(PointerTarget::Mesh(index.into())),
+            Weight::Gltf(GltfWeight::Node(_)) => Some(PointerTarget::Node(index.into())),
+            _ => None,
+        }
+    }
+    pub fn set_target(&self, graph: &mut Graph, target: Option<PointerTarget>) {
+        self.set_property(graph, TARGET_EDGE.to_string(), target);
+    }
+
+    /// Absolute JSON pointer, using the current index of the target.
+    pub fn resolve(&self, graph: &Graph, doc: &GltfDocument) -> Option<String> {
+        let pointer = self.read(graph).pointer;
+
+        match self.target(graph) {
+            Some(target) => {
+                let index = target.index(graph, doc)?;
+                Some(format!("/{}/{}{}", target.collection(), index, pointer))
+            }
+            None => Some(pointer),
+        }
+    }
+}
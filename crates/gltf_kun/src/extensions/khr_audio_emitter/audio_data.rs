@@ -0,0 +1,130 @@
+use byteorder::{ByteOrder, LittleEndian};
+use petgraph::graph::NodeIndex;
+use serde::{Deserialize, Serialize};
+
+use crate::graph::{ByteNode, Graph, OtherEdgeHelpers, Weight, gltf::buffer::Buffer};
+
+pub const BUFFER_EDGE: &str = "KHR_audio_emitter/buffer";
+
+/// Encoded audio, stored the same way as an [Image](crate::graph::gltf::image::Image).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AudioDataWeight {
+    pub name: Option<String>,
+    pub mime_type: Option<String>,
+    pub uri: Option<String>,
+    /// Encoded audio bytes.
+    /// May be empty if the data is referenced by an external URI that was not resolved.
+    pub data: Vec<u8>,
+}
+
+#[derive(Default, Deserialize, Serialize)]
+struct AudioDataHeader {
+    name: Option<String>,
+    mime_type: Option<String>,
+    uri: Option<String>,
+}
+
+// Stored as a length-prefixed json header followed by the raw audio bytes,
+// to avoid serializing the audio itself as json.
+impl From<&Vec<u8>> for AudioDataWeight {
+    fn from(bytes: &Vec<u8>) -> Self {
+        if bytes.len() < 4 {
+            return Self::default();
+        }
+
+        let header_len = LittleEndian::read_u32(&bytes[..4]) as usize;
+        let (header, data) = bytes[4..].split_at(header_len);
+        let header: AudioDataHeader =
+            serde_json::from_slice(header).expect("Failed to deserialize weight");
+
+        Self {
+            name: header.name,
+            mime_type: header.mime_type,
+            uri: header.uri,
+            data: data.to_vec(),
+        }
+    }
+}
+
+impl From<&AudioDataWeight> for Vec<u8> {
+    fn from(value: &AudioDataWeight) -> Self {
+        let header = serde_json::to_vec(&AudioDataHeader {
+            name: value.name.clone(),
+            mime_type: value.mime_type.clone(),
+            uri: value.uri.clone(),
+        })
+        .expect("Failed to serialize weight");
+
+        let mut bytes = vec![0; 4];
+        LittleEndian::write_u32(&mut bytes, header.len() as u32);
+        bytes.extend(header);
+        bytes.extend(&value.data);
+        bytes
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct AudioData(pub NodeIndex);
+
+impl From<NodeIndex> for AudioData {
+    fn from(index: NodeIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl From<AudioData> for NodeIndex {
+    fn from(audio_data: AudioData) -> Self {
+        audio_data.0
+    }
+}
+
+impl ByteNode<AudioDataWeight> for AudioData {}
+impl OtherEdgeHelpers for AudioData {}
+
+impl AudioData {
+    pub fn new(graph: &mut Graph, weight: &AudioDataWeight) -> Self {
+        let index = graph.add_node(Weight::Bytes(weight.into()));
+        Self(index)
+    }
+
+    pub fn buffer(&self, graph: &Graph) -> Option<Buffer> {
+        self.find_property(graph, BUFFER_EDGE)
+    }
+    pub fn set_buffer(&self, graph: &mut Graph, buffer: Option<Buffer>) {
+        self.set_property(graph, BUFFER_EDGE.to_string(), buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::GraphNodeWeight;
+
+    use super::*;
+
+    #[test]
+    fn weight_bytes() {
+        let weight = AudioDataWeight {
+            name: Some("Music".to_string()),
+            mime_type: Some("audio/mpeg".to_string()),
+            uri: None,
+            data: vec![0, 1, 2, 3],
+        };
+
+        let bytes: Vec<u8> = (&weight).into();
+        assert_eq!(AudioDataWeight::from(&bytes), weight);
+    }
+
+    #[test]
+    fn buffer() {
+        let graph = &mut Graph::default();
+
+        let audio = AudioData::new(graph, &AudioDataWeight::default());
+        let buffer = Buffer::new(graph);
+
+        audio.set_buffer(graph, Some(buffer));
+        assert_eq!(audio.buffer(graph), Some(buffer));
+
+        audio.set_buffer(graph, None);
+        assert_eq!(audio.buffer(graph), None);
+    }
+}
@@ -0,0 +1,165 @@
+use std::f32::consts::TAU;
+
+use petgraph::graph::NodeIndex;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    extensions::Extension,
+    graph::{ByteNode, Graph, OtherEdgeHelpers, Weight},
+};
+
+use super::{EXTENSION_NAME, audio_source::AudioSource};
+
+pub const SOURCES_EDGE: &str = "KHR_audio_emitter/emitter/source";
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct AudioEmitterWeight {
+    pub name: Option<String>,
+    pub typ: EmitterType,
+    /// Unitless multiplier applied to the volume of all sources.
+    pub gain: f32,
+    /// Only used by [`EmitterType::Positional`] emitters.
+    pub positional: Option<PositionalEmitter>,
+}
+
+impl Default for AudioEmitterWeight {
+    fn default() -> Self {
+        Self {
+            name: None,
+            typ: EmitterType::default(),
+            gain: 1.0,
+            positional: None,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum EmitterType {
+    #[serde(rename = "global")]
+    Global,
+    #[default]
+    #[serde(rename = "positional")]
+    Positional,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct PositionalEmitter {
+    /// Angle in radians of the cone inside which there is no volume reduction.
+    #[serde(default = "default_cone_angle", rename = "coneInnerAngle")]
+    pub cone_inner_angle: f32,
+    /// Angle in radians of the cone outside which the volume is reduced to `cone_outer_gain`.
+    #[serde(default = "default_cone_angle", rename = "coneOuterAngle")]
+    pub cone_outer_angle: f32,
+    #[serde(default, rename = "coneOuterGain")]
+    pub cone_outer_gain: f32,
+    #[serde(default, rename = "distanceModel")]
+    pub distance_model: DistanceModel,
+    /// Distance after which the volume is no longer reduced.
+    /// Only used by [`DistanceModel::Linear`].
+    #[serde(default = "default_max_distance", rename = "maxDistance")]
+    pub max_distance: f32,
+    /// Distance at which volume reduction starts.
+    #[serde(default = "default_one", rename = "refDistance")]
+    pub ref_distance: f32,
+    /// How quickly the volume is reduced with distance.
+    #[serde(default = "default_one", rename = "rolloffFactor")]
+    pub rolloff_factor: f32,
+}
+
+impl Default for PositionalEmitter {
+    fn default() -> Self {
+        Self {
+            cone_inner_angle: default_cone_angle(),
+            cone_outer_angle: default_cone_angle(),
+            cone_outer_gain: 0.0,
+            distance_model: DistanceModel::default(),
+            max_distance: default_max_distance(),
+            ref_distance: default_one(),
+            rolloff_factor: default_one(),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum DistanceModel {
+    #[serde(rename = "linear")]
+    Linear,
+    #[default]
+    #[serde(rename = "inverse")]
+    Inverse,
+    #[serde(rename = "exponential")]
+    Exponential,
+}
+
+const fn default_cone_angle() -> f32 {
+    TAU
+}
+
+const fn default_max_distance() -> f32 {
+    10000.0
+}
+
+const fn default_one() -> f32 {
+    1.0
+}
+
+impl From<&Vec<u8>> for AudioEmitterWeight {
+    fn from(bytes: &Vec<u8>) -> Self {
+        if bytes.is_empty() {
+            return Self::default();
+        }
+        serde_json::from_slice(bytes).expect("Failed to deserialize weight")
+    }
+}
+
+impl From<&AudioEmitterWeight> for Vec<u8> {
+    fn from(value: &AudioEmitterWeight) -> Self {
+        serde_json::to_vec(value).expect("Failed to serialize weight")
+    }
+}
+
+/// An audio emitter.
+///
+/// Emitters are owned by the [`KhrAudioEmitter`](super::KhrAudioEmitter) root extension,
+/// and attached to nodes as an extension, or to scenes with
+/// [`add_scene_emitter`](super::add_scene_emitter).
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct AudioEmitter(pub NodeIndex);
+
+impl From<NodeIndex> for AudioEmitter {
+    fn from(index: NodeIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl From<AudioEmitter> for NodeIndex {
+    fn from(audio_emitter: AudioEmitter) -> Self {
+        audio_emitter.0
+    }
+}
+
+impl ByteNode<AudioEmitterWeight> for AudioEmitter {}
+impl OtherEdgeHelpers for AudioEmitter {}
+
+impl Extension for AudioEmitter {
+    fn name() -> &'static str {
+        EXTENSION_NAME
+    }
+}
+
+impl AudioEmitter {
+    pub fn new(graph: &mut Graph, weight: &AudioEmitterWeight) -> Self {
+        let index = graph.add_node(Weight::Bytes(weight.into()));
+        Self(index)
+    }
+
+    pub fn sources(&self, graph: &Graph) -> Vec<AudioSource> {
+        self.find_properties(graph, SOURCES_EDGE)
+    }
+    pub fn add_source(&self, graph: &mut Graph, source: AudioSource) {
+        self.add_property(graph, SOURCES_EDGE.to_string(), source);
+    }
+    pub fn remove_source(&self, graph: &mut Graph, source: AudioSource) {
+        self.remove_property(graph, SOURCES_EDGE, source);
+    }
+}
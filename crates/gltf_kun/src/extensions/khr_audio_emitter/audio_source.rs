@@ -0,0 +1,77 @@
+use petgraph::graph::NodeIndex;
+use serde::{Deserialize, Serialize};
+
+use crate::graph::{ByteNode, Graph, OtherEdgeHelpers, Weight};
+
+use super::audio_data::AudioData;
+
+pub const DATA_EDGE: &str = "KHR_audio_emitter/source/audio";
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct AudioSourceWeight {
+    pub name: Option<String>,
+    /// Unitless multiplier applied to the audio volume.
+    pub gain: f32,
+    /// Whether the audio should start playing when the scene is loaded.
+    pub auto_play: bool,
+    /// Whether the audio should loop.
+    pub looping: bool,
+}
+
+impl Default for AudioSourceWeight {
+    fn default() -> Self {
+        Self {
+            name: None,
+            gain: 1.0,
+            auto_play: false,
+            looping: false,
+        }
+    }
+}
+
+impl From<&Vec<u8>> for AudioSourceWeight {
+    fn from(bytes: &Vec<u8>) -> Self {
+        if bytes.is_empty() {
+            return Self::default();
+        }
+        serde_json::from_slice(bytes).expect("Failed to deserialize weight")
+    }
+}
+
+impl From<&AudioSourceWeight> for Vec<u8> {
+    fn from(value: &AudioSourceWeight) -> Self {
+        serde_json::to_vec(value).expect("Failed to serialize weight")
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct AudioSource(pub NodeIndex);
+
+impl From<NodeIndex> for AudioSource {
+    fn from(index: NodeIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl From<AudioSource> for NodeIndex {
+    fn from(audio_source: AudioSource) -> Self {
+        audio_source.0
+    }
+}
+
+impl ByteNode<AudioSourceWeight> for AudioSource {}
+impl OtherEdgeHelpers for AudioSource {}
+
+impl AudioSource {
+    pub fn new(graph: &mut Graph, weight: &AudioSourceWeight) -> Self {
+        let index = graph.add_node(Weight::Bytes(weight.into()));
+        Self(index)
+    }
+
+    pub fn audio(&self, graph: &Graph) -> Option<AudioData> {
+        self.find_property(graph, DATA_EDGE)
+    }
+    pub fn set_audio(&self, graph: &mut Graph, audio: Option<AudioData>) {
+        self.set_property(graph, DATA_EDGE.to_string(), audio);
+    }
+}
@@ -0,0 +1,250 @@
+use std::error::Error;
+
+use crate::{
+    extensions::ExtensionExport,
+    graph::{
+        ByteNode, Extensions, Graph,
+        gltf::{buffer::Buffer, document::GltfDocument},
+    },
+    io::format::gltf::GltfFormat,
+};
+
+use super::{
+    EXTENSION_NAME, KhrAudioEmitter,
+    audio_data::AudioData,
+    audio_emitter::AudioEmitter,
+    json::{AudioJson, EmitterJson, NodeExtension, RootExtension, SceneExtension, SourceJson},
+    scene_emitters,
+};
+
+impl ExtensionExport<GltfDocument, GltfFormat> for KhrAudioEmitter {
+    fn export(
+        graph: &mut Graph,
+        doc: &GltfDocument,
+        format: &mut GltfFormat,
+    ) -> Result<(), Box<dyn Error>> {
+        let Some(ext) = doc.get_extension::<Self>(graph) else {
+            return Ok(());
+        };
+
+        let buffers = doc.buffers(graph);
+        let audio = ext.audio(graph);
+        let sources = ext.sources(graph);
+        let emitters = ext.emitters(graph);
+
+        if audio.is_empty() && sources.is_empty() && emitters.is_empty() {
+            return Ok(());
+        }
+
+        let root_audio = audio
+            .iter()
+            .enumerate()
+            .map(|(i, audio)| export_audio(graph, format, &buffers, i, *audio))
+            .collect();
+
+        let root_sources = sources
+            .iter()
+            .map(|source| {
+                let weight = source.read(graph);
+
+                SourceJson {
+                    name: weight.name,
+                    gain: weight.gain,
+                    auto_play: weight.auto_play,
+                    looping: weight.looping,
+                    audio: source
+                        .audio(graph)
+                        .and_then(|a| audio.iter().position(|x| *x == a)),
+                }
+            })
+            .collect();
+
+        let root_emitters = emitters
+            .iter()
+            .map(|emitter| {
+                let weight = emitter.read(graph);
+
+                EmitterJson {
+                    name: weight.name,
+                    typ: weight.typ,
+                    gain: weight.gain,
+                    sources: emitter
+                        .sources(graph)
+                        .iter()
+                        .filter_map(|s| sources.iter().position(|x| x == s))
+                        .collect(),
+                    positional: weight.positional,
+                }
+            })
+            .collect();
+
+        let root = RootExtension {
+            audio: root_audio,
+            sources: root_sources,
+            emitters: root_emitters,
+        };
+
+        export_refs(graph, *doc, format, &emitters)?;
+
+        let extensions = format
+            .json
+            .extensions
+            .get_or_insert_with(gltf::json::extensions::Root::default);
+
+        extensions
+            .others
+            .insert(EXTENSION_NAME.to_string(), serde_json::to_value(root)?);
+
+        format.json.extensions_used.push(EXTENSION_NAME.to_string());
+
+        Ok(())
+    }
+}
+
+/// Writes emitter references on nodes and scenes.
+fn export_refs(
+    graph: &Graph,
+    doc: GltfDocument,
+    format: &mut GltfFormat,
+    emitters: &[AudioEmitter],
+) -> Result<(), Box<dyn Error>> {
+    for (i, node) in doc.nodes(graph).iter().enumerate() {
+        let Some(emitter) = node
+            .get_extension::<AudioEmitter>(graph)
+            .and_then(|e| emitters.iter().position(|x| *x == e))
+        else {
+            continue;
+        };
+
+        let node = format
+            .json
+            .nodes
+            .get_mut(i)
+            .expect("Node index out of bounds");
+
+        let extensions = node
+            .extensions
+            .get_or_insert_with(gltf::json::extensions::scene::Node::default);
+
+        extensions.others.insert(
+            EXTENSION_NAME.to_string(),
+            serde_json::to_value(NodeExtension { emitter })?,
+        );
+    }
+
+    for (i, scene) in doc.scenes(graph).iter().enumerate() {
+        let indices = scene_emitters(graph, *scene)
+            .iter()
+            .filter_map(|e| emitters.iter().position(|x| x == e))
+            .collect::<Vec<_>>();
+
+        if indices.is_empty() {
+            continue;
+        }
+
+        let scene = format
+            .json
+            .scenes
+            .get_mut(i)
+            .expect("Scene index out of bounds");
+
+        let extensions = scene
+            .extensions
+            .get_or_insert_with(gltf::json::extensions::scene::Scene::default);
+
+        extensions.others.insert(
+            EXTENSION_NAME.to_string(),
+            serde_json::to_value(SceneExtension { emitters: indices })?,
+        );
+    }
+
+    Ok(())
+}
+
+fn export_audio(
+    graph: &Graph,
+    format: &mut GltfFormat,
+    buffers: &[Buffer],
+    index: usize,
+    audio: AudioData,
+) -> AudioJson {
+    let weight = audio.read(graph);
+
+    let mut json = AudioJson {
+        name: weight.name,
+        mime_type: weight
+            .mime_type
+            .or_else(|| guess_mime_type(&weight.data).map(ToString::to_string)),
+        ..Default::default()
+    };
+
+    if weight.data.is_empty() {
+        json.uri = weight.uri;
+        return json;
+    }
+
+    // Prefer the audio's buffer, then its URI, then the first buffer.
+    let buffer = audio.buffer(graph).or_else(|| {
+        weight
+            .uri
+            .is_none()
+            .then(|| buffers.first().copied())
+            .flatten()
+    });
+
+    if let Some(buffer_idx) = buffer.and_then(|b| buffers.iter().position(|x| *x == b)) {
+        json.buffer_view = format.push_buffer_view(buffer_idx, &weight.data);
+    } else {
+        let uri = weight.uri.unwrap_or_else(|| {
+            let ext = json
+                .mime_type
+                .as_deref()
+                .map(file_extension)
+                .unwrap_or_default();
+            format!("audio_{index}{ext}")
+        });
+
+        format.resources.insert(uri.clone(), weight.data);
+        json.uri = Some(uri);
+    }
+
+    json
+}
+
+/// Guess the mime type of encoded audio from its magic bytes.
+fn guess_mime_type(data: &[u8]) -> Option<&'static str> {
+    if data.starts_with(b"RIFF") && data.get(8..12) == Some(b"WAVE") {
+        Some("audio/wav")
+    } else if data.starts_with(b"OggS") {
+        Some("audio/ogg")
+    } else if data.starts_with(b"fLaC") {
+        Some("audio/flac")
+    } else if data.starts_with(b"ID3") || data.starts_with(&[0xFF, 0xFB]) {
+        Some("audio/mpeg")
+    } else {
+        None
+    }
+}
+
+fn file_extension(mime_type: &str) -> &'static str {
+    match mime_type {
+        "audio/mpeg" => ".mp3",
+        "audio/ogg" => ".ogg",
+        "audio/wav" => ".wav",
+        "audio/flac" => ".flac",
+        _ => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mime_type() {
+        assert_eq!(guess_mime_type(b"RIFF\0\0\0\0WAVEfmt "), Some("audio/wav"));
+        assert_eq!(guess_mime_type(b"OggS\0"), Some("audio/ogg"));
+        assert_eq!(guess_mime_type(b"ID3\x04"), Some("audio/mpeg"));
+        assert_eq!(guess_mime_type(&[0, 1, 2, 3]), None);
+    }
+}
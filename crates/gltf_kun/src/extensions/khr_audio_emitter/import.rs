@@ -0,0 +1,176 @@
+use std::error::Error;
+
+use tracing::warn;
+
+use crate::{
+    extensions::ExtensionImport,
+    graph::{Extensions, Graph, gltf::document::GltfDocument},
+    io::{format::gltf::GltfFormat, resolver::DataUriResolver},
+};
+
+use super::{
+    EXTENSION_NAME, KhrAudioEmitter, OMI_EXTENSION_NAME, add_scene_emitter,
+    audio_data::AudioDataWeight,
+    audio_emitter::AudioEmitterWeight,
+    audio_source::AudioSourceWeight,
+    json::{AudioJson, NodeExtension, RootExtension, SceneExtension},
+};
+
+impl ExtensionImport<GltfDocument, GltfFormat> for KhrAudioEmitter {
+    fn supported_extensions() -> Vec<&'static str> {
+        vec![EXTENSION_NAME, OMI_EXTENSION_NAME]
+    }
+
+    fn import(
+        graph: &mut Graph,
+        format: &mut GltfFormat,
+        doc: &GltfDocument,
+    ) -> Result<(), Box<dyn Error>> {
+        let Some(extensions) = &format.json.extensions else {
+            return Ok(());
+        };
+
+        let Some((name, value)) = [EXTENSION_NAME, OMI_EXTENSION_NAME]
+            .into_iter()
+            .find_map(|name| extensions.others.get(name).map(|v| (name, v)))
+        else {
+            return Ok(());
+        };
+
+        let root = serde_json::from_value::<RootExtension>(value.clone())?;
+
+        let ext = doc
+            .get_extension::<Self>(graph)
+            .unwrap_or_else(|| doc.create_extension::<Self>(graph));
+
+        let buffers = doc.buffers(graph);
+
+        let audio = root
+            .audio
+            .into_iter()
+            .map(|json| {
+                let buffer = json
+                    .buffer_view
+                    .and_then(|i| format.json.buffer_views.get(i))
+                    .and_then(|view| buffers.get(view.buffer.value()).copied());
+
+                let weight = read_audio(format, json);
+
+                let audio = ext.create_audio(graph, &weight);
+                audio.set_buffer(graph, buffer);
+                audio
+            })
+            .collect::<Vec<_>>();
+
+        let sources = root
+            .sources
+            .into_iter()
+            .map(|json| {
+                let source = ext.create_source(
+                    graph,
+                    &AudioSourceWeight {
+                        name: json.name,
+                        gain: json.gain,
+                        auto_play: json.auto_play,
+                        looping: json.looping,
+                    },
+                );
+
+                let audio = json.audio.and_then(|i| audio.get(i).copied());
+                source.set_audio(graph, audio);
+
+                source
+            })
+            .collect::<Vec<_>>();
+
+        let emitters = root
+            .emitters
+            .into_iter()
+            .map(|json| {
+                let emitter = ext.create_emitter(
+                    graph,
+                    &AudioEmitterWeight {
+                        name: json.name,
+                        typ: json.typ,
+                        gain: json.gain,
+                        positional: json.positional,
+                    },
+                );
+
+                for source in json.sources.iter().filter_map(|i| sources.get(*i)) {
+                    emitter.add_source(graph, *source);
+                }
+
+                emitter
+            })
+            .collect::<Vec<_>>();
+
+        let nodes = doc.nodes(graph);
+
+        for (i, node) in format.json.nodes.iter().enumerate() {
+            let Some(value) = node.extensions.as_ref().and_then(|e| e.others.get(name)) else {
+                continue;
+            };
+
+            let json = serde_json::from_value::<NodeExtension>(value.clone())?;
+
+            let Some(emitter) = emitters.get(json.emitter) else {
+                warn!("Audio emitter index {} out of bounds", json.emitter);
+                continue;
+            };
+
+            let node = nodes.get(i).expect("Node index out of bounds");
+            node.add_extension(graph, *emitter);
+        }
+
+        let scenes = doc.scenes(graph);
+
+        for (i, scene) in format.json.scenes.iter().enumerate() {
+            let Some(value) = scene.extensions.as_ref().and_then(|e| e.others.get(name)) else {
+                continue;
+            };
+
+            let json = serde_json::from_value::<SceneExtension>(value.clone())?;
+            let scene = scenes.get(i).expect("Scene index out of bounds");
+
+            for index in json.emitters {
+                let Some(emitter) = emitters.get(index) else {
+                    warn!("Audio emitter index {} out of bounds", index);
+                    continue;
+                };
+
+                add_scene_emitter(graph, *scene, *emitter);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn read_audio(format: &GltfFormat, json: AudioJson) -> AudioDataWeight {
+    let mut weight = AudioDataWeight {
+        name: json.name,
+        mime_type: json.mime_type,
+        uri: json.uri,
+        data: Vec::new(),
+    };
+
+    if let Some(uri) = weight.uri.as_ref() {
+        if let Some(data) = format.resources.get(uri) {
+            weight.data.clone_from(data);
+        } else if uri.starts_with("data:") {
+            match DataUriResolver::decode(uri) {
+                Ok(data) => weight.data = data,
+                Err(e) => warn!("Failed to decode audio data URI: {}", e),
+            }
+        }
+    } else if let Some(view) = json.buffer_view {
+        if let Some(data) = format.read_buffer_view(view) {
+            weight.data = data.to_vec();
+        } else {
+            warn!("Failed to read audio buffer view {}", view);
+        }
+    }
+
+    weight
+}
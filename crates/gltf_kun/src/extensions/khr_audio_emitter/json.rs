@@ -0,0 +1,125 @@
+use serde::{Deserialize, Serialize};
+
+use super::audio_emitter::{EmitterType, PositionalEmitter};
+
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct RootExtension {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub audio: Vec<AudioJson>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sources: Vec<SourceJson>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub emitters: Vec<EmitterJson>,
+}
+
+#[derive(Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct AudioJson {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+    #[serde(
+        default,
+        rename = "bufferView",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub buffer_view: Option<usize>,
+    #[serde(default, rename = "mimeType", skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct SourceJson {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default = "default_gain", skip_serializing_if = "is_default_gain")]
+    pub gain: f32,
+    #[serde(default, rename = "autoPlay", skip_serializing_if = "is_false")]
+    pub auto_play: bool,
+    #[serde(default, rename = "loop", skip_serializing_if = "is_false")]
+    pub looping: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audio: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct EmitterJson {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "type")]
+    pub typ: EmitterType,
+    #[serde(default = "default_gain", skip_serializing_if = "is_default_gain")]
+    pub gain: f32,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sources: Vec<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub positional: Option<PositionalEmitter>,
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct NodeExtension {
+    pub emitter: usize,
+}
+
+#[derive(Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SceneExtension {
+    #[serde(default)]
+    pub emitters: Vec<usize>,
+}
+
+const fn default_gain() -> f32 {
+    1.0
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn is_default_gain(gain: &f32) -> bool {
+    (*gain - default_gain()).abs() < f32::EPSILON
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+const fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn root_serde() {
+        let json = RootExtension {
+            audio: vec![AudioJson {
+                buffer_view: Some(0),
+                mime_type: Some("audio/mpeg".to_string()),
+                ..Default::default()
+            }],
+            sources: vec![SourceJson {
+                name: None,
+                gain: 0.5,
+                auto_play: true,
+                looping: true,
+                audio: Some(0),
+            }],
+            emitters: vec![EmitterJson {
+                name: None,
+                typ: EmitterType::Positional,
+                gain: 1.0,
+                sources: vec![0],
+                positional: Some(PositionalEmitter::default()),
+            }],
+        };
+
+        let json_str = serde_json::to_string(&json).expect("json should serialize");
+        let json_2 =
+            serde_json::from_str::<RootExtension>(&json_str).expect("json should deserialize");
+        assert_eq!(json, json_2);
+    }
+
+    #[test]
+    fn source_defaults() {
+        let source = serde_json::from_str::<SourceJson>("{}").expect("json should deserialize");
+        assert!((source.gain - 1.0).abs() < f32::EPSILON);
+        assert!(!source.auto_play);
+        assert!(!source.looping);
+    }
+}
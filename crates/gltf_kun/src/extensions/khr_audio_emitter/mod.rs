@@ -0,0 +1,115 @@
+//! [KHR_audio_emitter](https://github.com/KhronosGroup/glTF/pull/2137) extension.
+//!
+//! [OMI_audio_emitter](https://github.com/omigroup/gltf-extensions/tree/main/extensions/2.0/OMI_audio_emitter)
+//! shares the same schema, and is also read on import.
+//! Export always writes `KHR_audio_emitter`.
+
+use petgraph::graph::NodeIndex;
+
+use crate::graph::{Graph, OtherEdgeHelpers, gltf::Scene};
+
+use self::{
+    audio_data::{AudioData, AudioDataWeight},
+    audio_emitter::{AudioEmitter, AudioEmitterWeight},
+    audio_source::{AudioSource, AudioSourceWeight},
+};
+
+use super::Extension;
+
+pub mod audio_data;
+pub mod audio_emitter;
+pub mod audio_source;
+pub mod export;
+pub mod import;
+pub mod json;
+
+pub const EXTENSION_NAME: &str = "KHR_audio_emitter";
+pub const OMI_EXTENSION_NAME: &str = "OMI_audio_emitter";
+
+pub const AUDIO_EDGE: &str = "KHR_audio_emitter/audio";
+pub const EMITTER_EDGE: &str = "KHR_audio_emitter/emitter";
+pub const SOURCE_EDGE: &str = "KHR_audio_emitter/source";
+pub const SCENE_EMITTER_EDGE: &str = "KHR_audio_emitter/scene/emitter";
+
+/// Document-level audio data, sources and emitters.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct KhrAudioEmitter(pub NodeIndex);
+
+impl From<NodeIndex> for KhrAudioEmitter {
+    fn from(index: NodeIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl From<KhrAudioEmitter> for NodeIndex {
+    fn from(audio_emitter: KhrAudioEmitter) -> Self {
+        audio_emitter.0
+    }
+}
+
+impl OtherEdgeHelpers for KhrAudioEmitter {}
+
+impl Extension for KhrAudioEmitter {
+    fn name() -> &'static str {
+        EXTENSION_NAME
+    }
+}
+
+impl KhrAudioEmitter {
+    pub fn audio(&self, graph: &Graph) -> Vec<AudioData> {
+        self.find_properties(graph, AUDIO_EDGE)
+    }
+    pub fn add_audio(&self, graph: &mut Graph, audio: AudioData) {
+        self.add_property(graph, AUDIO_EDGE.to_string(), audio);
+    }
+    pub fn remove_audio(&self, graph: &mut Graph, audio: AudioData) {
+        self.remove_property(graph, AUDIO_EDGE, audio);
+    }
+    pub fn create_audio(&self, graph: &mut Graph, weight: &AudioDataWeight) -> AudioData {
+        let audio = AudioData::new(graph, weight);
+        self.add_audio(graph, audio);
+        audio
+    }
+
+    pub fn sources(&self, graph: &Graph) -> Vec<AudioSource> {
+        self.find_properties(graph, SOURCE_EDGE)
+    }
+    pub fn add_source(&self, graph: &mut Graph, source: AudioSource) {
+        self.add_property(graph, SOURCE_EDGE.to_string(), source);
+    }
+    pub fn remove_source(&self, graph: &mut Graph, source: AudioSource) {
+        self.remove_property(graph, SOURCE_EDGE, source);
+    }
+    pub fn create_source(&self, graph: &mut Graph, weight: &AudioSourceWeight) -> AudioSource {
+        let source = AudioSource::new(graph, weight);
+        self.add_source(graph, source);
+        source
+    }
+
+    pub fn emitters(&self, graph: &Graph) -> Vec<AudioEmitter> {
+        self.find_properties(graph, EMITTER_EDGE)
+    }
+    pub fn add_emitter(&self, graph: &mut Graph, emitter: AudioEmitter) {
+        self.add_property(graph, EMITTER_EDGE.to_string(), emitter);
+    }
+    pub fn remove_emitter(&self, graph: &mut Graph, emitter: AudioEmitter) {
+        self.remove_property(graph, EMITTER_EDGE, emitter);
+    }
+    pub fn create_emitter(&self, graph: &mut Graph, weight: &AudioEmitterWeight) -> AudioEmitter {
+        let emitter = AudioEmitter::new(graph, weight);
+        self.add_emitter(graph, emitter);
+        emitter
+    }
+}
+
+/// Emitters played by a scene, such as global ambient audio.
+/// Node emitters are attached to nodes as an extension instead.
+pub fn scene_emitters(graph: &Graph, scene: Scene) -> Vec<AudioEmitter> {
+    scene.find_properties(graph, SCENE_EMITTER_EDGE)
+}
+pub fn add_scene_emitter(graph: &mut Graph, scene: Scene, emitter: AudioEmitter) {
+    scene.add_property(graph, SCENE_EMITTER_EDGE.to_string(), emitter);
+}
+pub fn remove_scene_emitter(graph: &mut Graph, scene: Scene, emitter: AudioEmitter) {
+    scene.remove_property(graph, SCENE_EMITTER_EDGE, emitter);
+}
@@ -0,0 +1,45 @@
+use std::error::Error;
+
+use crate::{
+    extensions::ExtensionExport,
+    graph::{ByteNode, Extensions, Graph, gltf::document::GltfDocument},
+    io::format::gltf::GltfFormat,
+};
+
+use super::{EXTENSION_NAME, KhrMaterialsDispersion};
+
+impl ExtensionExport<GltfDocument, GltfFormat> for KhrMaterialsDispersion {
+    fn export(
+        graph: &mut Graph,
+        doc: &GltfDocument,
+        format: &mut GltfFormat,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut used = false;
+
+        for (material, material_json) in doc
+            .materials(graph)
+            .iter()
+            .zip(format.json.materials.iter_mut())
+        {
+            let Some(ext) = material.get_extension::<Self>(graph) else {
+                continue;
+            };
+
+            let weight = ext.read(graph);
+
+            material_json
+                .extensions
+                .get_or_insert_with(gltf::json::extensions::material::Material::default)
+                .others
+                .insert(EXTENSION_NAME.to_string(), serde_json::to_value(weight)?);
+
+            used = true;
+        }
+
+        if used {
+            format.json.extensions_used.push(EXTENSION_NAME.to_string());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+use std::error::Error;
+
+use crate::{
+    extensions::ExtensionImport,
+    graph::{ByteNode, Extensions, Graph, gltf::document::GltfDocument},
+    io::format::gltf::GltfFormat,
+};
+
+use super::{EXTENSION_NAME, KhrMaterialsDispersion, weight::DispersionWeight};
+
+impl ExtensionImport<GltfDocument, GltfFormat> for KhrMaterialsDispersion {
+    fn supported_extensions() -> Vec<&'static str> {
+        vec![EXTENSION_NAME]
+    }
+
+    fn import(
+        graph: &mut Graph,
+        format: &mut GltfFormat,
+        doc: &GltfDocument,
+    ) -> Result<(), Box<dyn Error>> {
+        let materials = doc.materials(graph);
+
+        for (material, material_json) in materials.iter().zip(format.json.materials.iter()) {
+            let Some(value) = material_json
+                .extensions
+                .as_ref()
+                .and_then(|e| e.others.get(EXTENSION_NAME))
+            else {
+                continue;
+            };
+
+            let weight = serde_json::from_value::<DispersionWeight>(value.clone())?;
+
+            let ext = material.create_extension::<Self>(graph);
+            ext.write(graph, &weight);
+        }
+
+        Ok(())
+    }
+}
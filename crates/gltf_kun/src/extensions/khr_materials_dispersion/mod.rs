@@ -0,0 +1,40 @@
+//! [KHR_materials_dispersion](https://github.com/KhronosGroup/glTF/tree/main/extensions/2.0/Khronos/KHR_materials_dispersion)
+//! extension.
+
+use petgraph::graph::NodeIndex;
+
+use crate::graph::ByteNode;
+
+use self::weight::DispersionWeight;
+
+use super::Extension;
+
+pub mod export;
+pub mod import;
+pub mod weight;
+
+pub const EXTENSION_NAME: &str = "KHR_materials_dispersion";
+
+/// Dispersion of a volumetric material, added as a material extension.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct KhrMaterialsDispersion(pub NodeIndex);
+
+impl From<NodeIndex> for KhrMaterialsDispersion {
+    fn from(index: NodeIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl From<KhrMaterialsDispersion> for NodeIndex {
+    fn from(dispersion: KhrMaterialsDispersion) -> Self {
+        dispersion.0
+    }
+}
+
+impl ByteNode<DispersionWeight> for KhrMaterialsDispersion {}
+
+impl Extension for KhrMaterialsDispersion {
+    fn name() -> &'static str {
+        EXTENSION_NAME
+    }
+}
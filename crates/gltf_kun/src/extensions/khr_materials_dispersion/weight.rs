@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct DispersionWeight {
+    /// Strength of the dispersion effect, as 20 / Abbe number.
+    #[serde(default)]
+    pub dispersion: f32,
+}
+
+impl From<&Vec<u8>> for DispersionWeight {
+    fn from(bytes: &Vec<u8>) -> Self {
+        if bytes.is_empty() {
+            return Self::default();
+        }
+        serde_json::from_slice(bytes).expect("Failed to deserialize weight")
+    }
+}
+
+impl From<&DispersionWeight> for Vec<u8> {
+    fn from(value: &DispersionWeight) -> Self {
+        serde_json::to_vec(value).expect("Failed to serialize weight")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dispersion_serde() {
+        let weight = DispersionWeight { dispersion: 0.5 };
+
+        let json = serde_json::to_string(&weight).expect("json should serialize");
+        assert_eq!(json, r#"{"dispersion":0.5}"#);
+
+        let weight_2 =
+            serde_json::from_str::<DispersionWeight>(&json).expect("json should deserialize");
+        assert_eq!(weight, weight_2);
+
+        let weight =
+            serde_json::from_str::<DispersionWeight>("{}").expect("json should deserialize");
+        assert_eq!(weight, DispersionWeight::default());
+    }
+}
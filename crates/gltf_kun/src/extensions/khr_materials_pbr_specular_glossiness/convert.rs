@@ -0,0 +1,395 @@
+//! Conversion from specular-glossiness to metallic-roughness materials.
+//!
+//! Based on the [Khronos reference conversion](https://github.com/KhronosGroup/glTF/blob/main/extensions/2.0/Archived/KHR_materials_pbrSpecularGlossiness/examples/convert-between-workflows/js/three.pbrUtilities.js).
+
+use std::{error::Error, io::Cursor};
+
+use image::{ImageFormat, Rgba, RgbaImage};
+use thiserror::Error;
+use tracing::warn;
+
+use crate::{
+    graph::{
+        ByteNode, Extensions, Graph, GraphNodeWeight,
+        gltf::{Material, Texture, document::GltfDocument},
+    },
+    transform::Transform,
+};
+
+use super::{EXTENSION_NAME, KhrMaterialsPbrSpecularGlossiness, SpecularGlossinessWeight};
+
+const DIELECTRIC_SPECULAR: f32 = 0.04;
+const EPSILON: f32 = 1e-6;
+
+#[derive(Debug, Error)]
+pub enum ConvertError {
+    #[error(transparent)]
+    Image(#[from] image::ImageError),
+}
+
+/// Metallic-roughness factors of a single texel or material.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MetallicRoughness {
+    /// Linear RGBA base color.
+    pub base_color: [f32; 4],
+    pub metallic: f32,
+    pub roughness: f32,
+}
+
+/// Converts linear specular-glossiness values to metallic-roughness.
+pub fn to_metallic_roughness(
+    diffuse: [f32; 4],
+    specular: [f32; 3],
+    glossiness: f32,
+) -> MetallicRoughness {
+    let one_minus_specular_strength = 1.0 - specular[0].max(specular[1]).max(specular[2]);
+
+    let metallic = solve_metallic(
+        perceived_brightness(&diffuse[..3]),
+        perceived_brightness(&specular),
+        one_minus_specular_strength,
+    );
+
+    let diffuse_scale =
+        one_minus_specular_strength / (1.0 - DIELECTRIC_SPECULAR) / (1.0 - metallic).max(EPSILON);
+    let specular_scale = 1.0 / metallic.max(EPSILON);
+    let t = metallic * metallic;
+
+    let mut base_color = [0.0, 0.0, 0.0, diffuse[3]];
+
+    for i in 0..3 {
+        let from_diffuse = diffuse[i] * diffuse_scale;
+        let from_specular =
+            DIELECTRIC_SPECULAR.mul_add(metallic - 1.0, specular[i]) * specular_scale;
+        base_color[i] = (from_specular - from_diffuse)
+            .mul_add(t, from_diffuse)
+            .clamp(0.0, 1.0);
+    }
+
+    MetallicRoughness {
+        base_color,
+        metallic,
+        roughness: 1.0 - glossiness,
+    }
+}
+
+fn perceived_brightness(color: &[f32]) -> f32 {
+    0.299f32
+        .mul_add(
+            color[0] * color[0],
+            0.587f32.mul_add(color[1] * color[1], 0.114 * color[2] * color[2]),
+        )
+        .sqrt()
+}
+
+fn solve_metallic(diffuse: f32, specular: f32, one_minus_specular_strength: f32) -> f32 {
+    if specular < DIELECTRIC_SPECULAR {
+        return 0.0;
+    }
+
+    let a = DIELECTRIC_SPECULAR;
+    let b = (diffuse / (1.0 - DIELECTRIC_SPECULAR)).mul_add(
+        one_minus_specular_strength,
+        2.0f32.mul_add(-DIELECTRIC_SPECULAR, specular),
+    );
+    let c = DIELECTRIC_SPECULAR - specular;
+    let d = b.mul_add(b, -4.0 * a * c).max(0.0);
+
+    ((-b + d.sqrt()) / (2.0 * a)).clamp(0.0, 1.0)
+}
+
+/// Converts every specular-glossiness material in the document to metallic-roughness,
+/// removing the extension.
+pub fn convert(graph: &mut Graph, doc: &GltfDocument) -> Result<(), ConvertError> {
+    for material in doc.materials(graph) {
+        convert_material(graph, doc, material)?;
+    }
+
+    Ok(())
+}
+
+/// [`Transform`] running [`convert`].
+pub struct ConvertSpecularGlossiness;
+
+impl Transform for ConvertSpecularGlossiness {
+    fn name(&self) -> &'static str {
+        "convert_specular_glossiness"
+    }
+
+    fn apply(&self, graph: &mut Graph, doc: GltfDocument) -> Result<(), Box<dyn Error>> {
+        convert(graph, &doc)?;
+        Ok(())
+    }
+}
+
+/// Converts a specular-glossiness material to metallic-roughness, removing the extension.
+///
+/// If the material has a specular-glossiness texture, new base color and
+/// metallic-roughness textures are baked and added to the document.
+/// Otherwise, or if the textures cannot be decoded, only the factors are converted,
+/// and the diffuse texture is reused as the base color texture.
+pub fn convert_material(
+    graph: &mut Graph,
+    doc: &GltfDocument,
+    mut material: Material,
+) -> Result<(), ConvertError> {
+    let Some(ext) = material.get_extension::<KhrMaterialsPbrSpecularGlossiness>(graph) else {
+        return Ok(());
+    };
+
+    let weight = ext.read(graph);
+    let diffuse_texture = ext.diffuse_texture(graph);
+
+    let baked = match ext.specular_glossiness_texture(graph) {
+        Some(texture) => bake(graph, *doc, &weight, texture, diffuse_texture)?,
+        None => None,
+    };
+
+    if let Some((base_color_texture, metallic_roughness_texture)) = baked {
+        let material_weight = material.get_mut(graph);
+        material_weight.base_color_factor = [1.0, 1.0, 1.0, 1.0];
+        material_weight.metallic_factor = 1.0;
+        material_weight.roughness_factor = 1.0;
+        material_weight.base_color_tex_coord = weight.specular_glossiness_tex_coord;
+        material_weight.metallic_roughness_tex_coord = weight.specular_glossiness_tex_coord;
+
+        material.set_base_color_texture(graph, Some(base_color_texture));
+        material.set_metallic_roughness_texture(graph, Some(metallic_roughness_texture));
+    } else {
+        let out = to_metallic_roughness(
+            weight.diffuse_factor,
+            weight.specular_factor,
+            weight.glossiness_factor,
+        );
+
+        let material_weight = material.get_mut(graph);
+        material_weight.base_color_factor = out.base_color;
+        material_weight.metallic_factor = out.metallic;
+        material_weight.roughness_factor = out.roughness;
+        material_weight.base_color_tex_coord = weight.diffuse_tex_coord;
+
+        material.set_base_color_texture(graph, diffuse_texture);
+        material.set_metallic_roughness_texture(graph, None);
+    }
+
+    material.remove_extension(graph, EXTENSION_NAME);
+
+    Ok(())
+}
+
+/// Bakes base color and metallic-roughness textures at the resolution of the
+/// specular-glossiness texture.
+fn bake(
+    graph: &mut Graph,
+    doc: GltfDocument,
+    weight: &SpecularGlossinessWeight,
+    specular_glossiness_texture: Texture,
+    diffuse_texture: Option<Texture>,
+) -> Result<Option<(Texture, Texture)>, ConvertError> {
+    let specular_glossiness = match decode(graph, specular_glossiness_texture) {
+        Ok(Some(image)) => image,
+        Ok(None) => {
+            warn!("Specular-glossiness texture has no image data, converting factors only");
+            return Ok(None);
+        }
+        Err(e) => {
+            warn!("Failed to decode specular-glossiness texture, converting factors only: {e}");
+            return Ok(None);
+        }
+    };
+
+    let diffuse = match diffuse_texture {
+        Some(_) if weight.diffuse_tex_coord != weight.specular_glossiness_tex_coord => {
+            warn!(
+                "Diffuse and specular-glossiness textures use different UV sets, ignoring diffuse texture"
+            );
+            None
+        }
+        Some(texture) => match decode(graph, texture) {
+            Ok(image) => image,
+            Err(e) => {
+                warn!("Failed to decode diffuse texture, converting factors only: {e}");
+                return Ok(None);
+            }
+        },
+        None => None,
+    };
+
+    let (width, height) = specular_glossiness.dimensions();
+    let mut base_color = RgbaImage::new(width, height);
+    let mut metallic_roughness = RgbaImage::new(width, height);
+
+    for (x, y, texel) in specular_glossiness.enumerate_pixels() {
+        let mut diffuse_color = weight.diffuse_factor;
+
+        if let Some(diffuse) = &diffuse {
+            let sample =
+                diffuse.get_pixel(x * diffuse.width() / width, y * diffuse.height() / height);
+            for i in 0..3 {
+                diffuse_color[i] *= srgb_to_linear(sample[i]);
+            }
+            diffuse_color[3] *= unorm(sample[3]);
+        }
+
+        let mut specular = weight.specular_factor;
+        for i in 0..3 {
+            specular[i] *= srgb_to_linear(texel[i]);
+        }
+
+        let glossiness = weight.glossiness_factor * unorm(texel[3]);
+
+        let out = to_metallic_roughness(diffuse_color, specular, glossiness);
+
+        base_color.put_pixel(
+            x,
+            y,
+            Rgba([
+                linear_to_srgb(out.base_color[0]),
+                linear_to_srgb(out.base_color[1]),
+                linear_to_srgb(out.base_color[2]),
+                to_unorm(out.base_color[3]),
+            ]),
+        );
+        metallic_roughness.put_pixel(
+            x,
+            y,
+            Rgba([255, to_unorm(out.roughness), to_unorm(out.metallic), 255]),
+        );
+    }
+
+    let base_color = create_texture(graph, doc, specular_glossiness_texture, &base_color)?;
+    let metallic_roughness =
+        create_texture(graph, doc, specular_glossiness_texture, &metallic_roughness)?;
+
+    Ok(Some((base_color, metallic_roughness)))
+}
+
+fn decode(graph: &Graph, texture: Texture) -> Result<Option<RgbaImage>, ConvertError> {
+    let Some(image) = texture.image(graph) else {
+        return Ok(None);
+    };
+
+    let data = &image.get(graph).data;
+
+    if data.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(image::load_from_memory(data)?.to_rgba8()))
+}
+
+/// Creates a PNG texture, copying the sampler and buffer of the source texture.
+fn create_texture(
+    graph: &mut Graph,
+    doc: GltfDocument,
+    source: Texture,
+    pixels: &RgbaImage,
+) -> Result<Texture, ConvertError> {
+    let mut data = Vec::new();
+    pixels.write_to(&mut Cursor::new(&mut data), ImageFormat::Png)?;
+
+    let buffer = source.image(graph).and_then(|i| i.buffer(graph));
+
+    let mut image = doc.create_image(graph);
+    image.set_buffer(graph, buffer);
+
+    let image_weight = image.get_mut(graph);
+    image_weight.mime_type = Some("image/png".to_string());
+    image_weight.data = data;
+
+    let texture_weight = source.get(graph).clone();
+
+    let mut texture = doc.create_texture(graph);
+    *texture.get_mut(graph) = texture_weight;
+    texture.set_image(graph, Some(image));
+
+    Ok(texture)
+}
+
+fn unorm(value: u8) -> f32 {
+    f32::from(value) / 255.0
+}
+
+fn to_unorm(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+fn srgb_to_linear(value: u8) -> f32 {
+    let c = unorm(value);
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(value: f32) -> u8 {
+    let c = value.clamp(0.0, 1.0);
+    let c = if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055f32.mul_add(c.powf(1.0 / 2.4), -0.055)
+    };
+    to_unorm(c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convert_dielectric() {
+        let out = to_metallic_roughness([0.5, 0.25, 0.1, 0.8], [0.04, 0.04, 0.04], 0.75);
+
+        assert!(out.metallic.abs() < EPSILON);
+        assert!((out.roughness - 0.25).abs() < EPSILON);
+        assert!((out.base_color[0] - 0.5).abs() < 1e-3);
+        assert!((out.base_color[3] - 0.8).abs() < EPSILON);
+    }
+
+    #[test]
+    fn convert_metal() {
+        let out = to_metallic_roughness([0.0, 0.0, 0.0, 1.0], [1.0, 0.8, 0.3], 1.0);
+
+        assert!((out.metallic - 1.0).abs() < 1e-3);
+        assert!(out.roughness.abs() < EPSILON);
+        assert!((out.base_color[0] - 1.0).abs() < 1e-3);
+        assert!((out.base_color[1] - 0.8).abs() < 1e-3);
+        assert!((out.base_color[2] - 0.3).abs() < 1e-3);
+    }
+
+    #[test]
+    fn convert_undecodable_texture() {
+        let mut graph = Graph::default();
+        let doc = GltfDocument::new(&mut graph);
+
+        let mut image = doc.create_image(&mut graph);
+        image.get_mut(&mut graph).data = vec![0, 1, 2, 3];
+
+        let texture = doc.create_texture(&mut graph);
+        texture.set_image(&mut graph, Some(image));
+
+        let material = doc.create_material(&mut graph);
+        let ext = material.create_extension::<KhrMaterialsPbrSpecularGlossiness>(&mut graph);
+        ext.set_diffuse_texture(&mut graph, Some(texture));
+        ext.set_specular_glossiness_texture(&mut graph, Some(texture));
+
+        convert(&mut graph, &doc).expect("convert should succeed");
+
+        assert!(
+            material
+                .get_extension::<KhrMaterialsPbrSpecularGlossiness>(&graph)
+                .is_none()
+        );
+        assert_eq!(material.base_color_texture(&graph), Some(texture));
+        assert!(material.metallic_roughness_texture(&graph).is_none());
+        assert_eq!(doc.textures(&graph).len(), 1);
+    }
+
+    #[test]
+    fn srgb_round_trip() {
+        for value in [0, 1, 64, 128, 200, 255] {
+            assert_eq!(linear_to_srgb(srgb_to_linear(value)), value);
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use std::error::Error;
+
+use crate::{
+    extensions::ExtensionExport,
+    graph::{ByteNode, Extensions, Graph, gltf::document::GltfDocument},
+    io::format::gltf::GltfFormat,
+};
+
+use super::{
+    EXTENSION_NAME, KhrMaterialsPbrSpecularGlossiness,
+    json::{MaterialExtension, TextureInfoJson},
+};
+
+impl ExtensionExport<GltfDocument, GltfFormat> for KhrMaterialsPbrSpecularGlossiness {
+    fn export(
+        graph: &mut Graph,
+        doc: &GltfDocument,
+        format: &mut GltfFormat,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut used = false;
+
+        for (material, material_json) in doc
+            .materials(graph)
+            .iter()
+            .zip(format.json.materials.iter_mut())
+        {
+            let Some(ext) = material.get_extension::<Self>(graph) else {
+                continue;
+            };
+
+            let weight = ext.read(graph);
+
+            let texture_info = |texture, tex_coord| {
+                doc.texture_index(graph, texture)
+                    .map(|index| TextureInfoJson { index, tex_coord })
+            };
+
+            let json = MaterialExtension {
+                diffuse_factor: weight.diffuse_factor,
+                diffuse_texture: ext
+                    .diffuse_texture(graph)
+                    .and_then(|t| texture_info(t, weight.diffuse_tex_coord)),
+                specular_factor: weight.specular_factor,
+                glossiness_factor: weight.glossiness_factor,
+                specular_glossiness_texture: ext
+                    .specular_glossiness_texture(graph)
+                    .and_then(|t| texture_info(t, weight.specular_glossiness_tex_coord)),
+            };
+
+            material_json
+                .extensions
+                .get_or_insert_with(gltf::json::extensions::material::Material::default)
+                .others
+                .insert(EXTENSION_NAME.to_string(), serde_json::to_value(json)?);
+
+            used = true;
+        }
+
+        if used {
+            format.json.extensions_used.push(EXTENSION_NAME.to_string());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+use std::error::Error;
+
+use tracing::warn;
+
+use crate::{
+    extensions::ExtensionImport,
+    graph::{ByteNode, Extensions, Graph, gltf::document::GltfDocument},
+    io::format::gltf::GltfFormat,
+};
+
+use super::{
+    EXTENSION_NAME, KhrMaterialsPbrSpecularGlossiness, SpecularGlossinessWeight,
+    json::MaterialExtension,
+};
+
+impl ExtensionImport<GltfDocument, GltfFormat> for KhrMaterialsPbrSpecularGlossiness {
+    fn supported_extensions() -> Vec<&'static str> {
+        vec![EXTENSION_NAME]
+    }
+
+    fn import(
+        graph: &mut Graph,
+        format: &mut GltfFormat,
+        doc: &GltfDocument,
+    ) -> Result<(), Box<dyn Error>> {
+        let materials = doc.materials(graph);
+        let textures = doc.textures(graph);
+
+        for (material, material_json) in materials.iter().zip(format.json.materials.iter()) {
+            let Some(value) = material_json
+                .extensions
+                .as_ref()
+                .and_then(|e| e.others.get(EXTENSION_NAME))
+            else {
+                continue;
+            };
+
+            let json = serde_json::from_value::<MaterialExtension>(value.clone())?;
+
+            let ext = material.create_extension::<Self>(graph);
+
+            ext.write(
+                graph,
+                &SpecularGlossinessWeight {
+                    diffuse_factor: json.diffuse_factor,
+                    specular_factor: json.specular_factor,
+                    glossiness_factor: json.glossiness_factor,
+                    diffuse_tex_coord: json
+                        .diffuse_texture
+                        .as_ref()
+                        .map(|t| t.tex_coord)
+                        .unwrap_or_default(),
+                    specular_glossiness_tex_coord: json
+                        .specular_glossiness_texture
+                        .as_ref()
+                        .map(|t| t.tex_coord)
+                        .unwrap_or_default(),
+                },
+            );
+
+            if let Some(info) = json.diffuse_texture {
+                if let Some(t) = textures.get(info.index) {
+                    ext.set_diffuse_texture(graph, Some(*t));
+                } else {
+                    warn!("Diffuse texture index {} out of bounds", info.index);
+                }
+            }
+
+            if let Some(info) = json.specular_glossiness_texture {
+                if let Some(t) = textures.get(info.index) {
+                    ext.set_specular_glossiness_texture(graph, Some(*t));
+                } else {
+                    warn!(
+                        "Specular-glossiness texture index {} out of bounds",
+                        info.index
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
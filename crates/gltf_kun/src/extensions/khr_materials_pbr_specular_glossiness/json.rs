@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaterialExtension {
+    #[serde(default = "default_diffuse_factor")]
+    pub diffuse_factor: [f32; 4],
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diffuse_texture: Option<TextureInfoJson>,
+    #[serde(default = "default_specular_factor")]
+    pub specular_factor: [f32; 3],
+    #[serde(default = "default_glossiness_factor")]
+    pub glossiness_factor: f32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub specular_glossiness_texture: Option<TextureInfoJson>,
+}
+
+impl Default for MaterialExtension {
+    fn default() -> Self {
+        Self {
+            diffuse_factor: default_diffuse_factor(),
+            diffuse_texture: None,
+            specular_factor: default_specular_factor(),
+            glossiness_factor: default_glossiness_factor(),
+            specular_glossiness_texture: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextureInfoJson {
+    pub index: usize,
+    #[serde(default)]
+    pub tex_coord: usize,
+}
+
+const fn default_diffuse_factor() -> [f32; 4] {
+    [1.0, 1.0, 1.0, 1.0]
+}
+
+const fn default_specular_factor() -> [f32; 3] {
+    [1.0, 1.0, 1.0]
+}
+
+const fn default_glossiness_factor() -> f32 {
+    1.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn material_extension_serde() {
+        let json = r#"{
+            "diffuseFactor": [0.5, 0.25, 1.0, 0.75],
+            "diffuseTexture": { "index": 1 },
+            "glossinessFactor": 0.2,
+            "specularGlossinessTexture": { "index": 2, "texCoord": 1 }
+        }"#;
+
+        let ext = serde_json::from_str::<MaterialExtension>(json).expect("json should deserialize");
+        assert_eq!(
+            ext,
+            MaterialExtension {
+                diffuse_factor: [0.5, 0.25, 1.0, 0.75],
+                diffuse_texture: Some(TextureInfoJson {
+                    index: 1,
+                    tex_coord: 0,
+                }),
+                glossiness_factor: 0.2,
+                specular_glossiness_texture: Some(TextureInfoJson {
+                    index: 2,
+                    tex_coord: 1,
+                }),
+                ..Default::default()
+            }
+        );
+
+        let value = serde_json::to_value(&ext).expect("extension should serialize");
+        let ext_2 =
+            serde_json::from_value::<MaterialExtension>(value).expect("json should deserialize");
+        assert_eq!(ext, ext_2);
+
+        let ext = serde_json::from_str::<MaterialExtension>("{}").expect("json should deserialize");
+        assert_eq!(ext, MaterialExtension::default());
+    }
+}
@@ -0,0 +1,106 @@
+//! [KHR_materials_pbrSpecularGlossiness](https://github.com/KhronosGroup/glTF/tree/main/extensions/2.0/Archived/KHR_materials_pbrSpecularGlossiness)
+//! extension.
+//!
+//! This extension is archived, and most engines only support the metallic-roughness model.
+//! Use [`convert`] to bake materials into metallic-roughness.
+
+use petgraph::graph::NodeIndex;
+use serde::{Deserialize, Serialize};
+
+use crate::graph::{ByteNode, Graph, OtherEdgeHelpers, gltf::Texture};
+
+use super::Extension;
+
+pub mod convert;
+pub mod export;
+pub mod import;
+pub mod json;
+
+pub const EXTENSION_NAME: &str = "KHR_materials_pbrSpecularGlossiness";
+
+pub const DIFFUSE_TEXTURE_EDGE: &str = "KHR_materials_pbrSpecularGlossiness/diffuse_texture";
+pub const SPECULAR_GLOSSINESS_TEXTURE_EDGE: &str =
+    "KHR_materials_pbrSpecularGlossiness/specular_glossiness_texture";
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct SpecularGlossinessWeight {
+    /// Linear RGBA diffuse color.
+    pub diffuse_factor: [f32; 4],
+    /// Linear RGB specular color.
+    pub specular_factor: [f32; 3],
+    pub glossiness_factor: f32,
+
+    pub diffuse_tex_coord: usize,
+    pub specular_glossiness_tex_coord: usize,
+}
+
+impl Default for SpecularGlossinessWeight {
+    fn default() -> Self {
+        Self {
+            diffuse_factor: [1.0, 1.0, 1.0, 1.0],
+            specular_factor: [1.0, 1.0, 1.0],
+            glossiness_factor: 1.0,
+
+            diffuse_tex_coord: 0,
+            specular_glossiness_tex_coord: 0,
+        }
+    }
+}
+
+impl From<&Vec<u8>> for SpecularGlossinessWeight {
+    fn from(bytes: &Vec<u8>) -> Self {
+        if bytes.is_empty() {
+            return Self::default();
+        }
+        serde_json::from_slice(bytes).expect("Failed to deserialize weight")
+    }
+}
+
+impl From<&SpecularGlossinessWeight> for Vec<u8> {
+    fn from(value: &SpecularGlossinessWeight) -> Self {
+        serde_json::to_vec(value).expect("Failed to serialize weight")
+    }
+}
+
+/// Specular-glossiness parameters of a material, added as a material extension.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct KhrMaterialsPbrSpecularGlossiness(pub NodeIndex);
+
+impl From<NodeIndex> for KhrMaterialsPbrSpecularGlossiness {
+    fn from(index: NodeIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl From<KhrMaterialsPbrSpecularGlossiness> for NodeIndex {
+    fn from(ext: KhrMaterialsPbrSpecularGlossiness) -> Self {
+        ext.0
+    }
+}
+
+impl ByteNode<SpecularGlossinessWeight> for KhrMaterialsPbrSpecularGlossiness {}
+impl OtherEdgeHelpers for KhrMaterialsPbrSpecularGlossiness {}
+
+impl Extension for KhrMaterialsPbrSpecularGlossiness {
+    fn name() -> &'static str {
+        EXTENSION_NAME
+    }
+}
+
+impl KhrMaterialsPbrSpecularGlossiness {
+    /// sRGB diffuse color, with linear alpha.
+    pub fn diffuse_texture(&self, graph: &Graph) -> Option<Texture> {
+        self.find_property(graph, DIFFUSE_TEXTURE_EDGE)
+    }
+    pub fn set_diffuse_texture(&self, graph: &mut Graph, texture: Option<Texture>) {
+        self.set_property(graph, DIFFUSE_TEXTURE_EDGE.to_string(), texture);
+    }
+
+    /// sRGB specular color, with linear glossiness in the alpha channel.
+    pub fn specular_glossiness_texture(&self, graph: &Graph) -> Option<Texture> {
+        self.find_property(graph, SPECULAR_GLOSSINESS_TEXTURE_EDGE)
+    }
+    pub fn set_specular_glossiness_texture(&self, graph: &mut Graph, texture: Option<Texture>) {
+        self.set_property(graph, SPECULAR_GLOSSINESS_TEXTURE_EDGE.to_string(), texture);
+    }
+}
@@ -0,0 +1,49 @@
+use std::error::Error;
+
+use crate::{
+    extensions::ExtensionExport,
+    graph::{ByteNode, Extensions, Graph, gltf::document::GltfDocument},
+    io::format::gltf::GltfFormat,
+};
+
+use super::{EXTENSION_NAME, KhrNodeVisibility};
+
+impl ExtensionExport<GltfDocument, GltfFormat> for KhrNodeVisibility {
+    fn export(
+        graph: &mut Graph,
+        doc: &GltfDocument,
+        format: &mut GltfFormat,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut added_extension = false;
+
+        for (i, node) in doc.nodes(graph).iter().enumerate() {
+            let Some(ext) = node.get_extension::<Self>(graph) else {
+                continue;
+            };
+
+            let weight = ext.read(graph);
+
+            let node = format
+                .json
+                .nodes
+                .get_mut(i)
+                .expect("Node index out of bounds");
+
+            let extensions = node
+                .extensions
+                .get_or_insert_with(gltf::json::extensions::scene::Node::default);
+
+            extensions
+                .others
+                .insert(EXTENSION_NAME.to_string(), serde_json::to_value(weight)?);
+
+            added_extension = true;
+        }
+
+        if added_extension {
+            format.json.extensions_used.push(EXTENSION_NAME.to_string());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use std::error::Error;
+
+use crate::{
+    extensions::ExtensionImport,
+    graph::{ByteNode, Extensions, Graph, gltf::document::GltfDocument},
+    io::format::gltf::GltfFormat,
+};
+
+use super::{EXTENSION_NAME, KhrNodeVisibility, weight::NodeVisibilityWeight};
+
+impl ExtensionImport<GltfDocument, GltfFormat> for KhrNodeVisibility {
+    fn supported_extensions() -> Vec<&'static str> {
+        vec![EXTENSION_NAME]
+    }
+
+    fn import(
+        graph: &mut Graph,
+        format: &mut GltfFormat,
+        doc: &GltfDocument,
+    ) -> Result<(), Box<dyn Error>> {
+        format
+            .json
+            .nodes
+            .iter()
+            .enumerate()
+            .filter_map(|(i, n)| n.extensions.as_ref().map(|e| (i, e)))
+            .filter_map(|(i, e)| e.others.get(EXTENSION_NAME).map(|v| (i, v)))
+            .filter_map(|(i, v)| {
+                serde_json::from_value::<NodeVisibilityWeight>(v.clone())
+                    .map(|weight| (i, weight))
+                    .ok()
+            })
+            .for_each(|(i, weight)| {
+                let nodes = doc.nodes(graph);
+                let node = nodes.get(i).expect("Node index out of bounds");
+                let ext = node.create_extension::<Self>(graph);
+                ext.write(graph, &weight);
+            });
+
+        Ok(())
+    }
+}
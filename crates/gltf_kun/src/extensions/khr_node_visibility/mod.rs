@@ -0,0 +1,44 @@
+//! [KHR_node_visibility](https://github.com/KhronosGroup/glTF/tree/main/extensions/2.0/Khronos/KHR_node_visibility)
+//! extension.
+
+use petgraph::graph::NodeIndex;
+
+use crate::graph::ByteNode;
+
+use self::weight::NodeVisibilityWeight;
+
+use super::Extension;
+
+pub mod export;
+pub mod import;
+pub mod weight;
+
+pub const EXTENSION_NAME: &str = "KHR_node_visibility";
+
+/// [`KHR_animation_pointer`](super::khr_animation_pointer) path of the visible flag,
+/// relative to the node.
+pub const VISIBLE_POINTER: &str = "/extensions/KHR_node_visibility/visible";
+
+/// Visibility of a node and its descendants, added as a node extension.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct KhrNodeVisibility(pub NodeIndex);
+
+impl From<NodeIndex> for KhrNodeVisibility {
+    fn from(index: NodeIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl From<KhrNodeVisibility> for NodeIndex {
+    fn from(visibility: KhrNodeVisibility) -> Self {
+        visibility.0
+    }
+}
+
+impl ByteNode<NodeVisibilityWeight> for KhrNodeVisibility {}
+
+impl Extension for KhrNodeVisibility {
+    fn name() -> &'static str {
+        EXTENSION_NAME
+    }
+}
@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct NodeVisibilityWeight {
+    /// Whether the node and its descendants are visible.
+    #[serde(default = "default_visible")]
+    pub visible: bool,
+}
+
+impl Default for NodeVisibilityWeight {
+    fn default() -> Self {
+        Self {
+            visible: default_visible(),
+        }
+    }
+}
+
+impl From<&Vec<u8>> for NodeVisibilityWeight {
+    fn from(bytes: &Vec<u8>) -> Self {
+        if bytes.is_empty() {
+            return Self::default();
+        }
+        serde_json::from_slice(bytes).expect("Failed to deserialize weight")
+    }
+}
+
+impl From<&NodeVisibilityWeight> for Vec<u8> {
+    fn from(value: &NodeVisibilityWeight) -> Self {
+        serde_json::to_vec(value).expect("Failed to serialize weight")
+    }
+}
+
+const fn default_visible() -> bool {
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn visibility_serde() {
+        let weight = NodeVisibilityWeight { visible: false };
+
+        let json = serde_json::to_string(&weight).expect("json should serialize");
+        assert_eq!(json, r#"{"visible":false}"#);
+
+        let weight_2 =
+            serde_json::from_str::<NodeVisibilityWeight>(&json).expect("json should deserialize");
+        assert_eq!(weight, weight_2);
+
+        let weight =
+            serde_json::from_str::<NodeVisibilityWeight>("{}").expect("json should deserialize");
+        assert!(weight.visible);
+    }
+}
@@ -0,0 +1,194 @@
+//! Typed access to common [Dublin Core](https://www.dublincore.org/specifications/dublin-core/dces/)
+//! properties within a packet.
+
+use serde_json::{Map, Value, json};
+
+use super::packet::PacketWeight;
+
+pub const DC_PREFIX: &str = "dc";
+pub const DC_NAMESPACE: &str = "http://purl.org/dc/elements/1.1/";
+pub const RDF_PREFIX: &str = "rdf";
+pub const RDF_NAMESPACE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+
+/// Language used when writing language alternatives.
+pub const DEFAULT_LANGUAGE: &str = "x-default";
+
+const TITLE: &str = "dc:title";
+const DESCRIPTION: &str = "dc:description";
+const RIGHTS: &str = "dc:rights";
+const CREATOR: &str = "dc:creator";
+const CONTRIBUTOR: &str = "dc:contributor";
+const PUBLISHER: &str = "dc:publisher";
+const SUBJECT: &str = "dc:subject";
+const DATE: &str = "dc:date";
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DublinCore {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    /// License or copyright statement.
+    pub rights: Option<String>,
+    /// Authors, in order of precedence.
+    pub creators: Vec<String>,
+    pub contributors: Vec<String>,
+    pub publishers: Vec<String>,
+    /// Keywords describing the content.
+    pub subjects: Vec<String>,
+    /// Date of creation, as an ISO 8601 string.
+    pub date: Option<String>,
+}
+
+impl PacketWeight {
+    /// Reads Dublin Core properties from the packet.
+    /// Language alternatives resolve to the default language, or the first entry.
+    pub fn dublin_core(&self) -> DublinCore {
+        let text = |key| self.properties.get(key).and_then(read_text);
+        let list = |key| self.properties.get(key).map(read_list).unwrap_or_default();
+
+        DublinCore {
+            title: text(TITLE),
+            description: text(DESCRIPTION),
+            rights: text(RIGHTS),
+            creators: list(CREATOR),
+            contributors: list(CONTRIBUTOR),
+            publishers: list(PUBLISHER),
+            subjects: list(SUBJECT),
+            date: list(DATE).into_iter().next(),
+        }
+    }
+
+    /// Writes Dublin Core properties to the packet, adding the required context.
+    /// Empty fields are removed from the packet.
+    pub fn set_dublin_core(&mut self, dc: &DublinCore) {
+        self.set_property(TITLE, dc.title.as_deref().map(write_alt));
+        self.set_property(DESCRIPTION, dc.description.as_deref().map(write_alt));
+        self.set_property(RIGHTS, dc.rights.as_deref().map(write_alt));
+        self.set_property(CREATOR, write_container("@list", &dc.creators));
+        self.set_property(CONTRIBUTOR, write_container("@set", &dc.contributors));
+        self.set_property(PUBLISHER, write_container("@set", &dc.publishers));
+        self.set_property(SUBJECT, write_container("@set", &dc.subjects));
+        self.set_property(DATE, write_container("@list", dc.date.as_slice()));
+
+        if self.properties.keys().any(|k| k.starts_with("dc:")) {
+            self.context
+                .entry(DC_PREFIX)
+                .or_insert_with(|| DC_NAMESPACE.into());
+            self.context
+                .entry(RDF_PREFIX)
+                .or_insert_with(|| RDF_NAMESPACE.into());
+        }
+    }
+
+    fn set_property(&mut self, key: &str, value: Option<Value>) {
+        match value {
+            Some(value) => self.properties.insert(key.to_string(), value),
+            None => self.properties.remove(key),
+        };
+    }
+}
+
+fn read_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Object(map) => map
+            .get("@value")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .or_else(|| read_alt(map)),
+        _ => None,
+    }
+}
+
+fn read_alt(map: &Map<String, Value>) -> Option<String> {
+    let mut entries = (1..)
+        .map_while(|i| map.get(&format!("rdf:_{i}")))
+        .peekable();
+
+    let first = entries.peek().copied();
+
+    entries
+        .find(|v| v.get("@language").and_then(Value::as_str) == Some(DEFAULT_LANGUAGE))
+        .or(first)
+        .and_then(read_text)
+}
+
+fn read_list(value: &Value) -> Vec<String> {
+    match value {
+        Value::Array(items) => items.iter().filter_map(read_text).collect(),
+        Value::Object(map) => map
+            .get("@list")
+            .or_else(|| map.get("@set"))
+            .map_or_else(|| read_text(value).into_iter().collect(), read_list),
+        _ => read_text(value).into_iter().collect(),
+    }
+}
+
+fn write_alt(text: &str) -> Value {
+    json!({
+        "@type": "rdf:Alt",
+        "rdf:_1": {
+            "@language": DEFAULT_LANGUAGE,
+            "@value": text,
+        },
+    })
+}
+
+fn write_container(container: &str, items: &[String]) -> Option<Value> {
+    if items.is_empty() {
+        return None;
+    }
+
+    let mut map = Map::new();
+    map.insert(container.to_string(), items.into());
+    Some(Value::Object(map))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dublin_core_round_trip() {
+        let dc = DublinCore {
+            title: Some("Lamp".to_string()),
+            rights: Some("CC-BY-4.0".to_string()),
+            creators: vec!["Alice".to_string(), "Bob".to_string()],
+            subjects: vec!["Furniture".to_string()],
+            date: Some("2024-01-01".to_string()),
+            ..Default::default()
+        };
+
+        let mut packet = PacketWeight::default();
+        packet.set_dublin_core(&dc);
+
+        assert_eq!(packet.context.get(DC_PREFIX), Some(&DC_NAMESPACE.into()));
+        assert!(!packet.properties.contains_key(DESCRIPTION));
+        assert_eq!(packet.dublin_core(), dc);
+
+        packet.set_dublin_core(&DublinCore::default());
+        assert!(packet.properties.is_empty());
+    }
+
+    #[test]
+    fn read_language_alternative() {
+        let packet = serde_json::from_value::<PacketWeight>(json!({
+            "@context": { "dc": DC_NAMESPACE },
+            "dc:title": {
+                "@type": "rdf:Alt",
+                "rdf:_1": { "@language": "fr", "@value": "Lampe" },
+                "rdf:_2": { "@language": "x-default", "@value": "Lamp" },
+            },
+            "dc:description": {
+                "@type": "rdf:Alt",
+                "rdf:_1": { "@language": "en-us", "@value": "A lamp." },
+            },
+            "dc:creator": "Alice",
+        }))
+        .expect("json should deserialize");
+
+        let dc = packet.dublin_core();
+        assert_eq!(dc.title.as_deref(), Some("Lamp"));
+        assert_eq!(dc.description.as_deref(), Some("A lamp."));
+        assert_eq!(dc.creators, vec!["Alice".to_string()]);
+    }
+}
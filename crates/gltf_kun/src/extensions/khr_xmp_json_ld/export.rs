@@ -0,0 +1,127 @@
+use std::error::Error;
+
+use crate::{
+    extensions::ExtensionExport,
+    graph::{ByteNode, Extensions, Graph, gltf::document::GltfDocument},
+    io::format::gltf::GltfFormat,
+};
+
+use super::{
+    EXTENSION_NAME, KhrXmpJsonLd,
+    json::{PropertyExtension, RootExtension},
+    packet::Packet,
+};
+
+impl ExtensionExport<GltfDocument, GltfFormat> for KhrXmpJsonLd {
+    fn export(
+        graph: &mut Graph,
+        doc: &GltfDocument,
+        format: &mut GltfFormat,
+    ) -> Result<(), Box<dyn Error>> {
+        let Some(ext) = doc.get_extension::<Self>(graph) else {
+            return Ok(());
+        };
+
+        let packets = ext.packets(graph);
+
+        if packets.is_empty() {
+            return Ok(());
+        }
+
+        for (i, node) in doc.nodes(graph).iter().enumerate() {
+            let Some(value) = packet_ref(graph, &packets, *node)? else {
+                continue;
+            };
+
+            format
+                .json
+                .nodes
+                .get_mut(i)
+                .expect("Node index out of bounds")
+                .extensions
+                .get_or_insert_with(gltf::json::extensions::scene::Node::default)
+                .others
+                .insert(EXTENSION_NAME.to_string(), value);
+        }
+
+        for (i, mesh) in doc.meshes(graph).iter().enumerate() {
+            let Some(value) = packet_ref(graph, &packets, *mesh)? else {
+                continue;
+            };
+
+            format
+                .json
+                .meshes
+                .get_mut(i)
+                .expect("Mesh index out of bounds")
+                .extensions
+                .get_or_insert_with(gltf::json::extensions::mesh::Mesh::default)
+                .others
+                .insert(EXTENSION_NAME.to_string(), value);
+        }
+
+        for (i, material) in doc.materials(graph).iter().enumerate() {
+            let Some(value) = packet_ref(graph, &packets, *material)? else {
+                continue;
+            };
+
+            format
+                .json
+                .materials
+                .get_mut(i)
+                .expect("Material index out of bounds")
+                .extensions
+                .get_or_insert_with(gltf::json::extensions::material::Material::default)
+                .others
+                .insert(EXTENSION_NAME.to_string(), value);
+        }
+
+        for (i, image) in doc.images(graph).iter().enumerate() {
+            let Some(value) = packet_ref(graph, &packets, *image)? else {
+                continue;
+            };
+
+            format
+                .json
+                .images
+                .get_mut(i)
+                .expect("Image index out of bounds")
+                .extensions
+                .get_or_insert_with(gltf::json::extensions::image::Image::default)
+                .others
+                .insert(EXTENSION_NAME.to_string(), value);
+        }
+
+        let extensions = format
+            .json
+            .extensions
+            .get_or_insert_with(gltf::json::extensions::Root::default);
+
+        extensions.others.insert(
+            EXTENSION_NAME.to_string(),
+            serde_json::to_value(RootExtension {
+                packets: packets.iter().map(|p| p.read(graph)).collect(),
+                asset: ext
+                    .asset_packet(graph)
+                    .and_then(|p| packets.iter().position(|x| *x == p)),
+            })?,
+        );
+
+        format.json.extensions_used.push(EXTENSION_NAME.to_string());
+
+        Ok(())
+    }
+}
+
+/// JSON reference to the property's packet, if it has one in the document.
+fn packet_ref<P: Extensions>(
+    graph: &Graph,
+    packets: &[Packet],
+    property: P,
+) -> Result<Option<serde_json::Value>, serde_json::Error> {
+    property
+        .get_extension::<Packet>(graph)
+        .and_then(|p| packets.iter().position(|x| *x == p))
+        .map(|packet| serde_json::to_value(PropertyExtension { packet }))
+        .transpose()
+}
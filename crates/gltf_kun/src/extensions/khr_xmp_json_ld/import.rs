@@ -0,0 +1,116 @@
+use std::error::Error;
+
+use serde_json::{Map, Value};
+use tracing::warn;
+
+use crate::{
+    extensions::ExtensionImport,
+    graph::{Extensions, Graph, gltf::document::GltfDocument},
+    io::format::gltf::GltfFormat,
+};
+
+use super::{
+    EXTENSION_NAME, KhrXmpJsonLd,
+    json::{PropertyExtension, RootExtension},
+    packet::Packet,
+};
+
+impl ExtensionImport<GltfDocument, GltfFormat> for KhrXmpJsonLd {
+    fn supported_extensions() -> Vec<&'static str> {
+        vec![EXTENSION_NAME]
+    }
+
+    fn import(
+        graph: &mut Graph,
+        format: &mut GltfFormat,
+        doc: &GltfDocument,
+    ) -> Result<(), Box<dyn Error>> {
+        let Some(value) = format
+            .json
+            .extensions
+            .as_ref()
+            .and_then(|e| e.others.get(EXTENSION_NAME))
+        else {
+            return Ok(());
+        };
+
+        let root = serde_json::from_value::<RootExtension>(value.clone())?;
+
+        let ext = doc
+            .get_extension::<Self>(graph)
+            .unwrap_or_else(|| doc.create_extension::<Self>(graph));
+
+        let packets = root
+            .packets
+            .iter()
+            .map(|weight| ext.create_packet(graph, weight))
+            .collect::<Vec<_>>();
+
+        if let Some(index) = root.asset {
+            let packet = packets.get(index).copied();
+
+            if packet.is_none() {
+                warn!("XMP packet index {} out of bounds", index);
+            }
+
+            ext.set_asset_packet(graph, packet);
+        }
+
+        let json = &format.json;
+
+        let nodes = doc.nodes(graph);
+        let others = json
+            .nodes
+            .iter()
+            .map(|n| n.extensions.as_ref().map(|e| &e.others));
+        import_refs(graph, &packets, &nodes, others)?;
+
+        let meshes = doc.meshes(graph);
+        let others = json
+            .meshes
+            .iter()
+            .map(|m| m.extensions.as_ref().map(|e| &e.others));
+        import_refs(graph, &packets, &meshes, others)?;
+
+        let materials = doc.materials(graph);
+        let others = json
+            .materials
+            .iter()
+            .map(|m| m.extensions.as_ref().map(|e| &e.others));
+        import_refs(graph, &packets, &materials, others)?;
+
+        let images = doc.images(graph);
+        let others = json
+            .images
+            .iter()
+            .map(|i| i.extensions.as_ref().map(|e| &e.others));
+        import_refs(graph, &packets, &images, others)?;
+
+        Ok(())
+    }
+}
+
+/// Attaches referenced packets to each property, matching properties to JSON by index.
+fn import_refs<'a, P: Extensions>(
+    graph: &mut Graph,
+    packets: &[Packet],
+    properties: &[P],
+    others: impl Iterator<Item = Option<&'a Map<String, Value>>>,
+) -> Result<(), Box<dyn Error>> {
+    for (property, others) in properties.iter().zip(others) {
+        let Some(value) = others.and_then(|o| o.get(EXTENSION_NAME)) else {
+            continue;
+        };
+
+        let json = serde_json::from_value::<PropertyExtension>(value.clone())?;
+
+        let Some(packet) = packets.get(json.packet) else {
+            warn!("XMP packet index {} out of bounds", json.packet);
+            continue;
+        };
+
+        property.add_extension(graph, *packet);
+    }
+
+    Ok(())
+}
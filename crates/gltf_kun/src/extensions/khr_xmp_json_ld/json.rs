@@ -0,0 +1,136 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{EXTENSION_NAME, packet::PacketWeight};
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct RootExtension {
+    pub packets: Vec<PacketWeight>,
+    /// Packet referenced by the asset, stored here while the document is in the JSON format.
+    ///
+    /// `gltf::json` drops asset extensions, so the reference is moved out of the asset.
+    /// See [`take_asset_packet`] and [`restore_asset_packet`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset: Option<usize>,
+}
+
+#[derive(Copy, Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PropertyExtension {
+    pub packet: usize,
+}
+
+/// Move the asset's packet reference into the root extension.
+/// Returns `true` if a reference was moved.
+pub fn take_asset_packet(root: &mut Value) -> bool {
+    let Some(asset_extensions) = root
+        .get_mut("asset")
+        .and_then(|a| a.get_mut("extensions"))
+        .and_then(Value::as_object_mut)
+    else {
+        return false;
+    };
+
+    let Some(value) = asset_extensions.remove(EXTENSION_NAME) else {
+        return false;
+    };
+
+    let Ok(json) = serde_json::from_value::<PropertyExtension>(value) else {
+        return false;
+    };
+
+    let Some(ext) = root
+        .get_mut("extensions")
+        .and_then(|e| e.get_mut(EXTENSION_NAME))
+        .and_then(Value::as_object_mut)
+    else {
+        return false;
+    };
+
+    ext.insert("asset".to_string(), json.packet.into());
+
+    true
+}
+
+/// Move the asset's packet reference from the root extension back into the asset.
+/// Returns `true` if a reference was moved.
+pub fn restore_asset_packet(root: &mut Value) -> bool {
+    let Some(packet) = root
+        .get_mut("extensions")
+        .and_then(|e| e.get_mut(EXTENSION_NAME))
+        .and_then(Value::as_object_mut)
+        .and_then(|ext| ext.remove("asset"))
+    else {
+        return false;
+    };
+
+    let Some(asset) = root.get_mut("asset").and_then(Value::as_object_mut) else {
+        return false;
+    };
+
+    let extensions = asset
+        .entry("extensions")
+        .or_insert_with(|| Value::Object(serde_json::Map::new()));
+
+    if let Some(extensions) = extensions.as_object_mut() {
+        extensions.insert(
+            EXTENSION_NAME.to_string(),
+            serde_json::json!({ "packet": packet }),
+        );
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packet_serde() {
+        let json = r#"{
+            "packets": [{
+                "@context": { "dc": "http://purl.org/dc/elements/1.1/" },
+                "@id": "",
+                "dc:creator": { "@list": ["Alice"] }
+            }]
+        }"#;
+
+        let root = serde_json::from_str::<RootExtension>(json).expect("json should deserialize");
+        let packet = &root.packets[0];
+
+        assert!(packet.context.contains_key("dc"));
+        assert!(packet.properties.contains_key("@id"));
+        assert!(packet.properties.contains_key("dc:creator"));
+        assert!(!packet.properties.contains_key("@context"));
+
+        let json_2 = serde_json::to_string(&root).expect("json should serialize");
+        let root_2 =
+            serde_json::from_str::<RootExtension>(&json_2).expect("json should deserialize");
+        assert_eq!(root, root_2);
+    }
+
+    #[test]
+    fn asset_packet_round_trip() {
+        let json = serde_json::json!({
+            "asset": {
+                "version": "2.0",
+                "extensions": { "KHR_xmp_json_ld": { "packet": 1 } }
+            },
+            "extensions": {
+                "KHR_xmp_json_ld": { "packets": [{}, {}] }
+            }
+        });
+
+        let mut value = json.clone();
+        assert!(take_asset_packet(&mut value));
+        assert!(value["asset"]["extensions"].get(EXTENSION_NAME).is_none());
+
+        let root =
+            serde_json::from_value::<RootExtension>(value["extensions"][EXTENSION_NAME].clone())
+                .expect("extension should deserialize");
+        assert_eq!(root.asset, Some(1));
+
+        assert!(restore_asset_packet(&mut value));
+        assert_eq!(value, json);
+    }
+}
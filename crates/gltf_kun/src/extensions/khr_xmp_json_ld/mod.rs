@@ -0,0 +1,72 @@
+//! [KHR_xmp_json_ld](https://github.com/KhronosGroup/glTF/tree/main/extensions/2.0/Khronos/KHR_xmp_json_ld)
+//! extension.
+
+use petgraph::graph::NodeIndex;
+
+use crate::graph::{Graph, OtherEdgeHelpers};
+
+use self::packet::{Packet, PacketWeight};
+
+use super::Extension;
+
+pub mod dublin_core;
+pub mod export;
+pub mod import;
+pub mod json;
+pub mod packet;
+
+pub const EXTENSION_NAME: &str = "KHR_xmp_json_ld";
+
+pub const PACKET_EDGE: &str = "KHR_xmp_json_ld/packet";
+pub const ASSET_PACKET_EDGE: &str = "KHR_xmp_json_ld/asset";
+
+/// Document-level XMP metadata packets.
+/// Nodes, meshes, materials, and images reference a packet by adding it as an extension,
+/// and the asset itself references one with [`KhrXmpJsonLd::set_asset_packet`].
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct KhrXmpJsonLd(pub NodeIndex);
+
+impl From<NodeIndex> for KhrXmpJsonLd {
+    fn from(index: NodeIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl From<KhrXmpJsonLd> for NodeIndex {
+    fn from(xmp: KhrXmpJsonLd) -> Self {
+        xmp.0
+    }
+}
+
+impl OtherEdgeHelpers for KhrXmpJsonLd {}
+
+impl Extension for KhrXmpJsonLd {
+    fn name() -> &'static str {
+        EXTENSION_NAME
+    }
+}
+
+impl KhrXmpJsonLd {
+    pub fn packets(&self, graph: &Graph) -> Vec<Packet> {
+        self.find_properties(graph, PACKET_EDGE)
+    }
+    pub fn add_packet(&self, graph: &mut Graph, packet: Packet) {
+        self.add_property(graph, PACKET_EDGE.to_string(), packet);
+    }
+    pub fn remove_packet(&self, graph: &mut Graph, packet: Packet) {
+        self.remove_property(graph, PACKET_EDGE, packet);
+    }
+    pub fn create_packet(&self, graph: &mut Graph, weight: &PacketWeight) -> Packet {
+        let packet = Packet::new(graph, weight);
+        self.add_packet(graph, packet);
+        packet
+    }
+
+    /// Packet describing the asset as a whole.
+    pub fn asset_packet(&self, graph: &Graph) -> Option<Packet> {
+        self.find_property(graph, ASSET_PACKET_EDGE)
+    }
+    pub fn set_asset_packet(&self, graph: &mut Graph, packet: Option<Packet>) {
+        self.set_property(graph, ASSET_PACKET_EDGE.to_string(), packet);
+    }
+}
@@ -0,0 +1,66 @@
+use petgraph::graph::NodeIndex;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{
+    extensions::Extension,
+    graph::{ByteNode, Graph, Weight},
+};
+
+use super::EXTENSION_NAME;
+
+/// A JSON-LD object containing XMP metadata.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PacketWeight {
+    /// Namespace prefixes used by the properties, such as `"dc"`.
+    #[serde(rename = "@context", default)]
+    pub context: Map<String, Value>,
+    /// Metadata properties, keyed by prefixed name (e.g. `"dc:title"`).
+    #[serde(flatten)]
+    pub properties: Map<String, Value>,
+}
+
+impl From<&Vec<u8>> for PacketWeight {
+    fn from(bytes: &Vec<u8>) -> Self {
+        if bytes.is_empty() {
+            return Self::default();
+        }
+        serde_json::from_slice(bytes).expect("Failed to deserialize weight")
+    }
+}
+
+impl From<&PacketWeight> for Vec<u8> {
+    fn from(value: &PacketWeight) -> Self {
+        serde_json::to_vec(value).expect("Failed to serialize weight")
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Packet(pub NodeIndex);
+
+impl From<NodeIndex> for Packet {
+    fn from(index: NodeIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl From<Packet> for NodeIndex {
+    fn from(packet: Packet) -> Self {
+        packet.0
+    }
+}
+
+impl ByteNode<PacketWeight> for Packet {}
+
+impl Extension for Packet {
+    fn name() -> &'static str {
+        EXTENSION_NAME
+    }
+}
+
+impl Packet {
+    pub fn new(graph: &mut Graph, weight: &PacketWeight) -> Self {
+        let index = graph.add_node(Weight::Bytes(weight.into()));
+        Self(index)
+    }
+}
@@ -11,10 +11,42 @@ use crate::{
     io::format::gltf::GltfFormat,
 };
 
+#[cfg(feature = "ext_lights_image_based")]
+pub mod ext_lights_image_based;
+#[cfg(feature = "ext_mesh_features")]
+pub mod ext_mesh_features;
+#[cfg(feature = "ext_mesh_gpu_instancing")]
+pub mod ext_mesh_gpu_instancing;
+#[cfg(feature = "ext_structural_metadata")]
+pub mod ext_structural_metadata;
+#[cfg(feature = "khr_animation_pointer")]
+pub mod khr_animation_pointer;
+#[cfg(feature = "khr_audio_emitter")]
+pub mod khr_audio_emitter;
+#[cfg(feature = "khr_materials_dispersion")]
+pub mod khr_materials_dispersion;
+#[cfg(feature = "khr_materials_pbr_specular_glossiness")]
+pub mod khr_materials_pbr_specular_glossiness;
+#[cfg(feature = "khr_node_visibility")]
+pub mod khr_node_visibility;
+#[cfg(feature = "khr_xmp_json_ld")]
+pub mod khr_xmp_json_ld;
+#[cfg(feature = "msft_lod")]
+pub mod msft_lod;
+#[cfg(feature = "omi_link")]
+pub mod omi_link;
 #[cfg(feature = "omi_physics")]
 pub mod omi_physics_body;
 #[cfg(feature = "omi_physics")]
 pub mod omi_physics_shape;
+#[cfg(feature = "omi_seat")]
+pub mod omi_seat;
+#[cfg(feature = "omi_spawn_point")]
+pub mod omi_spawn_point;
+#[cfg(feature = "vrmc_spring_bone")]
+pub mod vrmc_spring_bone;
+#[cfg(feature = "vrmc_vrm")]
+pub mod vrmc_vrm;
 
 pub trait Extension: Copy + Sized + Into<NodeIndex> + From<NodeIndex> {
     fn name() -> &'static str;
@@ -30,7 +62,24 @@ pub trait ExtensionExport<D, F> {
     fn export(graph: &mut Graph, doc: &D, format: &mut F) -> Result<(), Box<dyn Error>>;
 }
 
+/// Extensions handled by the core importer, which are always supported.
+pub const CORE_EXTENSIONS: &[&str] = &["KHR_mesh_quantization"];
+
 pub trait ExtensionImport<D, F> {
+    /// Names of the extensions handled by this import.
+    fn supported_extensions() -> Vec<&'static str> {
+        Vec::new()
+    }
+
+    /// Whether to reject files listing an extension in `extensionsRequired` that is
+    /// neither [supported](Self::supported_extensions) nor in [`CORE_EXTENSIONS`].
+    ///
+    /// On by default. Override this to return `false` to load such files anyway,
+    /// skipping the extensions that cannot be imported.
+    fn reject_unsupported() -> bool {
+        true
+    }
+
     /// Import the extension from format -> document.
     fn import(graph: &mut Graph, format: &mut F, doc: &D) -> Result<(), Box<dyn Error>>;
 }
@@ -44,28 +93,171 @@ impl ExtensionExport<GltfDocument, GltfFormat> for DefaultExtensions {
         doc: &GltfDocument,
         format: &mut GltfFormat,
     ) -> Result<(), Box<dyn Error>> {
+        #[cfg(feature = "ext_lights_image_based")]
+        ext_lights_image_based::ExtLightsImageBased::export(graph, doc, format)?;
+
+        #[cfg(feature = "ext_mesh_features")]
+        ext_mesh_features::ExtMeshFeatures::export(graph, doc, format)?;
+
+        #[cfg(feature = "ext_mesh_gpu_instancing")]
+        ext_mesh_gpu_instancing::ExtMeshGpuInstancing::export(graph, doc, format)?;
+
+        #[cfg(feature = "ext_structural_metadata")]
+        ext_structural_metadata::ExtStructuralMetadata::export(graph, doc, format)?;
+
+        #[cfg(feature = "khr_animation_pointer")]
+        khr_animation_pointer::KhrAnimationPointer::export(graph, doc, format)?;
+
+        #[cfg(feature = "khr_audio_emitter")]
+        khr_audio_emitter::KhrAudioEmitter::export(graph, doc, format)?;
+
+        #[cfg(feature = "khr_materials_dispersion")]
+        khr_materials_dispersion::KhrMaterialsDispersion::export(graph, doc, format)?;
+
+        #[cfg(feature = "khr_materials_pbr_specular_glossiness")]
+        khr_materials_pbr_specular_glossiness::KhrMaterialsPbrSpecularGlossiness::export(
+            graph, doc, format,
+        )?;
+
+        #[cfg(feature = "khr_node_visibility")]
+        khr_node_visibility::KhrNodeVisibility::export(graph, doc, format)?;
+
+        #[cfg(feature = "khr_xmp_json_ld")]
+        khr_xmp_json_ld::KhrXmpJsonLd::export(graph, doc, format)?;
+
+        #[cfg(feature = "msft_lod")]
+        msft_lod::MsftLod::export(graph, doc, format)?;
+
+        #[cfg(feature = "omi_link")]
+        omi_link::OmiLink::export(graph, doc, format)?;
+
         #[cfg(feature = "omi_physics")]
         {
             omi_physics_shape::OmiPhysicsShape::export(graph, doc, format)?;
             omi_physics_body::OmiPhysicsBody::export(graph, doc, format)?;
         }
 
+        #[cfg(feature = "omi_seat")]
+        omi_seat::OmiSeat::export(graph, doc, format)?;
+
+        #[cfg(feature = "omi_spawn_point")]
+        omi_spawn_point::OmiSpawnPoint::export(graph, doc, format)?;
+
+        #[cfg(feature = "vrmc_spring_bone")]
+        vrmc_spring_bone::VrmcSpringBone::export(graph, doc, format)?;
+
+        #[cfg(feature = "vrmc_vrm")]
+        vrmc_vrm::VrmcVrm::export(graph, doc, format)?;
+
         Ok(())
     }
 }
 
 impl ExtensionImport<GltfDocument, GltfFormat> for DefaultExtensions {
+    fn supported_extensions() -> Vec<&'static str> {
+        let extensions: Vec<Vec<&'static str>> = vec![
+            #[cfg(feature = "ext_lights_image_based")]
+            ext_lights_image_based::ExtLightsImageBased::supported_extensions(),
+            #[cfg(feature = "ext_mesh_features")]
+            ext_mesh_features::ExtMeshFeatures::supported_extensions(),
+            #[cfg(feature = "ext_mesh_gpu_instancing")]
+            ext_mesh_gpu_instancing::ExtMeshGpuInstancing::supported_extensions(),
+            #[cfg(feature = "ext_structural_metadata")]
+            ext_structural_metadata::ExtStructuralMetadata::supported_extensions(),
+            #[cfg(feature = "khr_animation_pointer")]
+            khr_animation_pointer::KhrAnimationPointer::supported_extensions(),
+            #[cfg(feature = "khr_audio_emitter")]
+            khr_audio_emitter::KhrAudioEmitter::supported_extensions(),
+            #[cfg(feature = "khr_materials_dispersion")]
+            khr_materials_dispersion::KhrMaterialsDispersion::supported_extensions(),
+            #[cfg(feature = "khr_materials_pbr_specular_glossiness")]
+            khr_materials_pbr_specular_glossiness::KhrMaterialsPbrSpecularGlossiness::supported_extensions(),
+            #[cfg(feature = "khr_node_visibility")]
+            khr_node_visibility::KhrNodeVisibility::supported_extensions(),
+            #[cfg(feature = "khr_xmp_json_ld")]
+            khr_xmp_json_ld::KhrXmpJsonLd::supported_extensions(),
+            #[cfg(feature = "msft_lod")]
+            msft_lod::MsftLod::supported_extensions(),
+            #[cfg(feature = "omi_link")]
+            omi_link::OmiLink::supported_extensions(),
+            #[cfg(feature = "omi_physics")]
+            omi_physics_shape::OmiPhysicsShape::supported_extensions(),
+            #[cfg(feature = "omi_physics")]
+            omi_physics_body::OmiPhysicsBody::supported_extensions(),
+            #[cfg(feature = "omi_seat")]
+            omi_seat::OmiSeat::supported_extensions(),
+            #[cfg(feature = "omi_spawn_point")]
+            omi_spawn_point::OmiSpawnPoint::supported_extensions(),
+            #[cfg(feature = "vrmc_spring_bone")]
+            vrmc_spring_bone::VrmcSpringBone::supported_extensions(),
+            #[cfg(feature = "vrmc_vrm")]
+            vrmc_vrm::VrmcVrm::supported_extensions(),
+        ];
+
+        extensions.concat()
+    }
+
     fn import(
         graph: &mut Graph,
         format: &mut GltfFormat,
         doc: &GltfDocument,
     ) -> Result<(), Box<dyn Error>> {
+        #[cfg(feature = "ext_lights_image_based")]
+        ext_lights_image_based::ExtLightsImageBased::import(graph, format, doc)?;
+
+        #[cfg(feature = "ext_mesh_features")]
+        ext_mesh_features::ExtMeshFeatures::import(graph, format, doc)?;
+
+        #[cfg(feature = "ext_mesh_gpu_instancing")]
+        ext_mesh_gpu_instancing::ExtMeshGpuInstancing::import(graph, format, doc)?;
+
+        #[cfg(feature = "ext_structural_metadata")]
+        ext_structural_metadata::ExtStructuralMetadata::import(graph, format, doc)?;
+
+        #[cfg(feature = "khr_animation_pointer")]
+        khr_animation_pointer::KhrAnimationPointer::import(graph, format, doc)?;
+
+        #[cfg(feature = "khr_audio_emitter")]
+        khr_audio_emitter::KhrAudioEmitter::import(graph, format, doc)?;
+
+        #[cfg(feature = "khr_materials_dispersion")]
+        khr_materials_dispersion::KhrMaterialsDispersion::import(graph, format, doc)?;
+
+        #[cfg(feature = "khr_materials_pbr_specular_glossiness")]
+        khr_materials_pbr_specular_glossiness::KhrMaterialsPbrSpecularGlossiness::import(
+            graph, format, doc,
+        )?;
+
+        #[cfg(feature = "khr_node_visibility")]
+        khr_node_visibility::KhrNodeVisibility::import(graph, format, doc)?;
+
+        #[cfg(feature = "khr_xmp_json_ld")]
+        khr_xmp_json_ld::KhrXmpJsonLd::import(graph, format, doc)?;
+
+        #[cfg(feature = "msft_lod")]
+        msft_lod::MsftLod::import(graph, format, doc)?;
+
+        #[cfg(feature = "omi_link")]
+        omi_link::OmiLink::import(graph, format, doc)?;
+
         #[cfg(feature = "omi_physics")]
         {
             omi_physics_shape::OmiPhysicsShape::import(graph, format, doc)?;
             omi_physics_body::OmiPhysicsBody::import(graph, format, doc)?;
         }
 
+        #[cfg(feature = "omi_seat")]
+        omi_seat::OmiSeat::import(graph, format, doc)?;
+
+        #[cfg(feature = "omi_spawn_point")]
+        omi_spawn_point::OmiSpawnPoint::import(graph, format, doc)?;
+
+        #[cfg(feature = "vrmc_spring_bone")]
+        vrmc_spring_bone::VrmcSpringBone::import(graph, format, doc)?;
+
+        #[cfg(feature = "vrmc_vrm")]
+        vrmc_vrm::VrmcVrm::import(graph, format, doc)?;
+
         Ok(())
     }
 }
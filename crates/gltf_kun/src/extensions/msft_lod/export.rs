@@ -0,0 +1,117 @@
+use std::error::Error;
+
+use serde_json::{Map, Value};
+
+use crate::{
+    extensions::ExtensionExport,
+    graph::{
+        ByteNode, Extensions, Graph,
+        gltf::{Material, Node, document::GltfDocument},
+    },
+    io::format::gltf::GltfFormat,
+};
+
+use super::{EXTENSION_NAME, MsftLod, SCREEN_COVERAGE_EXTRA, json::LodJson};
+
+impl ExtensionExport<GltfDocument, GltfFormat> for MsftLod {
+    fn export(
+        graph: &mut Graph,
+        doc: &GltfDocument,
+        format: &mut GltfFormat,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut added_extension = false;
+
+        let nodes = doc.nodes(graph);
+
+        for (i, node) in nodes.iter().enumerate() {
+            let Some(ext) = node.get_extension::<Self>(graph) else {
+                continue;
+            };
+
+            let ids = ext
+                .levels::<Node>(graph)
+                .iter()
+                .filter_map(|level| nodes.iter().position(|x| x == level))
+                .collect();
+
+            let screen_coverage = ext.read(graph).screen_coverage;
+
+            let node = format
+                .json
+                .nodes
+                .get_mut(i)
+                .expect("Node index out of bounds");
+
+            if !screen_coverage.is_empty() {
+                node.extras = Some(write_screen_coverage(
+                    node.extras.as_deref(),
+                    &screen_coverage,
+                )?);
+            }
+
+            node.extensions
+                .get_or_insert_with(gltf::json::extensions::scene::Node::default)
+                .others
+                .insert(
+                    EXTENSION_NAME.to_string(),
+                    serde_json::to_value(LodJson { ids })?,
+                );
+
+            added_extension = true;
+        }
+
+        let materials = doc.materials(graph);
+
+        for (i, material) in materials.iter().enumerate() {
+            let Some(ext) = material.get_extension::<Self>(graph) else {
+                continue;
+            };
+
+            let ids = ext
+                .levels::<Material>(graph)
+                .iter()
+                .filter_map(|level| materials.iter().position(|x| x == level))
+                .collect();
+
+            let material = format
+                .json
+                .materials
+                .get_mut(i)
+                .expect("Material index out of bounds");
+
+            material
+                .extensions
+                .get_or_insert_with(gltf::json::extensions::material::Material::default)
+                .others
+                .insert(
+                    EXTENSION_NAME.to_string(),
+                    serde_json::to_value(LodJson { ids })?,
+                );
+
+            added_extension = true;
+        }
+
+        if added_extension {
+            format.json.extensions_used.push(EXTENSION_NAME.to_string());
+        }
+
+        Ok(())
+    }
+}
+
+/// Adds screen coverage to existing extras, keeping any other values.
+fn write_screen_coverage(
+    extras: Option<&serde_json::value::RawValue>,
+    screen_coverage: &[f32],
+) -> Result<Box<serde_json::value::RawValue>, serde_json::Error> {
+    let mut map = extras
+        .and_then(|raw| serde_json::from_str::<Map<String, Value>>(raw.get()).ok())
+        .unwrap_or_default();
+
+    map.insert(
+        SCREEN_COVERAGE_EXTRA.to_string(),
+        serde_json::to_value(screen_coverage)?,
+    );
+
+    serde_json::value::to_raw_value(&map)
+}
@@ -0,0 +1,110 @@
+use std::error::Error;
+
+use serde_json::{Map, Value, value::RawValue};
+use tracing::warn;
+
+use crate::{
+    extensions::ExtensionImport,
+    graph::{ByteNode, Extensions, Graph, GraphNodeWeight, gltf::document::GltfDocument},
+    io::format::gltf::GltfFormat,
+};
+
+use super::{EXTENSION_NAME, MsftLod, SCREEN_COVERAGE_EXTRA, json::LodJson, weight::MsftLodWeight};
+
+impl ExtensionImport<GltfDocument, GltfFormat> for MsftLod {
+    fn supported_extensions() -> Vec<&'static str> {
+        vec![EXTENSION_NAME]
+    }
+
+    fn import(
+        graph: &mut Graph,
+        format: &mut GltfFormat,
+        doc: &GltfDocument,
+    ) -> Result<(), Box<dyn Error>> {
+        let nodes = doc.nodes(graph);
+
+        for (i, n) in format.json.nodes.iter().enumerate() {
+            let Some(value) = n
+                .extensions
+                .as_ref()
+                .and_then(|e| e.others.get(EXTENSION_NAME))
+            else {
+                continue;
+            };
+
+            let json = serde_json::from_value::<LodJson>(value.clone())?;
+            let levels = json
+                .ids
+                .iter()
+                .filter_map(|id| {
+                    let level = nodes.get(*id).copied();
+                    if level.is_none() {
+                        warn!("LOD node index {} out of bounds", id);
+                    }
+                    level
+                })
+                .collect::<Vec<_>>();
+
+            let mut node = nodes[i];
+            let weight = node.get_mut(graph);
+
+            // Move screen coverage out of the node extras, into the extension.
+            let (screen_coverage, extras) = take_screen_coverage(weight.extras.as_deref());
+            weight.extras = extras;
+
+            let ext = node.create_extension::<Self>(graph);
+            ext.write(graph, &MsftLodWeight { screen_coverage });
+            ext.set_levels(graph, &levels);
+        }
+
+        let materials = doc.materials(graph);
+
+        for (i, m) in format.json.materials.iter().enumerate() {
+            let Some(value) = m
+                .extensions
+                .as_ref()
+                .and_then(|e| e.others.get(EXTENSION_NAME))
+            else {
+                continue;
+            };
+
+            let json = serde_json::from_value::<LodJson>(value.clone())?;
+            let levels = json
+                .ids
+                .iter()
+                .filter_map(|id| materials.get(*id).copied())
+                .collect::<Vec<_>>();
+
+            let ext = materials[i].create_extension::<Self>(graph);
+            ext.set_levels(graph, &levels);
+        }
+
+        Ok(())
+    }
+}
+
+/// Splits the screen coverage from the rest of the extras.
+fn take_screen_coverage(extras: Option<&RawValue>) -> (Vec<f32>, Option<Box<RawValue>>) {
+    let Some(mut map) =
+        extras.and_then(|raw| serde_json::from_str::<Map<String, Value>>(raw.get()).ok())
+    else {
+        return (Vec::new(), extras.map(ToOwned::to_owned));
+    };
+
+    let Some(value) = map.remove(SCREEN_COVERAGE_EXTRA) else {
+        return (Vec::new(), extras.map(ToOwned::to_owned));
+    };
+
+    let screen_coverage = serde_json::from_value(value).unwrap_or_else(|e| {
+        warn!("Invalid {}: {}", SCREEN_COVERAGE_EXTRA, e);
+        Vec::new()
+    });
+
+    let extras = if map.is_empty() {
+        None
+    } else {
+        serde_json::value::to_raw_value(&map).ok()
+    };
+
+    (screen_coverage, extras)
+}
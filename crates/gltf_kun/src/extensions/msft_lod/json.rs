@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct LodJson {
+    /// Indices of the lower detail levels.
+    pub ids: Vec<usize>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lod_serde() {
+        let json = r#"{"ids":[2,1]}"#;
+        let lod = serde_json::from_str::<LodJson>(json).expect("json should deserialize");
+        assert_eq!(lod.ids, vec![2, 1]);
+
+        let json_2 = serde_json::to_string(&lod).expect("json should serialize");
+        assert_eq!(json, json_2);
+    }
+}
@@ -0,0 +1,78 @@
+//! [MSFT_lod](https://github.com/KhronosGroup/glTF/tree/main/extensions/2.0/Vendor/MSFT_lod)
+//! extension, on nodes and materials.
+//!
+//! Node screen coverage thresholds are read from and written to the `MSFT_screencoverage` extra.
+
+use petgraph::graph::NodeIndex;
+
+use crate::graph::{ByteNode, Graph, OtherEdgeHelpers};
+
+use self::weight::MsftLodWeight;
+
+use super::Extension;
+
+pub mod export;
+pub mod import;
+pub mod json;
+pub mod weight;
+
+pub const EXTENSION_NAME: &str = "MSFT_lod";
+pub const SCREEN_COVERAGE_EXTRA: &str = "MSFT_screencoverage";
+
+pub const LEVEL_EDGE: &str = "MSFT_lod/level";
+
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct MsftLod(pub NodeIndex);
+
+impl From<NodeIndex> for MsftLod {
+    fn from(index: NodeIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl From<MsftLod> for NodeIndex {
+    fn from(lod: MsftLod) -> Self {
+        lod.0
+    }
+}
+
+impl ByteNode<MsftLodWeight> for MsftLod {}
+impl OtherEdgeHelpers for MsftLod {}
+
+impl Extension for MsftLod {
+    fn name() -> &'static str {
+        EXTENSION_NAME
+    }
+}
+
+impl MsftLod {
+    /// Lower detail levels, in order.
+    /// The property with the extension is the highest level of detail.
+    ///
+    /// Levels are [nodes](crate::graph::gltf::Node) for a node extension,
+    /// and [materials](crate::graph::gltf::Material) for a material extension.
+    pub fn levels<P: Copy + From<NodeIndex> + Into<NodeIndex>>(&self, graph: &Graph) -> Vec<P> {
+        (0..)
+            .map_while(|i| self.find_property(graph, &level_edge(i)))
+            .collect()
+    }
+    pub fn set_levels<P: Copy + Into<NodeIndex>>(&self, graph: &mut Graph, levels: &[P]) {
+        let mut i = 0;
+        while self
+            .find_other_edges(graph, &level_edge(i))
+            .next()
+            .is_some()
+        {
+            self.set_property::<NodeIndex>(graph, level_edge(i), None);
+            i += 1;
+        }
+
+        for (i, level) in levels.iter().enumerate() {
+            self.add_property(graph, level_edge(i), *level);
+        }
+    }
+}
+
+fn level_edge(index: usize) -> String {
+    format!("{LEVEL_EDGE}/{index}")
+}
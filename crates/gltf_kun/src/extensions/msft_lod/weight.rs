@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct MsftLodWeight {
+    /// Minimum screen coverage for each level, starting with the highest level of detail.
+    /// An additional final value is the coverage below which the object is culled.
+    pub screen_coverage: Vec<f32>,
+}
+
+impl From<&Vec<u8>> for MsftLodWeight {
+    fn from(bytes: &Vec<u8>) -> Self {
+        if bytes.is_empty() {
+            return Self::default();
+        }
+        serde_json::from_slice(bytes).expect("Failed to deserialize weight")
+    }
+}
+
+impl From<&MsftLodWeight> for Vec<u8> {
+    fn from(value: &MsftLodWeight) -> Self {
+        serde_json::to_vec(value).expect("Failed to serialize weight")
+    }
+}
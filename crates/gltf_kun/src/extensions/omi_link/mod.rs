@@ -0,0 +1,39 @@
+//! [OMI_link](https://github.com/omigroup/gltf-extensions/tree/main/extensions/2.0/OMI_link)
+//! extension.
+
+use petgraph::graph::NodeIndex;
+
+use crate::graph::ByteNode;
+
+use self::weight::OmiLinkWeight;
+
+use super::Extension;
+
+pub mod export;
+pub mod import;
+pub mod weight;
+
+pub const EXTENSION_NAME: &str = "OMI_link";
+
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct OmiLink(pub NodeIndex);
+
+impl From<NodeIndex> for OmiLink {
+    fn from(index: NodeIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl From<OmiLink> for NodeIndex {
+    fn from(link: OmiLink) -> Self {
+        link.0
+    }
+}
+
+impl ByteNode<OmiLinkWeight> for OmiLink {}
+
+impl Extension for OmiLink {
+    fn name() -> &'static str {
+        EXTENSION_NAME
+    }
+}
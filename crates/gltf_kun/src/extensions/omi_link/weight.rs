@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct OmiLinkWeight {
+    /// The URI to link to, such as another glTF world.
+    pub uri: String,
+    /// A human-readable title for the link.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+impl From<&Vec<u8>> for OmiLinkWeight {
+    fn from(bytes: &Vec<u8>) -> Self {
+        if bytes.is_empty() {
+            return Self::default();
+        }
+        serde_json::from_slice(bytes).expect("Failed to deserialize weight")
+    }
+}
+
+impl From<&OmiLinkWeight> for Vec<u8> {
+    fn from(value: &OmiLinkWeight) -> Self {
+        serde_json::to_vec(value).expect("Failed to serialize weight")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn link_serde() {
+        let weight = OmiLinkWeight {
+            uri: "https://example.com/world.gltf".to_string(),
+            title: Some("Example World".to_string()),
+        };
+
+        let json = serde_json::to_string(&weight).expect("json should serialize");
+        let expected = r#"{"uri":"https://example.com/world.gltf","title":"Example World"}"#;
+        assert_eq!(json, expected);
+
+        let weight_2 =
+            serde_json::from_str::<OmiLinkWeight>(&json).expect("json should deserialize");
+        assert_eq!(weight, weight_2);
+    }
+}
@@ -8,7 +8,7 @@ use crate::{
 
 use super::{
     EXTENSION_NAME, OmiPhysicsBody,
-    json::{PhysicsBodyJson, ShapeRefJson},
+    json::{PhysicsBodyJson, RootExtension, ShapeRefJson},
 };
 
 impl ExtensionExport<GltfDocument, GltfFormat> for OmiPhysicsBody {
@@ -18,6 +18,7 @@ impl ExtensionExport<GltfDocument, GltfFormat> for OmiPhysicsBody {
         format: &mut GltfFormat,
     ) -> Result<(), Box<dyn Error>> {
         let mut added_extension = false;
+        let mut root = RootExtension::default();
 
         doc.nodes(graph)
             .iter()
@@ -26,6 +27,13 @@ impl ExtensionExport<GltfDocument, GltfFormat> for OmiPhysicsBody {
             .for_each(|(i, ext)| {
                 let weight = ext.read(graph);
 
+                let physics_material = weight
+                    .physics_material
+                    .map(|m| find_or_push(&mut root.physics_materials, m));
+                let collision_filter = weight
+                    .collision_filter
+                    .map(|f| find_or_push(&mut root.collision_filters, f));
+
                 let node = format
                     .json
                     .nodes
@@ -43,6 +51,8 @@ impl ExtensionExport<GltfDocument, GltfFormat> for OmiPhysicsBody {
                     .find_map(|(e, s)| e.shapes(graph).into_iter().position(|x| x == *s))
                     .map(|shape| ShapeRefJson {
                         shape: shape as isize,
+                        physics_material,
+                        collision_filter,
                     });
 
                 let trigger = ext
@@ -52,6 +62,9 @@ impl ExtensionExport<GltfDocument, GltfFormat> for OmiPhysicsBody {
                     .find_map(|(e, s)| e.shapes(graph).into_iter().position(|x| x == *s))
                     .map(|shape| ShapeRefJson {
                         shape: shape as isize,
+                        physics_material: None,
+                        // Triggers do not use physics materials, but can be filtered.
+                        collision_filter,
                     });
 
                 let json = PhysicsBodyJson {
@@ -68,6 +81,17 @@ impl ExtensionExport<GltfDocument, GltfFormat> for OmiPhysicsBody {
                 added_extension = true;
             });
 
+        if root != RootExtension::default() {
+            let extensions = format
+                .json
+                .extensions
+                .get_or_insert_with(gltf::json::extensions::Root::default);
+
+            extensions
+                .others
+                .insert(EXTENSION_NAME.to_string(), serde_json::to_value(root)?);
+        }
+
         if added_extension {
             format.json.extensions_used.push(EXTENSION_NAME.to_string());
         }
@@ -75,3 +99,11 @@ impl ExtensionExport<GltfDocument, GltfFormat> for OmiPhysicsBody {
         Ok(())
     }
 }
+
+fn find_or_push<T: PartialEq>(items: &mut Vec<T>, item: T) -> isize {
+    let idx = items.iter().position(|x| *x == item).unwrap_or_else(|| {
+        items.push(item);
+        items.len() - 1
+    });
+    idx as isize
+}
@@ -8,14 +8,31 @@ use crate::{
     io::format::gltf::GltfFormat,
 };
 
-use super::{EXTENSION_NAME, OmiPhysicsBody, OmiPhysicsBodyWeight, json::PhysicsBodyJson};
+use super::{
+    EXTENSION_NAME, OmiPhysicsBody, OmiPhysicsBodyWeight,
+    json::{PhysicsBodyJson, RootExtension, ShapeRefJson},
+};
 
 impl ExtensionImport<GltfDocument, GltfFormat> for OmiPhysicsBody {
+    fn supported_extensions() -> Vec<&'static str> {
+        vec![EXTENSION_NAME]
+    }
+
     fn import(
         graph: &mut crate::graph::Graph,
         format: &mut GltfFormat,
         doc: &GltfDocument,
     ) -> Result<(), Box<dyn Error>> {
+        let root = match format
+            .json
+            .extensions
+            .as_ref()
+            .and_then(|e| e.others.get(EXTENSION_NAME))
+        {
+            Some(value) => serde_json::from_value::<RootExtension>(value.clone())?,
+            None => RootExtension::default(),
+        };
+
         format
             .json
             .nodes
@@ -33,9 +50,26 @@ impl ExtensionImport<GltfDocument, GltfFormat> for OmiPhysicsBody {
                 let node = nodes.get(i).expect("Node index out of bounds");
                 let ext = node.create_extension::<Self>(graph);
 
-                if let Some(motion) = json.motion {
+                let shape_refs = [json.collider.as_ref(), json.trigger.as_ref()];
+
+                let physics_material = shape_refs
+                    .iter()
+                    .flatten()
+                    .find_map(|r| r.physics_material)
+                    .and_then(|idx| get_root_item(&root.physics_materials, idx));
+
+                let collision_filter = shape_refs
+                    .iter()
+                    .flatten()
+                    .find_map(|r| r.collision_filter)
+                    .and_then(|idx| get_root_item(&root.collision_filters, idx));
+
+                if json.motion.is_some() || physics_material.is_some() || collision_filter.is_some()
+                {
                     let weight = OmiPhysicsBodyWeight {
-                        motion: Some(motion),
+                        motion: json.motion,
+                        physics_material,
+                        collision_filter,
                     };
                     ext.write(graph, &weight);
                 }
@@ -49,8 +83,8 @@ impl ExtensionImport<GltfDocument, GltfFormat> for OmiPhysicsBody {
                     return;
                 };
 
-                if let Some(shape_ref) = json.collider
-                    && let Ok(idx) = shape_ref.shape.try_into()
+                if let Some(ShapeRefJson { shape, .. }) = json.collider
+                    && let Ok(idx) = shape.try_into()
                 {
                     let idx: usize = idx;
 
@@ -59,8 +93,8 @@ impl ExtensionImport<GltfDocument, GltfFormat> for OmiPhysicsBody {
                     ext.set_collider(graph, Some(shape));
                 }
 
-                if let Some(shape_ref) = json.trigger
-                    && let Ok(idx) = shape_ref.shape.try_into()
+                if let Some(ShapeRefJson { shape, .. }) = json.trigger
+                    && let Ok(idx) = shape.try_into()
                 {
                     let idx: usize = idx;
 
@@ -73,3 +107,16 @@ impl ExtensionImport<GltfDocument, GltfFormat> for OmiPhysicsBody {
         Ok(())
     }
 }
+
+fn get_root_item<T: Clone>(items: &[T], idx: isize) -> Option<T> {
+    let item = usize::try_from(idx)
+        .ok()
+        .and_then(|i| items.get(i))
+        .cloned();
+
+    if item.is_none() {
+        warn!("{} root index {} out of bounds", EXTENSION_NAME, idx);
+    }
+
+    item
+}
@@ -1,6 +1,23 @@
 use serde::{Deserialize, Serialize};
 
-use super::weight::Motion;
+use super::weight::{CollisionFilter, Motion, PhysicsMaterial};
+
+/// Document-level `OMI_physics_body` data, referenced by index from shape refs.
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct RootExtension {
+    #[serde(
+        default,
+        rename = "physicsMaterials",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub physics_materials: Vec<PhysicsMaterial>,
+    #[serde(
+        default,
+        rename = "collisionFilters",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub collision_filters: Vec<CollisionFilter>,
+}
 
 #[derive(Debug, Deserialize, PartialEq, Serialize)]
 pub struct PhysicsBodyJson {
@@ -15,6 +32,18 @@ pub struct PhysicsBodyJson {
 #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct ShapeRefJson {
     pub shape: isize,
+    #[serde(
+        default,
+        rename = "physicsMaterial",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub physics_material: Option<isize>,
+    #[serde(
+        default,
+        rename = "collisionFilter",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub collision_filter: Option<isize>,
 }
 
 #[cfg(test)]
@@ -39,4 +68,25 @@ mod tests {
             serde_json::from_str::<PhysicsBodyJson>(&json_str).expect("json should deserialize");
         assert_eq!(json, json_2);
     }
+
+    #[test]
+    fn shape_ref_serde() {
+        let json = PhysicsBodyJson {
+            motion: None,
+            trigger: None,
+            collider: Some(ShapeRefJson {
+                shape: 0,
+                physics_material: Some(1),
+                collision_filter: None,
+            }),
+        };
+
+        let json_str = serde_json::to_string(&json).expect("json should serialize");
+        let expected = r#"{"collider":{"shape":0,"physicsMaterial":1}}"#;
+        assert_eq!(json_str, expected);
+
+        let json_2 =
+            serde_json::from_str::<PhysicsBodyJson>(&json_str).expect("json should deserialize");
+        assert_eq!(json, json_2);
+    }
 }
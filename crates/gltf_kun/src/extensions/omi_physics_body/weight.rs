@@ -3,6 +3,10 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
 pub struct OmiPhysicsBodyWeight {
     pub motion: Option<Motion>,
+    #[serde(default)]
+    pub physics_material: Option<PhysicsMaterial>,
+    #[serde(default)]
+    pub collision_filter: Option<CollisionFilter>,
 }
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
@@ -50,6 +54,86 @@ pub struct Motion {
     pub inertia_orientation: Quat,
 }
 
+/// Surface properties used when resolving contacts between colliders.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct PhysicsMaterial {
+    /// The friction coefficient used when the surfaces are not moving relative to each other.
+    #[serde(default = "default_friction", rename = "staticFriction")]
+    pub static_friction: f32,
+    /// The friction coefficient used when the surfaces are sliding against each other.
+    #[serde(default = "default_friction", rename = "dynamicFriction")]
+    pub dynamic_friction: f32,
+    /// The bounciness of the surface, between 0 and 1.
+    #[serde(default, skip_serializing_if = "float_is_zero")]
+    pub restitution: f32,
+    /// How to combine the friction of two colliding surfaces.
+    #[serde(
+        default,
+        rename = "frictionCombine",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub friction_combine: Option<CombineMode>,
+    /// How to combine the restitution of two colliding surfaces.
+    #[serde(
+        default,
+        rename = "restitutionCombine",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub restitution_combine: Option<CombineMode>,
+}
+
+impl Default for PhysicsMaterial {
+    fn default() -> Self {
+        Self {
+            static_friction: default_friction(),
+            dynamic_friction: default_friction(),
+            restitution: 0.0,
+            friction_combine: None,
+            restitution_combine: None,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum CombineMode {
+    #[default]
+    #[serde(rename = "average")]
+    Average,
+    #[serde(rename = "minimum")]
+    Minimum,
+    #[serde(rename = "maximum")]
+    Maximum,
+    #[serde(rename = "multiply")]
+    Multiply,
+}
+
+/// Named collision systems used to decide which colliders interact.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct CollisionFilter {
+    /// The systems this collider belongs to.
+    #[serde(
+        default,
+        rename = "collisionSystems",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub collision_systems: Vec<String>,
+    /// If not empty, the only systems this collider will collide with.
+    #[serde(
+        default,
+        rename = "collideWithSystems",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub collide_with_systems: Vec<String>,
+    /// Systems this collider will not collide with.
+    /// Ignored if `collide_with_systems` is not empty.
+    #[serde(
+        default,
+        rename = "notCollideWithSystems",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub not_collide_with_systems: Vec<String>,
+}
+
 #[derive(Copy, Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Mass(pub f32);
 
@@ -113,6 +197,10 @@ pub enum BodyType {
     Kinematic,
 }
 
+const fn default_friction() -> f32 {
+    0.6
+}
+
 #[allow(clippy::trivially_copy_pass_by_ref)]
 fn is_default_mass(mass: &Mass) -> bool {
     *mass == Mass::default()
@@ -152,4 +240,12 @@ mod tests {
         assert!((q.0[3].abs() - 1.0) < EPSILON);
         assert!(is_default_quat(&q));
     }
+
+    #[test]
+    fn default_physics_material() {
+        let material = serde_json::from_str::<PhysicsMaterial>("{}").expect("should deserialize");
+        assert_eq!(material, PhysicsMaterial::default());
+        assert!((material.static_friction - 0.6).abs() < EPSILON);
+        assert!((material.dynamic_friction - 0.6).abs() < EPSILON);
+    }
 }
@@ -7,6 +7,10 @@ use crate::{
 use super::{EXTENSION_NAME, OmiPhysicsShape, json::RootExtension};
 
 impl ExtensionImport<GltfDocument, GltfFormat> for OmiPhysicsShape {
+    fn supported_extensions() -> Vec<&'static str> {
+        vec![EXTENSION_NAME]
+    }
+
     fn import(
         graph: &mut Graph,
         format: &mut GltfFormat,
@@ -0,0 +1,39 @@
+//! [OMI_seat](https://github.com/omigroup/gltf-extensions/tree/main/extensions/2.0/OMI_seat)
+//! extension.
+
+use petgraph::graph::NodeIndex;
+
+use crate::graph::ByteNode;
+
+use self::weight::OmiSeatWeight;
+
+use super::Extension;
+
+pub mod export;
+pub mod import;
+pub mod weight;
+
+pub const EXTENSION_NAME: &str = "OMI_seat";
+
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct OmiSeat(pub NodeIndex);
+
+impl From<NodeIndex> for OmiSeat {
+    fn from(index: NodeIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl From<OmiSeat> for NodeIndex {
+    fn from(seat: OmiSeat) -> Self {
+        seat.0
+    }
+}
+
+impl ByteNode<OmiSeatWeight> for OmiSeat {}
+
+impl Extension for OmiSeat {
+    fn name() -> &'static str {
+        EXTENSION_NAME
+    }
+}
@@ -0,0 +1,75 @@
+use std::f32::consts::FRAC_PI_2;
+
+use serde::{Deserialize, Serialize};
+
+/// Seat IK targets, in the local space of the seat node.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct OmiSeatWeight {
+    /// The position limit for the back of the seat.
+    pub back: [f32; 3],
+    /// The position limit for the feet of the seated character.
+    pub foot: [f32; 3],
+    /// The position limit for the knees of the seated character.
+    pub knee: [f32; 3],
+    /// The angle between the spine and the back-knee line, in radians.
+    #[serde(default = "default_angle", skip_serializing_if = "is_default_angle")]
+    pub angle: f32,
+}
+
+impl Default for OmiSeatWeight {
+    fn default() -> Self {
+        Self {
+            back: [0.0; 3],
+            foot: [0.0; 3],
+            knee: [0.0; 3],
+            angle: default_angle(),
+        }
+    }
+}
+
+impl From<&Vec<u8>> for OmiSeatWeight {
+    fn from(bytes: &Vec<u8>) -> Self {
+        if bytes.is_empty() {
+            return Self::default();
+        }
+        serde_json::from_slice(bytes).expect("Failed to deserialize weight")
+    }
+}
+
+impl From<&OmiSeatWeight> for Vec<u8> {
+    fn from(value: &OmiSeatWeight) -> Self {
+        serde_json::to_vec(value).expect("Failed to serialize weight")
+    }
+}
+
+const fn default_angle() -> f32 {
+    FRAC_PI_2
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn is_default_angle(angle: &f32) -> bool {
+    (*angle - default_angle()).abs() < f32::EPSILON
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seat_serde() {
+        let weight = OmiSeatWeight {
+            back: [0.0, 0.0, -0.3],
+            foot: [0.0, -0.5, 0.4],
+            knee: [0.0, 0.0, 0.3],
+            angle: FRAC_PI_2,
+        };
+
+        let json = serde_json::to_string(&weight).expect("json should serialize");
+        let expected = r#"{"back":[0.0,0.0,-0.3],"foot":[0.0,-0.5,0.4],"knee":[0.0,0.0,0.3]}"#;
+        assert_eq!(json, expected);
+
+        let weight_2 =
+            serde_json::from_str::<OmiSeatWeight>(&json).expect("json should deserialize");
+        assert_eq!(weight, weight_2);
+    }
+}
@@ -0,0 +1,40 @@
+use std::error::Error;
+
+use crate::{
+    extensions::ExtensionImport,
+    graph::{ByteNode, Extensions, Graph, gltf::document::GltfDocument},
+    io::format::gltf::GltfFormat,
+};
+
+use super::{EXTENSION_NAME, OmiSpawnPoint, weight::OmiSpawnPointWeight};
+
+impl ExtensionImport<GltfDocument, GltfFormat> for OmiSpawnPoint {
+    fn supported_extensions() -> Vec<&'static str> {
+        vec![EXTENSION_NAME]
+    }
+
+    fn import(
+        graph: &mut Graph,
+        format: &mut GltfFormat,
+        doc: &GltfDocument,
+    ) -> Result<(), Box<dyn Error>> {
+        let nodes = doc.nodes(graph);
+
+        for (i, n) in format.json.nodes.iter().enumerate() {
+            let Some(value) = n
+                .extensions
+                .as_ref()
+                .and_then(|e| e.others.get(EXTENSION_NAME))
+            else {
+                continue;
+            };
+
+            let weight = serde_json::from_value::<OmiSpawnPointWeight>(value.clone())?;
+
+            let ext = nodes[i].create_extension::<Self>(graph);
+            ext.write(graph, &weight);
+        }
+
+        Ok(())
+    }
+}
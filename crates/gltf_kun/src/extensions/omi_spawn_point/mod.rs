@@ -0,0 +1,39 @@
+//! [OMI_spawn_point](https://github.com/omigroup/gltf-extensions/tree/main/extensions/2.0/OMI_spawn_point)
+//! extension.
+
+use petgraph::graph::NodeIndex;
+
+use crate::graph::ByteNode;
+
+use self::weight::OmiSpawnPointWeight;
+
+use super::Extension;
+
+pub mod export;
+pub mod import;
+pub mod weight;
+
+pub const EXTENSION_NAME: &str = "OMI_spawn_point";
+
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct OmiSpawnPoint(pub NodeIndex);
+
+impl From<NodeIndex> for OmiSpawnPoint {
+    fn from(index: NodeIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl From<OmiSpawnPoint> for NodeIndex {
+    fn from(spawn_point: OmiSpawnPoint) -> Self {
+        spawn_point.0
+    }
+}
+
+impl ByteNode<OmiSpawnPointWeight> for OmiSpawnPoint {}
+
+impl Extension for OmiSpawnPoint {
+    fn name() -> &'static str {
+        EXTENSION_NAME
+    }
+}
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct OmiSpawnPointWeight {
+    /// The title of the spawn point.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The team that should spawn at this point.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<String>,
+    /// The group this spawn point belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+}
+
+impl From<&Vec<u8>> for OmiSpawnPointWeight {
+    fn from(bytes: &Vec<u8>) -> Self {
+        if bytes.is_empty() {
+            return Self::default();
+        }
+        serde_json::from_slice(bytes).expect("Failed to deserialize weight")
+    }
+}
+
+impl From<&OmiSpawnPointWeight> for Vec<u8> {
+    fn from(value: &OmiSpawnPointWeight) -> Self {
+        serde_json::to_vec(value).expect("Failed to serialize weight")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spawn_point_serde() {
+        let weight = OmiSpawnPointWeight {
+            title: Some("Red Base".to_string()),
+            team: Some("Red".to_string()),
+            group: None,
+        };
+
+        let json = serde_json::to_string(&weight).expect("json should serialize");
+        let expected = r#"{"title":"Red Base","team":"Red"}"#;
+        assert_eq!(json, expected);
+
+        let weight_2 =
+            serde_json::from_str::<OmiSpawnPointWeight>(&json).expect("json should deserialize");
+        assert_eq!(weight, weight_2);
+    }
+}
@@ -0,0 +1,143 @@
+use petgraph::graph::NodeIndex;
+use serde::{Deserialize, Serialize};
+
+use crate::graph::{ByteNode, Graph, OtherEdgeHelpers, Weight, gltf::node::Node};
+
+pub const COLLIDER_NODE_EDGE: &str = "VRMC_springBone/collider/node";
+pub const GROUP_COLLIDER_EDGE: &str = "VRMC_springBone/collider_group/collider";
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct ColliderWeight {
+    pub shape: ColliderShape,
+}
+
+/// Collider shape, in the local space of its node.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ColliderShape {
+    Sphere {
+        #[serde(default)]
+        offset: [f32; 3],
+        #[serde(default)]
+        radius: f32,
+    },
+    Capsule {
+        #[serde(default)]
+        offset: [f32; 3],
+        #[serde(default)]
+        radius: f32,
+        #[serde(default)]
+        tail: [f32; 3],
+    },
+}
+
+impl Default for ColliderShape {
+    fn default() -> Self {
+        Self::Sphere {
+            offset: [0.0; 3],
+            radius: 0.0,
+        }
+    }
+}
+
+impl From<&Vec<u8>> for ColliderWeight {
+    fn from(bytes: &Vec<u8>) -> Self {
+        if bytes.is_empty() {
+            return Self::default();
+        }
+        serde_json::from_slice(bytes).expect("Failed to deserialize weight")
+    }
+}
+
+impl From<&ColliderWeight> for Vec<u8> {
+    fn from(value: &ColliderWeight) -> Self {
+        serde_json::to_vec(value).expect("Failed to serialize weight")
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Collider(pub NodeIndex);
+
+impl From<NodeIndex> for Collider {
+    fn from(index: NodeIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl From<Collider> for NodeIndex {
+    fn from(collider: Collider) -> Self {
+        collider.0
+    }
+}
+
+impl ByteNode<ColliderWeight> for Collider {}
+impl OtherEdgeHelpers for Collider {}
+
+impl Collider {
+    pub fn new(graph: &mut Graph, weight: &ColliderWeight) -> Self {
+        let index = graph.add_node(Weight::Bytes(weight.into()));
+        Self(index)
+    }
+
+    pub fn node(&self, graph: &Graph) -> Option<Node> {
+        self.find_property(graph, COLLIDER_NODE_EDGE)
+    }
+    pub fn set_node(&self, graph: &mut Graph, node: Option<Node>) {
+        self.set_property(graph, COLLIDER_NODE_EDGE.to_string(), node);
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ColliderGroupWeight {
+    pub name: Option<String>,
+}
+
+impl From<&Vec<u8>> for ColliderGroupWeight {
+    fn from(bytes: &Vec<u8>) -> Self {
+        if bytes.is_empty() {
+            return Self::default();
+        }
+        serde_json::from_slice(bytes).expect("Failed to deserialize weight")
+    }
+}
+
+impl From<&ColliderGroupWeight> for Vec<u8> {
+    fn from(value: &ColliderGroupWeight) -> Self {
+        serde_json::to_vec(value).expect("Failed to serialize weight")
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ColliderGroup(pub NodeIndex);
+
+impl From<NodeIndex> for ColliderGroup {
+    fn from(index: NodeIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl From<ColliderGroup> for NodeIndex {
+    fn from(group: ColliderGroup) -> Self {
+        group.0
+    }
+}
+
+impl ByteNode<ColliderGroupWeight> for ColliderGroup {}
+impl OtherEdgeHelpers for ColliderGroup {}
+
+impl ColliderGroup {
+    pub fn new(graph: &mut Graph, weight: &ColliderGroupWeight) -> Self {
+        let index = graph.add_node(Weight::Bytes(weight.into()));
+        Self(index)
+    }
+
+    pub fn colliders(&self, graph: &Graph) -> Vec<Collider> {
+        self.find_properties(graph, GROUP_COLLIDER_EDGE)
+    }
+    pub fn add_collider(&self, graph: &mut Graph, collider: Collider) {
+        self.add_property(graph, GROUP_COLLIDER_EDGE.to_string(), collider);
+    }
+    pub fn remove_collider(&self, graph: &mut Graph, collider: Collider) {
+        self.remove_property(graph, GROUP_COLLIDER_EDGE, collider);
+    }
+}
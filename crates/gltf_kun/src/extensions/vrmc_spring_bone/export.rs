@@ -0,0 +1,123 @@
+use std::error::Error;
+
+use crate::{
+    extensions::ExtensionExport,
+    graph::{
+        ByteNode, Extensions, Graph,
+        gltf::{document::GltfDocument, node::Node},
+    },
+    io::format::gltf::GltfFormat,
+};
+
+use super::{
+    EXTENSION_NAME, VrmcSpringBone,
+    collider::{Collider, ColliderGroup},
+    json::{ColliderGroupJson, ColliderJson, JointJson, RootExtension, SpringJson},
+    spring::Spring,
+};
+
+impl ExtensionExport<GltfDocument, GltfFormat> for VrmcSpringBone {
+    fn export(
+        graph: &mut Graph,
+        doc: &GltfDocument,
+        format: &mut GltfFormat,
+    ) -> Result<(), Box<dyn Error>> {
+        let Some(ext) = doc.get_extension::<Self>(graph) else {
+            return Ok(());
+        };
+
+        let nodes = doc.nodes(graph);
+        let node_index = |node: Node| nodes.iter().position(|x| *x == node);
+
+        // Colliders without a node cannot be represented, and are skipped.
+        let colliders = ext
+            .colliders(graph)
+            .into_iter()
+            .filter_map(|c| c.node(graph).and_then(node_index).map(|node| (c, node)))
+            .collect::<Vec<_>>();
+
+        let groups = ext.collider_groups(graph);
+
+        let root_colliders = colliders
+            .iter()
+            .map(|(collider, node)| ColliderJson {
+                node: *node,
+                shape: collider.read(graph).shape,
+            })
+            .collect();
+
+        let root_groups = groups
+            .iter()
+            .map(|group| export_collider_group(graph, &colliders, *group))
+            .collect();
+
+        let root_springs = ext
+            .springs(graph)
+            .into_iter()
+            .map(|spring| export_spring(graph, &node_index, &groups, spring))
+            .collect();
+
+        let root = RootExtension {
+            spec_version: ext.read(graph).spec_version,
+            colliders: root_colliders,
+            collider_groups: root_groups,
+            springs: root_springs,
+        };
+
+        let extensions = format
+            .json
+            .extensions
+            .get_or_insert_with(gltf::json::extensions::Root::default);
+
+        extensions
+            .others
+            .insert(EXTENSION_NAME.to_string(), serde_json::to_value(root)?);
+
+        format.json.extensions_used.push(EXTENSION_NAME.to_string());
+
+        Ok(())
+    }
+}
+
+fn export_collider_group(
+    graph: &Graph,
+    colliders: &[(Collider, usize)],
+    group: ColliderGroup,
+) -> ColliderGroupJson {
+    ColliderGroupJson {
+        name: group.read(graph).name,
+        colliders: group
+            .colliders(graph)
+            .iter()
+            .filter_map(|c| colliders.iter().position(|(x, _)| x == c))
+            .collect(),
+    }
+}
+
+fn export_spring(
+    graph: &Graph,
+    node_index: &impl Fn(Node) -> Option<usize>,
+    groups: &[ColliderGroup],
+    spring: Spring,
+) -> SpringJson {
+    SpringJson {
+        name: spring.read(graph).name,
+        joints: spring
+            .joints(graph)
+            .into_iter()
+            .filter_map(|joint| {
+                let node = joint.node(graph).and_then(node_index)?;
+                Some(JointJson {
+                    node,
+                    weight: joint.read(graph),
+                })
+            })
+            .collect(),
+        collider_groups: spring
+            .collider_groups(graph)
+            .iter()
+            .filter_map(|g| groups.iter().position(|x| x == g))
+            .collect(),
+        center: spring.center(graph).and_then(node_index),
+    }
+}
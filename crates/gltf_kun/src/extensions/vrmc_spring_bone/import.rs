@@ -0,0 +1,106 @@
+use std::error::Error;
+
+use tracing::warn;
+
+use crate::{
+    extensions::ExtensionImport,
+    graph::{ByteNode, Extensions, Graph, gltf::document::GltfDocument},
+    io::format::gltf::GltfFormat,
+};
+
+use super::{
+    EXTENSION_NAME, VrmcSpringBone,
+    collider::{ColliderGroupWeight, ColliderWeight},
+    json::RootExtension,
+    spring::SpringWeight,
+    weight::VrmcSpringBoneWeight,
+};
+
+impl ExtensionImport<GltfDocument, GltfFormat> for VrmcSpringBone {
+    fn supported_extensions() -> Vec<&'static str> {
+        vec![EXTENSION_NAME]
+    }
+
+    fn import(
+        graph: &mut Graph,
+        format: &mut GltfFormat,
+        doc: &GltfDocument,
+    ) -> Result<(), Box<dyn Error>> {
+        let Some(value) = format
+            .json
+            .extensions
+            .as_ref()
+            .and_then(|e| e.others.get(EXTENSION_NAME))
+        else {
+            return Ok(());
+        };
+
+        let root = serde_json::from_value::<RootExtension>(value.clone())?;
+
+        let ext = doc
+            .get_extension::<Self>(graph)
+            .unwrap_or_else(|| doc.create_extension::<Self>(graph));
+
+        ext.write(
+            graph,
+            &VrmcSpringBoneWeight {
+                spec_version: root.spec_version,
+            },
+        );
+
+        let nodes = doc.nodes(graph);
+
+        let colliders = root
+            .colliders
+            .into_iter()
+            .map(|json| {
+                let collider = ext.create_collider(graph, &ColliderWeight { shape: json.shape });
+
+                if let Some(node) = nodes.get(json.node) {
+                    collider.set_node(graph, Some(*node));
+                } else {
+                    warn!("Collider node index {} out of bounds", json.node);
+                }
+
+                collider
+            })
+            .collect::<Vec<_>>();
+
+        let groups = root
+            .collider_groups
+            .into_iter()
+            .map(|json| {
+                let group =
+                    ext.create_collider_group(graph, &ColliderGroupWeight { name: json.name });
+
+                for collider in json.colliders.iter().filter_map(|i| colliders.get(*i)) {
+                    group.add_collider(graph, *collider);
+                }
+
+                group
+            })
+            .collect::<Vec<_>>();
+
+        for json in root.springs {
+            let spring = ext.create_spring(graph, &SpringWeight { name: json.name });
+
+            for joint in json.joints {
+                let Some(node) = nodes.get(joint.node) else {
+                    warn!("Spring joint node index {} out of bounds", joint.node);
+                    continue;
+                };
+
+                spring.create_joint(graph, *node, &joint.weight);
+            }
+
+            for group in json.collider_groups.iter().filter_map(|i| groups.get(*i)) {
+                spring.add_collider_group(graph, *group);
+            }
+
+            let center = json.center.and_then(|i| nodes.get(i).copied());
+            spring.set_center(graph, center);
+        }
+
+        Ok(())
+    }
+}
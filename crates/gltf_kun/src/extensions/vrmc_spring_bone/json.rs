@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+
+use super::{collider::ColliderShape, spring::SpringJointWeight};
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RootExtension {
+    pub spec_version: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub colliders: Vec<ColliderJson>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub collider_groups: Vec<ColliderGroupJson>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub springs: Vec<SpringJson>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct ColliderJson {
+    pub node: usize,
+    pub shape: ColliderShape,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ColliderGroupJson {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub colliders: Vec<usize>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpringJson {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub joints: Vec<JointJson>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub collider_groups: Vec<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub center: Option<usize>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct JointJson {
+    pub node: usize,
+    #[serde(flatten)]
+    pub weight: SpringJointWeight,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn root_serde() {
+        let json = r#"{
+            "specVersion": "1.0",
+            "colliders": [
+                { "node": 0, "shape": { "sphere": { "offset": [0, 0.1, 0], "radius": 0.2 } } },
+                { "node": 1, "shape": { "capsule": { "radius": 0.05, "tail": [0, 0.3, 0] } } }
+            ],
+            "colliderGroups": [{ "name": "body", "colliders": [0, 1] }],
+            "springs": [{
+                "name": "hair",
+                "joints": [
+                    { "node": 2, "hitRadius": 0.02, "gravityPower": 0.5 },
+                    { "node": 3 }
+                ],
+                "colliderGroups": [0]
+            }]
+        }"#;
+
+        let root = serde_json::from_str::<RootExtension>(json).expect("json should deserialize");
+
+        assert_eq!(root.colliders.len(), 2);
+        assert!(matches!(
+            root.colliders[1].shape,
+            ColliderShape::Capsule { radius, .. } if (radius - 0.05).abs() < f32::EPSILON
+        ));
+
+        let joints = &root.springs[0].joints;
+        assert!((joints[0].weight.gravity_power - 0.5).abs() < f32::EPSILON);
+        assert_eq!(joints[1].weight, SpringJointWeight::default());
+
+        let json_2 = serde_json::to_string(&root).expect("json should serialize");
+        let root_2 =
+            serde_json::from_str::<RootExtension>(&json_2).expect("json should deserialize");
+        assert_eq!(root, root_2);
+    }
+}
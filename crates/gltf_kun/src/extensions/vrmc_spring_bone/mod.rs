@@ -0,0 +1,103 @@
+//! [VRMC_springBone](https://github.com/vrm-c/vrm-specification/tree/master/specification/VRMC_springBone-1.0)
+//! extension.
+
+use petgraph::graph::NodeIndex;
+
+use crate::graph::{ByteNode, Graph, OtherEdgeHelpers};
+
+use self::{
+    collider::{Collider, ColliderGroup, ColliderGroupWeight, ColliderWeight},
+    spring::{Spring, SpringWeight},
+    weight::VrmcSpringBoneWeight,
+};
+
+use super::Extension;
+
+pub mod collider;
+pub mod export;
+pub mod import;
+pub mod json;
+pub mod spring;
+pub mod weight;
+
+pub const EXTENSION_NAME: &str = "VRMC_springBone";
+
+pub const COLLIDER_EDGE: &str = "VRMC_springBone/collider";
+pub const COLLIDER_GROUP_EDGE: &str = "VRMC_springBone/collider_group";
+pub const SPRING_EDGE: &str = "VRMC_springBone/spring";
+
+/// Document-level spring bone colliders, collider groups and springs.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct VrmcSpringBone(pub NodeIndex);
+
+impl From<NodeIndex> for VrmcSpringBone {
+    fn from(index: NodeIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl From<VrmcSpringBone> for NodeIndex {
+    fn from(spring_bone: VrmcSpringBone) -> Self {
+        spring_bone.0
+    }
+}
+
+impl ByteNode<VrmcSpringBoneWeight> for VrmcSpringBone {}
+impl OtherEdgeHelpers for VrmcSpringBone {}
+
+impl Extension for VrmcSpringBone {
+    fn name() -> &'static str {
+        EXTENSION_NAME
+    }
+}
+
+impl VrmcSpringBone {
+    pub fn colliders(&self, graph: &Graph) -> Vec<Collider> {
+        self.find_properties(graph, COLLIDER_EDGE)
+    }
+    pub fn add_collider(&self, graph: &mut Graph, collider: Collider) {
+        self.add_property(graph, COLLIDER_EDGE.to_string(), collider);
+    }
+    pub fn remove_collider(&self, graph: &mut Graph, collider: Collider) {
+        self.remove_property(graph, COLLIDER_EDGE, collider);
+    }
+    pub fn create_collider(&self, graph: &mut Graph, weight: &ColliderWeight) -> Collider {
+        let collider = Collider::new(graph, weight);
+        self.add_collider(graph, collider);
+        collider
+    }
+
+    pub fn collider_groups(&self, graph: &Graph) -> Vec<ColliderGroup> {
+        self.find_properties(graph, COLLIDER_GROUP_EDGE)
+    }
+    pub fn add_collider_group(&self, graph: &mut Graph, group: ColliderGroup) {
+        self.add_property(graph, COLLIDER_GROUP_EDGE.to_string(), group);
+    }
+    pub fn remove_collider_group(&self, graph: &mut Graph, group: ColliderGroup) {
+        self.remove_property(graph, COLLIDER_GROUP_EDGE, group);
+    }
+    pub fn create_collider_group(
+        &self,
+        graph: &mut Graph,
+        weight: &ColliderGroupWeight,
+    ) -> ColliderGroup {
+        let group = ColliderGroup::new(graph, weight);
+        self.add_collider_group(graph, group);
+        group
+    }
+
+    pub fn springs(&self, graph: &Graph) -> Vec<Spring> {
+        self.find_properties(graph, SPRING_EDGE)
+    }
+    pub fn add_spring(&self, graph: &mut Graph, spring: Spring) {
+        self.add_property(graph, SPRING_EDGE.to_string(), spring);
+    }
+    pub fn remove_spring(&self, graph: &mut Graph, spring: Spring) {
+        self.remove_property(graph, SPRING_EDGE, spring);
+    }
+    pub fn create_spring(&self, graph: &mut Graph, weight: &SpringWeight) -> Spring {
+        let spring = Spring::new(graph, weight);
+        self.add_spring(graph, spring);
+        spring
+    }
+}
@@ -0,0 +1,185 @@
+use petgraph::graph::NodeIndex;
+use serde::{Deserialize, Serialize};
+
+use crate::graph::{ByteNode, Graph, OtherEdgeHelpers, Weight, gltf::node::Node};
+
+use super::collider::ColliderGroup;
+
+pub const CENTER_EDGE: &str = "VRMC_springBone/spring/center";
+pub const JOINT_EDGE: &str = "VRMC_springBone/spring/joint";
+pub const JOINT_NODE_EDGE: &str = "VRMC_springBone/joint/node";
+pub const SPRING_COLLIDER_GROUP_EDGE: &str = "VRMC_springBone/spring/collider_group";
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SpringWeight {
+    pub name: Option<String>,
+}
+
+impl From<&Vec<u8>> for SpringWeight {
+    fn from(bytes: &Vec<u8>) -> Self {
+        if bytes.is_empty() {
+            return Self::default();
+        }
+        serde_json::from_slice(bytes).expect("Failed to deserialize weight")
+    }
+}
+
+impl From<&SpringWeight> for Vec<u8> {
+    fn from(value: &SpringWeight) -> Self {
+        serde_json::to_vec(value).expect("Failed to serialize weight")
+    }
+}
+
+/// A chain of joints, from root to tail.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Spring(pub NodeIndex);
+
+impl From<NodeIndex> for Spring {
+    fn from(index: NodeIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl From<Spring> for NodeIndex {
+    fn from(spring: Spring) -> Self {
+        spring.0
+    }
+}
+
+impl ByteNode<SpringWeight> for Spring {}
+impl OtherEdgeHelpers for Spring {}
+
+impl Spring {
+    pub fn new(graph: &mut Graph, weight: &SpringWeight) -> Self {
+        let index = graph.add_node(Weight::Bytes(weight.into()));
+        Self(index)
+    }
+
+    /// Joints of the chain, in creation order.
+    pub fn joints(&self, graph: &Graph) -> Vec<SpringJoint> {
+        self.find_properties(graph, JOINT_EDGE)
+    }
+    pub fn add_joint(&self, graph: &mut Graph, joint: SpringJoint) {
+        self.add_property(graph, JOINT_EDGE.to_string(), joint);
+    }
+    pub fn remove_joint(&self, graph: &mut Graph, joint: SpringJoint) {
+        self.remove_property(graph, JOINT_EDGE, joint);
+    }
+    pub fn create_joint(
+        &self,
+        graph: &mut Graph,
+        node: Node,
+        weight: &SpringJointWeight,
+    ) -> SpringJoint {
+        let joint = SpringJoint::new(graph, weight);
+        joint.set_node(graph, Some(node));
+        self.add_joint(graph, joint);
+        joint
+    }
+
+    pub fn collider_groups(&self, graph: &Graph) -> Vec<ColliderGroup> {
+        self.find_properties(graph, SPRING_COLLIDER_GROUP_EDGE)
+    }
+    pub fn add_collider_group(&self, graph: &mut Graph, group: ColliderGroup) {
+        self.add_property(graph, SPRING_COLLIDER_GROUP_EDGE.to_string(), group);
+    }
+    pub fn remove_collider_group(&self, graph: &mut Graph, group: ColliderGroup) {
+        self.remove_property(graph, SPRING_COLLIDER_GROUP_EDGE, group);
+    }
+
+    /// Node whose space the simulation runs in.
+    pub fn center(&self, graph: &Graph) -> Option<Node> {
+        self.find_property(graph, CENTER_EDGE)
+    }
+    pub fn set_center(&self, graph: &mut Graph, node: Option<Node>) {
+        self.set_property(graph, CENTER_EDGE.to_string(), node);
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpringJointWeight {
+    #[serde(default)]
+    pub hit_radius: f32,
+    /// Force pulling the joint back to its rest pose.
+    #[serde(default = "default_stiffness")]
+    pub stiffness: f32,
+    #[serde(default)]
+    pub gravity_power: f32,
+    #[serde(default = "default_gravity_dir")]
+    pub gravity_dir: [f32; 3],
+    /// Velocity damping, from 0 to 1.
+    #[serde(default = "default_drag_force")]
+    pub drag_force: f32,
+}
+
+const fn default_stiffness() -> f32 {
+    1.0
+}
+
+const fn default_gravity_dir() -> [f32; 3] {
+    [0.0, -1.0, 0.0]
+}
+
+const fn default_drag_force() -> f32 {
+    0.5
+}
+
+impl Default for SpringJointWeight {
+    fn default() -> Self {
+        Self {
+            hit_radius: 0.0,
+            stiffness: default_stiffness(),
+            gravity_power: 0.0,
+            gravity_dir: default_gravity_dir(),
+            drag_force: default_drag_force(),
+        }
+    }
+}
+
+impl From<&Vec<u8>> for SpringJointWeight {
+    fn from(bytes: &Vec<u8>) -> Self {
+        if bytes.is_empty() {
+            return Self::default();
+        }
+        serde_json::from_slice(bytes).expect("Failed to deserialize weight")
+    }
+}
+
+impl From<&SpringJointWeight> for Vec<u8> {
+    fn from(value: &SpringJointWeight) -> Self {
+        serde_json::to_vec(value).expect("Failed to serialize weight")
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SpringJoint(pub NodeIndex);
+
+impl From<NodeIndex> for SpringJoint {
+    fn from(index: NodeIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl From<SpringJoint> for NodeIndex {
+    fn from(joint: SpringJoint) -> Self {
+        joint.0
+    }
+}
+
+impl ByteNode<SpringJointWeight> for SpringJoint {}
+impl OtherEdgeHelpers for SpringJoint {}
+
+impl SpringJoint {
+    pub fn new(graph: &mut Graph, weight: &SpringJointWeight) -> Self {
+        let index = graph.add_node(Weight::Bytes(weight.into()));
+        Self(index)
+    }
+
+    pub fn node(&self, graph: &Graph) -> Option<Node> {
+        self.find_property(graph, JOINT_NODE_EDGE)
+    }
+    pub fn set_node(&self, graph: &mut Graph, node: Option<Node>) {
+        self.set_property(graph, JOINT_NODE_EDGE.to_string(), node);
+    }
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+pub const SPEC_VERSION: &str = "1.0";
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct VrmcSpringBoneWeight {
+    pub spec_version: String,
+}
+
+impl Default for VrmcSpringBoneWeight {
+    fn default() -> Self {
+        Self {
+            spec_version: SPEC_VERSION.to_string(),
+        }
+    }
+}
+
+impl From<&Vec<u8>> for VrmcSpringBoneWeight {
+    fn from(bytes: &Vec<u8>) -> Self {
+        if bytes.is_empty() {
+            return Self::default();
+        }
+        serde_json::from_slice(bytes).expect("Failed to deserialize weight")
+    }
+}
+
+impl From<&VrmcSpringBoneWeight> for Vec<u8> {
+    fn from(value: &VrmcSpringBoneWeight) -> Self {
+        serde_json::to_vec(value).expect("Failed to serialize weight")
+    }
+}
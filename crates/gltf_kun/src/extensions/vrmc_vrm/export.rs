@@ -0,0 +1,114 @@
+use std::error::Error;
+
+use crate::{
+    extensions::ExtensionExport,
+    graph::{
+        ByteNode, Extensions, Graph,
+        gltf::{document::GltfDocument, node::Node},
+    },
+    io::format::gltf::GltfFormat,
+};
+
+use super::{
+    EXTENSION_NAME, VrmcVrm,
+    expression::Expression,
+    json::{
+        ExpressionJson, ExpressionsJson, HumanBoneJson, HumanoidJson, MetaJson,
+        MorphTargetBindJson, RootExtension,
+    },
+};
+
+impl ExtensionExport<GltfDocument, GltfFormat> for VrmcVrm {
+    fn export(
+        graph: &mut Graph,
+        doc: &GltfDocument,
+        format: &mut GltfFormat,
+    ) -> Result<(), Box<dyn Error>> {
+        let Some(ext) = doc.get_extension::<Self>(graph) else {
+            return Ok(());
+        };
+
+        let weight = ext.read(graph);
+        let nodes = doc.nodes(graph);
+
+        let thumbnail_image = ext
+            .thumbnail(graph)
+            .and_then(|image| doc.images(graph).iter().position(|x| *x == image));
+
+        let human_bones = ext
+            .humanoid_bones(graph)
+            .into_iter()
+            .filter_map(|b| {
+                let node = b.node(graph)?;
+                let node = nodes.iter().position(|x| *x == node)?;
+                Some((b.read(graph).bone, HumanBoneJson { node }))
+            })
+            .collect();
+
+        let mut expressions = ExpressionsJson::default();
+
+        for expression in ext.expressions(graph) {
+            let expression_weight = expression.read(graph);
+            let json = export_expression(graph, &nodes, expression);
+
+            if expression_weight.preset {
+                expressions.preset.insert(expression_weight.name, json);
+            } else {
+                expressions.custom.insert(expression_weight.name, json);
+            }
+        }
+
+        let root = RootExtension {
+            spec_version: weight.spec_version,
+            meta: MetaJson {
+                meta: weight.meta,
+                thumbnail_image,
+            },
+            humanoid: HumanoidJson { human_bones },
+            expressions,
+            look_at: weight.look_at,
+        };
+
+        let extensions = format
+            .json
+            .extensions
+            .get_or_insert_with(gltf::json::extensions::Root::default);
+
+        extensions
+            .others
+            .insert(EXTENSION_NAME.to_string(), serde_json::to_value(root)?);
+
+        format.json.extensions_used.push(EXTENSION_NAME.to_string());
+
+        Ok(())
+    }
+}
+
+fn export_expression(graph: &Graph, nodes: &[Node], expression: Expression) -> ExpressionJson {
+    let weight = expression.read(graph);
+
+    let morph_target_binds = expression
+        .morph_target_binds(graph)
+        .into_iter()
+        .filter_map(|bind| {
+            let node = bind.node(graph)?;
+            let node = nodes.iter().position(|x| *x == node)?;
+            let bind_weight = bind.read(graph);
+
+            Some(MorphTargetBindJson {
+                node,
+                index: bind_weight.index,
+                weight: bind_weight.weight,
+            })
+        })
+        .collect();
+
+    ExpressionJson {
+        morph_target_binds,
+        is_binary: weight.is_binary,
+        override_blink: weight.override_blink,
+        override_look_at: weight.override_look_at,
+        override_mouth: weight.override_mouth,
+        ..Default::default()
+    }
+}
@@ -0,0 +1,168 @@
+use petgraph::graph::NodeIndex;
+use serde::{Deserialize, Serialize};
+
+use crate::graph::{ByteNode, Graph, OtherEdgeHelpers, Weight, gltf::node::Node};
+
+pub const BIND_EDGE: &str = "VRMC_vrm/expression/morph_target_bind";
+pub const BIND_NODE_EDGE: &str = "VRMC_vrm/morph_target_bind/node";
+
+/// Names of the preset expressions defined by the specification.
+pub const PRESETS: [&str; 18] = [
+    "happy",
+    "angry",
+    "sad",
+    "relaxed",
+    "surprised",
+    "aa",
+    "ih",
+    "ou",
+    "ee",
+    "oh",
+    "blink",
+    "blinkLeft",
+    "blinkRight",
+    "lookUp",
+    "lookDown",
+    "lookLeft",
+    "lookRight",
+    "neutral",
+];
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ExpressionWeight {
+    pub name: String,
+    /// Whether this is one of the [`PRESETS`], rather than a custom expression.
+    pub preset: bool,
+    /// Whether the weight should be rounded to 0 or 1.
+    pub is_binary: bool,
+    pub override_blink: ExpressionOverride,
+    pub override_look_at: ExpressionOverride,
+    pub override_mouth: ExpressionOverride,
+}
+
+impl From<&Vec<u8>> for ExpressionWeight {
+    fn from(bytes: &Vec<u8>) -> Self {
+        if bytes.is_empty() {
+            return Self::default();
+        }
+        serde_json::from_slice(bytes).expect("Failed to deserialize weight")
+    }
+}
+
+impl From<&ExpressionWeight> for Vec<u8> {
+    fn from(value: &ExpressionWeight) -> Self {
+        serde_json::to_vec(value).expect("Failed to serialize weight")
+    }
+}
+
+/// How an active expression affects the blink, look-at or mouth expressions.
+#[derive(Copy, Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExpressionOverride {
+    #[default]
+    None,
+    Block,
+    Blend,
+}
+
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Expression(pub NodeIndex);
+
+impl From<NodeIndex> for Expression {
+    fn from(index: NodeIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl From<Expression> for NodeIndex {
+    fn from(expression: Expression) -> Self {
+        expression.0
+    }
+}
+
+impl ByteNode<ExpressionWeight> for Expression {}
+impl OtherEdgeHelpers for Expression {}
+
+impl Expression {
+    pub fn new(graph: &mut Graph, weight: &ExpressionWeight) -> Self {
+        let index = graph.add_node(Weight::Bytes(weight.into()));
+        Self(index)
+    }
+
+    pub fn morph_target_binds(&self, graph: &Graph) -> Vec<MorphTargetBind> {
+        self.find_properties(graph, BIND_EDGE)
+    }
+    pub fn add_morph_target_bind(&self, graph: &mut Graph, bind: MorphTargetBind) {
+        self.add_property(graph, BIND_EDGE.to_string(), bind);
+    }
+    pub fn remove_morph_target_bind(&self, graph: &mut Graph, bind: MorphTargetBind) {
+        self.remove_property(graph, BIND_EDGE, bind);
+    }
+    pub fn create_morph_target_bind(
+        &self,
+        graph: &mut Graph,
+        node: Node,
+        weight: &MorphTargetBindWeight,
+    ) -> MorphTargetBind {
+        let bind = MorphTargetBind::new(graph, weight);
+        bind.set_node(graph, Some(node));
+        self.add_morph_target_bind(graph, bind);
+        bind
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct MorphTargetBindWeight {
+    /// Index of the morph target within the node's mesh.
+    pub index: usize,
+    /// Morph target weight when the expression is fully applied.
+    pub weight: f32,
+}
+
+impl From<&Vec<u8>> for MorphTargetBindWeight {
+    fn from(bytes: &Vec<u8>) -> Self {
+        if bytes.is_empty() {
+            return Self::default();
+        }
+        serde_json::from_slice(bytes).expect("Failed to deserialize weight")
+    }
+}
+
+impl From<&MorphTargetBindWeight> for Vec<u8> {
+    fn from(value: &MorphTargetBindWeight) -> Self {
+        serde_json::to_vec(value).expect("Failed to serialize weight")
+    }
+}
+
+/// Binds an expression to a morph target of a node's mesh.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct MorphTargetBind(pub NodeIndex);
+
+impl From<NodeIndex> for MorphTargetBind {
+    fn from(index: NodeIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl From<MorphTargetBind> for NodeIndex {
+    fn from(bind: MorphTargetBind) -> Self {
+        bind.0
+    }
+}
+
+impl ByteNode<MorphTargetBindWeight> for MorphTargetBind {}
+impl OtherEdgeHelpers for MorphTargetBind {}
+
+impl MorphTargetBind {
+    pub fn new(graph: &mut Graph, weight: &MorphTargetBindWeight) -> Self {
+        let index = graph.add_node(Weight::Bytes(weight.into()));
+        Self(index)
+    }
+
+    pub fn node(&self, graph: &Graph) -> Option<Node> {
+        self.find_property(graph, BIND_NODE_EDGE)
+    }
+    pub fn set_node(&self, graph: &mut Graph, node: Option<Node>) {
+        self.set_property(graph, BIND_NODE_EDGE.to_string(), node);
+    }
+}
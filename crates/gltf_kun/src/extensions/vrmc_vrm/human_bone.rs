@@ -0,0 +1,225 @@
+use petgraph::graph::NodeIndex;
+use serde::{Deserialize, Serialize};
+
+use crate::graph::{ByteNode, Graph, OtherEdgeHelpers, Weight, gltf::node::Node};
+
+pub const NODE_EDGE: &str = "VRMC_vrm/humanoid_bone/node";
+
+/// A bone of the VRM humanoid skeleton.
+#[derive(
+    Copy, Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
+)]
+#[cfg_attr(feature = "bevy", derive(bevy::prelude::Reflect))]
+#[serde(rename_all = "camelCase")]
+pub enum HumanBone {
+    #[default]
+    Hips,
+    Spine,
+    Chest,
+    UpperChest,
+    Neck,
+    Head,
+    LeftEye,
+    RightEye,
+    Jaw,
+    LeftUpperLeg,
+    LeftLowerLeg,
+    LeftFoot,
+    LeftToes,
+    RightUpperLeg,
+    RightLowerLeg,
+    RightFoot,
+    RightToes,
+    LeftShoulder,
+    LeftUpperArm,
+    LeftLowerArm,
+    LeftHand,
+    RightShoulder,
+    RightUpperArm,
+    RightLowerArm,
+    RightHand,
+    LeftThumbMetacarpal,
+    LeftThumbProximal,
+    LeftThumbDistal,
+    LeftIndexProximal,
+    LeftIndexIntermediate,
+    LeftIndexDistal,
+    LeftMiddleProximal,
+    LeftMiddleIntermediate,
+    LeftMiddleDistal,
+    LeftRingProximal,
+    LeftRingIntermediate,
+    LeftRingDistal,
+    LeftLittleProximal,
+    LeftLittleIntermediate,
+    LeftLittleDistal,
+    RightThumbMetacarpal,
+    RightThumbProximal,
+    RightThumbDistal,
+    RightIndexProximal,
+    RightIndexIntermediate,
+    RightIndexDistal,
+    RightMiddleProximal,
+    RightMiddleIntermediate,
+    RightMiddleDistal,
+    RightRingProximal,
+    RightRingIntermediate,
+    RightRingDistal,
+    RightLittleProximal,
+    RightLittleIntermediate,
+    RightLittleDistal,
+}
+
+impl HumanBone {
+    pub const ALL: [Self; 55] = [
+        Self::Hips,
+        Self::Spine,
+        Self::Chest,
+        Self::UpperChest,
+        Self::Neck,
+        Self::Head,
+        Self::LeftEye,
+        Self::RightEye,
+        Self::Jaw,
+        Self::LeftUpperLeg,
+        Self::LeftLowerLeg,
+        Self::LeftFoot,
+        Self::LeftToes,
+        Self::RightUpperLeg,
+        Self::RightLowerLeg,
+        Self::RightFoot,
+        Self::RightToes,
+        Self::LeftShoulder,
+        Self::LeftUpperArm,
+        Self::LeftLowerArm,
+        Self::LeftHand,
+        Self::RightShoulder,
+        Self::RightUpperArm,
+        Self::RightLowerArm,
+        Self::RightHand,
+        Self::LeftThumbMetacarpal,
+        Self::LeftThumbProximal,
+        Self::LeftThumbDistal,
+        Self::LeftIndexProximal,
+        Self::LeftIndexIntermediate,
+        Self::LeftIndexDistal,
+        Self::LeftMiddleProximal,
+        Self::LeftMiddleIntermediate,
+        Self::LeftMiddleDistal,
+        Self::LeftRingProximal,
+        Self::LeftRingIntermediate,
+        Self::LeftRingDistal,
+        Self::LeftLittleProximal,
+        Self::LeftLittleIntermediate,
+        Self::LeftLittleDistal,
+        Self::RightThumbMetacarpal,
+        Self::RightThumbProximal,
+        Self::RightThumbDistal,
+        Self::RightIndexProximal,
+        Self::RightIndexIntermediate,
+        Self::RightIndexDistal,
+        Self::RightMiddleProximal,
+        Self::RightMiddleIntermediate,
+        Self::RightMiddleDistal,
+        Self::RightRingProximal,
+        Self::RightRingIntermediate,
+        Self::RightRingDistal,
+        Self::RightLittleProximal,
+        Self::RightLittleIntermediate,
+        Self::RightLittleDistal,
+    ];
+
+    /// Whether the bone must be present in a valid humanoid.
+    pub const fn is_required(self) -> bool {
+        matches!(
+            self,
+            Self::Hips
+                | Self::Spine
+                | Self::Head
+                | Self::LeftUpperLeg
+                | Self::LeftLowerLeg
+                | Self::LeftFoot
+                | Self::RightUpperLeg
+                | Self::RightLowerLeg
+                | Self::RightFoot
+                | Self::LeftUpperArm
+                | Self::LeftLowerArm
+                | Self::LeftHand
+                | Self::RightUpperArm
+                | Self::RightLowerArm
+                | Self::RightHand
+        )
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct HumanoidBoneWeight {
+    pub bone: HumanBone,
+}
+
+impl From<&Vec<u8>> for HumanoidBoneWeight {
+    fn from(bytes: &Vec<u8>) -> Self {
+        if bytes.is_empty() {
+            return Self::default();
+        }
+        serde_json::from_slice(bytes).expect("Failed to deserialize weight")
+    }
+}
+
+impl From<&HumanoidBoneWeight> for Vec<u8> {
+    fn from(value: &HumanoidBoneWeight) -> Self {
+        serde_json::to_vec(value).expect("Failed to serialize weight")
+    }
+}
+
+/// Maps a [`HumanBone`] to a node.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct HumanoidBone(pub NodeIndex);
+
+impl From<NodeIndex> for HumanoidBone {
+    fn from(index: NodeIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl From<HumanoidBone> for NodeIndex {
+    fn from(humanoid_bone: HumanoidBone) -> Self {
+        humanoid_bone.0
+    }
+}
+
+impl ByteNode<HumanoidBoneWeight> for HumanoidBone {}
+impl OtherEdgeHelpers for HumanoidBone {}
+
+impl HumanoidBone {
+    pub fn new(graph: &mut Graph, bone: HumanBone) -> Self {
+        let weight = HumanoidBoneWeight { bone };
+        let index = graph.add_node(Weight::Bytes((&weight).into()));
+        Self(index)
+    }
+
+    pub fn node(&self, graph: &Graph) -> Option<Node> {
+        self.find_property(graph, NODE_EDGE)
+    }
+    pub fn set_node(&self, graph: &mut Graph, node: Option<Node>) {
+        self.set_property(graph, NODE_EDGE.to_string(), node);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn human_bone_serde() {
+        let json =
+            serde_json::to_string(&HumanBone::LeftThumbMetacarpal).expect("json should serialize");
+        assert_eq!(json, r#""leftThumbMetacarpal""#);
+
+        for bone in HumanBone::ALL {
+            let json = serde_json::to_string(&bone).expect("json should serialize");
+            let bone_2 = serde_json::from_str::<HumanBone>(&json).expect("json should deserialize");
+            assert_eq!(bone, bone_2);
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use std::error::Error;
+
+use tracing::warn;
+
+use crate::{
+    extensions::ExtensionImport,
+    graph::{
+        ByteNode, Extensions, Graph,
+        gltf::{document::GltfDocument, node::Node},
+    },
+    io::format::gltf::GltfFormat,
+};
+
+use super::{
+    EXTENSION_NAME, VrmcVrm,
+    expression::{ExpressionWeight, MorphTargetBindWeight},
+    json::{ExpressionJson, RootExtension},
+    weight::VrmcVrmWeight,
+};
+
+impl ExtensionImport<GltfDocument, GltfFormat> for VrmcVrm {
+    fn supported_extensions() -> Vec<&'static str> {
+        vec![EXTENSION_NAME]
+    }
+
+    fn import(
+        graph: &mut Graph,
+        format: &mut GltfFormat,
+        doc: &GltfDocument,
+    ) -> Result<(), Box<dyn Error>> {
+        let Some(value) = format
+            .json
+            .extensions
+            .as_ref()
+            .and_then(|e| e.others.get(EXTENSION_NAME))
+        else {
+            return Ok(());
+        };
+
+        let root = serde_json::from_value::<RootExtension>(value.clone())?;
+
+        let ext = doc
+            .get_extension::<Self>(graph)
+            .unwrap_or_else(|| doc.create_extension::<Self>(graph));
+
+        ext.write(
+            graph,
+            &VrmcVrmWeight {
+                spec_version: root.spec_version,
+                meta: root.meta.meta,
+                look_at: root.look_at,
+            },
+        );
+
+        let thumbnail = root
+            .meta
+            .thumbnail_image
+            .and_then(|i| doc.images(graph).get(i).copied());
+        ext.set_thumbnail(graph, thumbnail);
+
+        let nodes = doc.nodes(graph);
+
+        for (bone, json) in root.humanoid.human_bones {
+            let Some(node) = nodes.get(json.node) else {
+                warn!(
+                    "Human bone {:?} node index {} out of bounds",
+                    bone, json.node
+                );
+                continue;
+            };
+
+            ext.set_human_bone(graph, bone, Some(*node));
+        }
+
+        let expressions = root
+            .expressions
+            .preset
+            .into_iter()
+            .map(|(name, json)| (name, true, json))
+            .chain(
+                root.expressions
+                    .custom
+                    .into_iter()
+                    .map(|(name, json)| (name, false, json)),
+            );
+
+        for (name, preset, json) in expressions {
+            import_expression(graph, &nodes, ext, name, preset, json);
+        }
+
+        Ok(())
+    }
+}
+
+fn import_expression(
+    graph: &mut Graph,
+    nodes: &[Node],
+    ext: VrmcVrm,
+    name: String,
+    preset: bool,
+    json: ExpressionJson,
+) {
+    if !json.material_color_binds.is_empty() || !json.texture_transform_binds.is_empty() {
+        warn!(
+            "Expression {} material binds are not supported, and will be ignored",
+            name
+        );
+    }
+
+    let expression = ext.create_expression(
+        graph,
+        &ExpressionWeight {
+            name,
+            preset,
+            is_binary: json.is_binary,
+            override_blink: json.override_blink,
+            override_look_at: json.override_look_at,
+            override_mouth: json.override_mouth,
+        },
+    );
+
+    for bind in json.morph_target_binds {
+        let Some(node) = nodes.get(bind.node) else {
+            warn!("Morph target bind node index {} out of bounds", bind.node);
+            continue;
+        };
+
+        expression.create_morph_target_bind(
+            graph,
+            *node,
+            &MorphTargetBindWeight {
+                index: bind.index,
+                weight: bind.weight,
+            },
+        );
+    }
+}
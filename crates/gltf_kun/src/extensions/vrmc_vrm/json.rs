@@ -0,0 +1,130 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    expression::ExpressionOverride,
+    human_bone::HumanBone,
+    weight::{LookAt, Meta},
+};
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RootExtension {
+    pub spec_version: String,
+    pub meta: MetaJson,
+    pub humanoid: HumanoidJson,
+    #[serde(default, skip_serializing_if = "ExpressionsJson::is_empty")]
+    pub expressions: ExpressionsJson,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub look_at: Option<LookAt>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetaJson {
+    #[serde(flatten)]
+    pub meta: Meta,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thumbnail_image: Option<usize>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HumanoidJson {
+    pub human_bones: BTreeMap<HumanBone, HumanBoneJson>,
+}
+
+#[derive(Copy, Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct HumanBoneJson {
+    pub node: usize,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct ExpressionsJson {
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub preset: BTreeMap<String, ExpressionJson>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub custom: BTreeMap<String, ExpressionJson>,
+}
+
+impl ExpressionsJson {
+    pub fn is_empty(&self) -> bool {
+        self.preset.is_empty() && self.custom.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpressionJson {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub morph_target_binds: Vec<MorphTargetBindJson>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub material_color_binds: Vec<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub texture_transform_binds: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub is_binary: bool,
+    #[serde(default)]
+    pub override_blink: ExpressionOverride,
+    #[serde(default)]
+    pub override_look_at: ExpressionOverride,
+    #[serde(default)]
+    pub override_mouth: ExpressionOverride,
+}
+
+#[derive(Copy, Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct MorphTargetBindJson {
+    pub node: usize,
+    pub index: usize,
+    pub weight: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn root_serde() {
+        let json = r#"{
+            "specVersion": "1.0",
+            "meta": {
+                "name": "Avatar",
+                "authors": ["Alice"],
+                "licenseUrl": "https://vrm.dev/licenses/1.0/",
+                "thumbnailImage": 0
+            },
+            "humanoid": {
+                "humanBones": {
+                    "hips": { "node": 1 },
+                    "head": { "node": 2 }
+                }
+            },
+            "expressions": {
+                "preset": {
+                    "blink": {
+                        "morphTargetBinds": [{ "node": 3, "index": 0, "weight": 1.0 }],
+                        "isBinary": true,
+                        "overrideMouth": "block"
+                    }
+                }
+            }
+        }"#;
+
+        let root = serde_json::from_str::<RootExtension>(json).expect("json should deserialize");
+
+        assert_eq!(root.meta.meta.name, "Avatar");
+        assert_eq!(root.meta.thumbnail_image, Some(0));
+        assert_eq!(root.humanoid.human_bones[&HumanBone::Head].node, 2);
+
+        let blink = &root.expressions.preset["blink"];
+        assert!(blink.is_binary);
+        assert_eq!(blink.override_mouth, ExpressionOverride::Block);
+        assert_eq!(blink.morph_target_binds[0].node, 3);
+
+        let json_2 = serde_json::to_string(&root).expect("json should serialize");
+        let root_2 =
+            serde_json::from_str::<RootExtension>(&json_2).expect("json should deserialize");
+        assert_eq!(root, root_2);
+    }
+}
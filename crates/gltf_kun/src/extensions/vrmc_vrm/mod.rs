@@ -0,0 +1,116 @@
+//! [VRMC_vrm](https://github.com/vrm-c/vrm-specification/tree/master/specification/VRMC_vrm-1.0)
+//! extension.
+//!
+//! Supports the avatar meta, humanoid bone mapping, expressions (morph target binds) and look-at.
+//! First-person annotations, material color binds and texture transform binds are not preserved.
+
+use petgraph::graph::NodeIndex;
+
+use crate::graph::{
+    ByteNode, Graph, OtherEdgeHelpers,
+    gltf::{image::Image, node::Node},
+};
+
+use self::{
+    expression::{Expression, ExpressionWeight},
+    human_bone::{HumanBone, HumanoidBone},
+    weight::VrmcVrmWeight,
+};
+
+use super::Extension;
+
+pub mod export;
+pub mod expression;
+pub mod human_bone;
+pub mod import;
+pub mod json;
+pub mod weight;
+
+pub const EXTENSION_NAME: &str = "VRMC_vrm";
+
+pub const EXPRESSION_EDGE: &str = "VRMC_vrm/expression";
+pub const HUMANOID_BONE_EDGE: &str = "VRMC_vrm/humanoid_bone";
+pub const THUMBNAIL_EDGE: &str = "VRMC_vrm/thumbnail";
+
+/// Document-level VRM avatar data.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct VrmcVrm(pub NodeIndex);
+
+impl From<NodeIndex> for VrmcVrm {
+    fn from(index: NodeIndex) -> Self {
+        Self(index)
+    }
+}
+
+impl From<VrmcVrm> for NodeIndex {
+    fn from(vrm: VrmcVrm) -> Self {
+        vrm.0
+    }
+}
+
+impl ByteNode<VrmcVrmWeight> for VrmcVrm {}
+impl OtherEdgeHelpers for VrmcVrm {}
+
+impl Extension for VrmcVrm {
+    fn name() -> &'static str {
+        EXTENSION_NAME
+    }
+}
+
+impl VrmcVrm {
+    pub fn thumbnail(&self, graph: &Graph) -> Option<Image> {
+        self.find_property(graph, THUMBNAIL_EDGE)
+    }
+    pub fn set_thumbnail(&self, graph: &mut Graph, image: Option<Image>) {
+        self.set_property(graph, THUMBNAIL_EDGE.to_string(), image);
+    }
+
+    pub fn humanoid_bones(&self, graph: &Graph) -> Vec<HumanoidBone> {
+        self.find_properties(graph, HUMANOID_BONE_EDGE)
+    }
+    pub fn add_humanoid_bone(&self, graph: &mut Graph, bone: HumanoidBone) {
+        self.add_property(graph, HUMANOID_BONE_EDGE.to_string(), bone);
+    }
+    pub fn remove_humanoid_bone(&self, graph: &mut Graph, bone: HumanoidBone) {
+        self.remove_property(graph, HUMANOID_BONE_EDGE, bone);
+    }
+
+    /// Maps a human bone to a node, replacing any existing mapping for that bone.
+    pub fn set_human_bone(&self, graph: &mut Graph, bone: HumanBone, node: Option<Node>) {
+        if let Some(existing) = self
+            .humanoid_bones(graph)
+            .into_iter()
+            .find(|b| b.read(graph).bone == bone)
+        {
+            self.remove_humanoid_bone(graph, existing);
+        }
+
+        if let Some(node) = node {
+            let humanoid_bone = HumanoidBone::new(graph, bone);
+            humanoid_bone.set_node(graph, Some(node));
+            self.add_humanoid_bone(graph, humanoid_bone);
+        }
+    }
+    /// Returns the node mapped to a human bone.
+    pub fn human_bone(&self, graph: &Graph, bone: HumanBone) -> Option<Node> {
+        self.humanoid_bones(graph)
+            .into_iter()
+            .find(|b| b.read(graph).bone == bone)
+            .and_then(|b| b.node(graph))
+    }
+
+    pub fn expressions(&self, graph: &Graph) -> Vec<Expression> {
+        self.find_properties(graph, EXPRESSION_EDGE)
+    }
+    pub fn add_expression(&self, graph: &mut Graph, expression: Expression) {
+        self.add_property(graph, EXPRESSION_EDGE.to_string(), expression);
+    }
+    pub fn remove_expression(&self, graph: &mut Graph, expression: Expression) {
+        self.remove_property(graph, EXPRESSION_EDGE, expression);
+    }
+    pub fn create_expression(&self, graph: &mut Graph, weight: &ExpressionWeight) -> Expression {
+        let expression = Expression::new(graph, weight);
+        self.add_expression(graph, expression);
+        expression
+    }
+}
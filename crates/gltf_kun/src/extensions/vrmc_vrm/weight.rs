@@ -0,0 +1,188 @@
+use serde::{Deserialize, Serialize};
+
+pub const SPEC_VERSION: &str = "1.0";
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct VrmcVrmWeight {
+    pub spec_version: String,
+    pub meta: Meta,
+    pub look_at: Option<LookAt>,
+}
+
+impl Default for VrmcVrmWeight {
+    fn default() -> Self {
+        Self {
+            spec_version: SPEC_VERSION.to_string(),
+            meta: Meta::default(),
+            look_at: None,
+        }
+    }
+}
+
+impl From<&Vec<u8>> for VrmcVrmWeight {
+    fn from(bytes: &Vec<u8>) -> Self {
+        if bytes.is_empty() {
+            return Self::default();
+        }
+        serde_json::from_slice(bytes).expect("Failed to deserialize weight")
+    }
+}
+
+impl From<&VrmcVrmWeight> for Vec<u8> {
+    fn from(value: &VrmcVrmWeight) -> Self {
+        serde_json::to_vec(value).expect("Failed to serialize weight")
+    }
+}
+
+/// Avatar information and license.
+/// The thumbnail image is stored as an edge, see [`VrmcVrm::thumbnail`](super::VrmcVrm::thumbnail).
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Meta {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    pub authors: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub copyright_information: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contact_information: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub references: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub third_party_licenses: Option<String>,
+    pub license_url: String,
+    #[serde(default)]
+    pub avatar_permission: AvatarPermission,
+    #[serde(default)]
+    pub allow_excessively_violent_usage: bool,
+    #[serde(default)]
+    pub allow_excessively_sexual_usage: bool,
+    #[serde(default)]
+    pub commercial_usage: CommercialUsage,
+    #[serde(default)]
+    pub allow_political_or_religious_usage: bool,
+    #[serde(default)]
+    pub allow_antisocial_or_hate_usage: bool,
+    #[serde(default)]
+    pub credit_notation: CreditNotation,
+    #[serde(default)]
+    pub allow_redistribution: bool,
+    #[serde(default)]
+    pub modification: Modification,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub other_license_url: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AvatarPermission {
+    #[default]
+    OnlyAuthor,
+    OnlySeparatelyLicensedPerson,
+    Everyone,
+}
+
+#[derive(Copy, Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CommercialUsage {
+    #[default]
+    PersonalNonProfit,
+    PersonalProfit,
+    Corporation,
+}
+
+#[derive(Copy, Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CreditNotation {
+    #[default]
+    Required,
+    Unnecessary,
+}
+
+#[derive(Copy, Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Modification {
+    #[default]
+    Prohibited,
+    AllowModification,
+    AllowModificationRedistribution,
+}
+
+/// Eye gaze control.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LookAt {
+    /// Position of the eyes, relative to the head bone.
+    #[serde(default)]
+    pub offset_from_head_bone: [f32; 3],
+    #[serde(rename = "type")]
+    pub typ: LookAtType,
+    #[serde(default)]
+    pub range_map_horizontal_inner: RangeMap,
+    #[serde(default)]
+    pub range_map_horizontal_outer: RangeMap,
+    #[serde(default)]
+    pub range_map_vertical_down: RangeMap,
+    #[serde(default)]
+    pub range_map_vertical_up: RangeMap,
+}
+
+#[derive(Copy, Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LookAtType {
+    /// Rotate the eye bones.
+    #[default]
+    Bone,
+    /// Drive the `lookUp`, `lookDown`, `lookLeft` and `lookRight` expressions.
+    Expression,
+}
+
+/// Maps a gaze angle, in degrees, to a bone rotation or expression weight.
+#[derive(Copy, Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RangeMap {
+    pub input_max_value: f32,
+    pub output_scale: f32,
+}
+
+impl Default for RangeMap {
+    fn default() -> Self {
+        Self {
+            input_max_value: 90.0,
+            output_scale: 10.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn meta_serde() {
+        let json = r#"{"name":"Avatar","authors":["Alice"],"licenseUrl":"https://vrm.dev/licenses/1.0/","commercialUsage":"personalProfit"}"#;
+        let meta = serde_json::from_str::<Meta>(json).expect("json should deserialize");
+
+        assert_eq!(meta.name, "Avatar");
+        assert_eq!(meta.authors, vec!["Alice".to_string()]);
+        assert_eq!(meta.avatar_permission, AvatarPermission::OnlyAuthor);
+        assert_eq!(meta.commercial_usage, CommercialUsage::PersonalProfit);
+        assert_eq!(meta.modification, Modification::Prohibited);
+
+        let json_2 = serde_json::to_string(&meta).expect("json should serialize");
+        let meta_2 = serde_json::from_str::<Meta>(&json_2).expect("json should deserialize");
+        assert_eq!(meta, meta_2);
+    }
+
+    #[test]
+    fn look_at_serde() {
+        let json = r#"{"offsetFromHeadBone":[0.0,0.06,0.0],"type":"expression","rangeMapVerticalUp":{"inputMaxValue":30.0,"outputScale":1.0}}"#;
+        let look_at = serde_json::from_str::<LookAt>(json).expect("json should deserialize");
+
+        assert_eq!(look_at.typ, LookAtType::Expression);
+        assert!((look_at.range_map_vertical_up.output_scale - 1.0).abs() < f32::EPSILON);
+        assert_eq!(look_at.range_map_horizontal_inner, RangeMap::default());
+    }
+}
@@ -300,6 +300,11 @@ impl<'a> AccessorIter<'a> {
         }
     }
 
+    /// Iterate over elements of any type.
+    pub const fn elements(self) -> AccessorElements<'a> {
+        AccessorElements(self)
+    }
+
     pub fn count(&self) -> usize {
         match self {
             AccessorIter::F32(iter) => iter.count(),
@@ -391,6 +396,45 @@ impl<'a> AccessorIter<'a> {
     }
 }
 
+/// Iterator over the elements of an [`AccessorIter`].
+#[derive(Clone, Debug)]
+pub struct AccessorElements<'a>(AccessorIter<'a>);
+
+impl Iterator for AccessorElements<'_> {
+    type Item = AccessorElement;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.0 {
+            AccessorIter::F32(iter) => iter.next().map(Into::into),
+            AccessorIter::F32x2(iter) => iter.next().map(Into::into),
+            AccessorIter::F32x3(iter) => iter.next().map(Into::into),
+            AccessorIter::F32x4(iter) => iter.next().map(Into::into),
+            AccessorIter::F32x16(iter) => iter.next().map(Into::into),
+            AccessorIter::U32(iter) => iter.next().map(Into::into),
+            AccessorIter::U32x2(iter) => iter.next().map(Into::into),
+            AccessorIter::U32x3(iter) => iter.next().map(Into::into),
+            AccessorIter::U32x4(iter) => iter.next().map(Into::into),
+            AccessorIter::U16(iter) => iter.next().map(Into::into),
+            AccessorIter::U16x2(iter) => iter.next().map(Into::into),
+            AccessorIter::U16x3(iter) => iter.next().map(Into::into),
+            AccessorIter::U16x4(iter) => iter.next().map(Into::into),
+            AccessorIter::U8(iter) => iter.next().map(Into::into),
+            AccessorIter::U8x2(iter) => iter.next().map(Into::into),
+            AccessorIter::U8x3(iter) => iter.next().map(Into::into),
+            AccessorIter::U8x4(iter) => iter.next().map(Into::into),
+            AccessorIter::I16(iter) => iter.next().map(Into::into),
+            AccessorIter::I16x2(iter) => iter.next().map(Into::into),
+            AccessorIter::I16x3(iter) => iter.next().map(Into::into),
+            AccessorIter::I16x4(iter) => iter.next().map(Into::into),
+            AccessorIter::I8(iter) => iter.next().map(Into::into),
+            AccessorIter::I8x2(iter) => iter.next().map(Into::into),
+            AccessorIter::I8x3(iter) => iter.next().map(Into::into),
+            AccessorIter::I8x4(iter) => iter.next().map(Into::into),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum AccessorElement {
     F32(f32),
     F32x2([f32; 2]),
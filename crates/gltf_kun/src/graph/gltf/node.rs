@@ -4,7 +4,7 @@ use crate::graph::{Edge, Extensions, Graph, GraphNodeEdges, GraphNodeWeight, Wei
 
 use super::{GltfEdge, GltfWeight, Skin, mesh::Mesh};
 
-pub use bevy_math::{Quat, Vec3};
+pub use bevy_math::{Mat4, Quat, Vec3};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum NodeEdge {
@@ -29,6 +29,9 @@ impl From<NodeEdge> for Edge {
     }
 }
 
+/// The raw matrix is private, so weights cannot be built with a struct literal.
+/// Start from [`NodeWeight::default`] and assign fields, or use
+/// [`NodeWeight::set_trs`] and [`NodeWeight::set_matrix`].
 #[derive(Clone, Debug)]
 pub struct NodeWeight {
     pub name: Option<String>,
@@ -37,6 +40,9 @@ pub struct NodeWeight {
     pub rotation: Quat,
     pub scale: Vec3,
     pub translation: Vec3,
+    /// Raw local transform, for transforms that cannot be represented as TRS (such as shear).
+    /// Read through [`NodeWeight::matrix`], so writes to the TRS fields replace it.
+    matrix: Option<Mat4>,
     pub weights: Vec<f32>,
 }
 
@@ -49,11 +55,84 @@ impl Default for NodeWeight {
             rotation: Quat::IDENTITY,
             scale: Vec3::ONE,
             translation: Vec3::ZERO,
+            matrix: None,
             weights: Vec::new(),
         }
     }
 }
 
+/// Maximum error, relative to the largest element, for a matrix to be considered TRS.
+const TRS_EPSILON: f32 = 1e-5;
+
+impl NodeWeight {
+    /// Local transform matrix.
+    pub fn local_matrix(&self) -> Mat4 {
+        self.matrix().unwrap_or_else(|| {
+            Mat4::from_scale_rotation_translation(self.scale, self.rotation, self.translation)
+        })
+    }
+
+    /// Sets the local transform from a matrix.
+    /// The matrix is decomposed into TRS, and only kept if the decomposition is lossy.
+    pub fn set_matrix(&mut self, matrix: Mat4) {
+        if let Some((scale, rotation, translation)) = decompose_trs(matrix) {
+            self.set_trs(translation, rotation, scale);
+        } else {
+            let (scale, rotation, translation) = matrix.to_scale_rotation_translation();
+            self.set_trs(translation, rotation, scale);
+            self.matrix = Some(matrix);
+        }
+    }
+
+    /// Sets the local transform from TRS, discarding any raw matrix.
+    pub const fn set_trs(&mut self, translation: Vec3, rotation: Quat, scale: Vec3) {
+        self.translation = translation;
+        self.rotation = rotation;
+        self.scale = scale;
+        self.matrix = None;
+    }
+
+    /// Raw matrix set with [`NodeWeight::set_matrix`], if it cannot be represented as TRS.
+    ///
+    /// The TRS fields hold the matrix's closest decomposition. If they have been changed
+    /// since, beyond the tolerance used by [`decompose_trs`], the matrix is stale and
+    /// `None` is returned, so the TRS fields take effect.
+    pub fn matrix(&self) -> Option<Mat4> {
+        self.matrix.filter(|matrix| {
+            let (scale, rotation, translation) = matrix.to_scale_rotation_translation();
+            let closest = Mat4::from_scale_rotation_translation(scale, rotation, translation);
+            let trs =
+                Mat4::from_scale_rotation_translation(self.scale, self.rotation, self.translation);
+
+            trs.abs_diff_eq(closest, TRS_EPSILON * max_element(*matrix))
+        })
+    }
+
+    /// Whether the local transform is represented as TRS.
+    pub fn is_trs(&self) -> bool {
+        self.matrix().is_none()
+    }
+}
+
+/// Decomposes a matrix into scale, rotation and translation,
+/// or returns `None` if the matrix cannot be represented as TRS (such as with shear).
+pub fn decompose_trs(matrix: Mat4) -> Option<(Vec3, Quat, Vec3)> {
+    let (scale, rotation, translation) = matrix.to_scale_rotation_translation();
+
+    let trs = Mat4::from_scale_rotation_translation(scale, rotation, translation);
+
+    trs.abs_diff_eq(matrix, TRS_EPSILON * max_element(matrix))
+        .then_some((scale, rotation, translation))
+}
+
+/// Largest absolute element of a matrix, and at least one.
+fn max_element(matrix: Mat4) -> f32 {
+    matrix
+        .to_cols_array()
+        .iter()
+        .fold(1.0_f32, |max, v| max.max(v.abs()))
+}
+
 impl From<NodeWeight> for Weight {
     fn from(weight: NodeWeight) -> Self {
         Self::Gltf(GltfWeight::Node(weight))
@@ -153,6 +232,45 @@ mod tests {
         assert!(child.parents(&graph).is_empty());
     }
 
+    #[test]
+    fn matrix() {
+        let mut weight = NodeWeight::default();
+
+        let trs = Mat4::from_scale_rotation_translation(
+            Vec3::new(1.0, 2.0, 3.0),
+            Quat::from_rotation_y(1.0),
+            Vec3::new(4.0, 5.0, 6.0),
+        );
+        weight.set_matrix(trs);
+        assert!(weight.is_trs());
+        assert!(weight.local_matrix().abs_diff_eq(trs, 1e-5));
+
+        let shear = Mat4::from_cols_array(&[
+            1.0, 0.0, 0.0, 0.0, //
+            0.5, 1.0, 0.0, 0.0, //
+            0.0, 0.0, 1.0, 0.0, //
+            0.0, 0.0, 0.0, 1.0,
+        ]);
+        weight.set_matrix(shear);
+        assert!(!weight.is_trs());
+        assert_eq!(weight.matrix(), Some(shear));
+        assert_eq!(weight.local_matrix(), shear);
+
+        // Float error in the TRS fields, such as from a round trip, keeps the matrix.
+        weight.translation += Vec3::splat(1e-7);
+        assert_eq!(weight.matrix(), Some(shear));
+
+        // Writing a TRS field directly replaces the stale matrix.
+        weight.translation = Vec3::X;
+        assert!(weight.is_trs());
+        assert_eq!(weight.matrix(), None);
+        assert_eq!(weight.local_matrix().w_axis, Vec3::X.extend(1.0));
+
+        weight.set_trs(Vec3::ONE, Quat::IDENTITY, Vec3::ONE);
+        assert!(weight.is_trs());
+        assert_eq!(weight.local_matrix(), Mat4::from_translation(Vec3::ONE));
+    }
+
     #[test]
     fn mesh() {
         let mut graph = Graph::default();
@@ -1,6 +1,8 @@
 use petgraph::graph::NodeIndex;
 
-use crate::graph::{Edge, Extensions, Graph, GraphNodeEdges, GraphNodeWeight, Weight};
+use crate::graph::{
+    Edge, Extensions, Graph, GraphNodeEdges, GraphNodeWeight, OtherEdgeHelpers, Weight,
+};
 
 use super::{GltfEdge, GltfWeight, node::Node};
 
@@ -75,6 +77,7 @@ impl From<Scene> for NodeIndex {
 impl GraphNodeWeight<SceneWeight> for Scene {}
 impl GraphNodeEdges for Scene {}
 impl Extensions for Scene {}
+impl OtherEdgeHelpers for Scene {}
 
 impl Scene {
     pub fn nodes(&self, graph: &Graph) -> Vec<Node> {
@@ -10,7 +10,8 @@ use crate::{
 };
 
 use super::gltf::{
-    GltfExport, GltfFormat, GltfImport, export::GltfExportError, import::GltfImportError,
+    BIN_RESOURCE_KEY, GltfExport, GltfFormat, GltfImport, export::GltfExportError,
+    import::GltfImportError,
 };
 
 #[derive(Default)]
@@ -106,13 +107,20 @@ where
 
         let mut gltf = GltfExport::<E>::export(graph, doc)?;
 
+        // Extensions may add other resources, so look the buffer up by its URI.
+        let bin = gltf
+            .json
+            .buffers
+            .first()
+            .and_then(|buf| buf.uri.as_ref())
+            .and_then(|uri| gltf.resources.remove(uri));
+
         // Remove the buffer URI.
         for buf in &mut gltf.json.buffers {
             buf.uri = None;
         }
 
-        let json_bin = gltf.json.to_vec()?;
-        let bin = gltf.resources.values().next();
+        let json_bin = gltf.json_to_vec()?;
 
         let glb = gltf::Glb {
             header: gltf::binary::Header {
@@ -158,13 +166,13 @@ where
     ) -> Result<GltfDocument, GlbImportError> {
         let mut glb = gltf::Glb::from_slice(&format.0)?;
 
-        let json = serde_json::from_slice(&glb.json)?;
+        let json = GltfFormat::parse_json(&glb.json)?;
         let bin = glb.bin.take().map(Cow::into_owned);
 
         let mut resources = HashMap::new();
 
         if let Some(bin) = bin {
-            resources.insert("bin".to_string(), bin);
+            resources.insert(BIN_RESOURCE_KEY.to_string(), bin);
         }
 
         let format = GltfFormat { json, resources };
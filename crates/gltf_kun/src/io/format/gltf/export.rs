@@ -20,7 +20,7 @@ use thiserror::Error;
 use tracing::warn;
 
 use crate::graph::{
-    Graph, GraphNodeWeight,
+    Extensions, Graph, GraphNodeWeight,
     gltf::{Buffer, GltfDocument, accessor::iter::AccessorElement},
 };
 
@@ -439,6 +439,7 @@ pub fn export(graph: &mut Graph, doc: &GltfDocument) -> Result<GltfFormat, GltfE
                 .map(|idx| Index::new(*idx as u32));
 
             let weight = node.get(graph);
+            let matrix = weight.matrix();
 
             gltf::json::scene::Node {
                 name: weight.name.clone(),
@@ -448,19 +449,19 @@ pub fn export(graph: &mut Graph, doc: &GltfDocument) -> Result<GltfFormat, GltfE
                 camera: None,
                 children: None,
                 skin: None,
-                matrix: None,
+                matrix: matrix.map(|m| m.to_cols_array()),
                 mesh,
-                rotation: if weight.rotation == Quat::IDENTITY {
+                rotation: if matrix.is_some() || weight.rotation == Quat::IDENTITY {
                     None
                 } else {
                     Some(UnitQuaternion(weight.rotation.into()))
                 },
-                scale: if weight.scale == Vec3::ONE {
+                scale: if matrix.is_some() || weight.scale == Vec3::ONE {
                     None
                 } else {
                     Some(weight.scale.into())
                 },
-                translation: if weight.translation == Vec3::ZERO {
+                translation: if matrix.is_some() || weight.translation == Vec3::ZERO {
                     None
                 } else {
                     Some(weight.translation.into())
@@ -600,8 +601,11 @@ pub fn export(graph: &mut Graph, doc: &GltfDocument) -> Result<GltfFormat, GltfE
                                 idx
                             });
 
+                        // Channels without a node may be targeted by an extension.
                         let Some(node) = c.target(graph) else {
-                            warn!("No target found for animation channel.");
+                            if c.extensions(graph).is_empty() {
+                                warn!("No target found for animation channel.");
+                            }
                             return None;
                         };
 
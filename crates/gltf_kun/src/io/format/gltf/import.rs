@@ -28,6 +28,8 @@ pub enum GltfImportError {
     ReadAccessor(#[from] ReadAccessorError),
     #[error(transparent)]
     ReadBufferView(#[from] ReadBufferViewError),
+    #[error("unsupported required extensions: {}", .0.join(", "))]
+    UnsupportedExtensions(Vec<String>),
 }
 
 #[allow(clippy::too_many_lines)]
@@ -445,11 +447,7 @@ pub async fn import(
             weight.scale = n.scale.map_or(Vec3::ONE, Into::into);
 
             if let Some(matrix) = n.matrix {
-                let matrix = Mat4::from_cols_slice(&matrix);
-                let (scale, rotation, translation) = matrix.to_scale_rotation_translation();
-                weight.rotation = rotation;
-                weight.scale = scale;
-                weight.translation = translation;
+                weight.set_matrix(Mat4::from_cols_slice(&matrix));
             }
 
             if let Some(weights) = &n.weights {
@@ -554,10 +552,23 @@ pub async fn import(
         weight.name.clone_from(&a.name);
         weight.extras.clone_from(&a.extras);
 
-        let samplers = a
-            .samplers
-            .iter()
-            .map(|s| {
+        // Samplers are created when first used, so samplers only used by extensions
+        // are left to them.
+        let mut samplers = vec![None; a.samplers.len()];
+
+        a.channels.iter().for_each(|c| {
+            let mut channel = animation.create_channel(graph);
+            let c_weight = channel.get_mut(graph);
+
+            c_weight.extras.clone_from(&c.extras);
+            c_weight.path = c.target.path.unwrap();
+
+            let node = nodes[c.target.node.value()];
+            channel.set_target(graph, Some(node));
+
+            let sampler_idx = c.sampler.value();
+            let sampler = *samplers[sampler_idx].get_or_insert_with(|| {
+                let s = &a.samplers[sampler_idx];
                 let mut sampler = AnimationSampler::new(graph);
 
                 let weight = sampler.get_mut(graph);
@@ -573,25 +584,17 @@ pub async fn import(
                 sampler.set_output(graph, Some(output));
 
                 sampler
-            })
-            .collect::<Vec<_>>();
-
-        a.channels.iter().for_each(|c| {
-            let mut channel = animation.create_channel(graph);
-            let c_weight = channel.get_mut(graph);
-
-            c_weight.extras.clone_from(&c.extras);
-            c_weight.path = c.target.path.unwrap();
-
-            let node = nodes[c.target.node.value()];
-            channel.set_target(graph, Some(node));
-
-            let sampler_idx = c.sampler.value();
-            let sampler = &samplers[sampler_idx];
-            channel.set_sampler(graph, Some(*sampler));
+            });
+            channel.set_sampler(graph, Some(sampler));
         });
     }
 
+    // Return buffer data to the format, so extensions can read buffer views.
+    for (buf, data) in format.json.buffers.iter().zip(buffer_data) {
+        let key = super::buffer_resource_key(buf).to_string();
+        format.resources.insert(key, data);
+    }
+
     Ok(doc)
 }
 
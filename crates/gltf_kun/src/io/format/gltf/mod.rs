@@ -1,10 +1,16 @@
-use std::{collections::HashMap, fs::File, io::BufWriter, path::Path};
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
 
+use gltf::json::validation::USize64;
 use thiserror::Error;
 use tracing::{debug, warn};
 
 use crate::{
-    extensions::{ExtensionExport, ExtensionImport},
+    extensions::{CORE_EXTENSIONS, ExtensionExport, ExtensionImport},
     graph::{Graph, gltf::document::GltfDocument},
     io::resolver::{FileResolver, Resolver},
 };
@@ -28,7 +34,113 @@ pub enum WriteFileError {
     SerdeJson(#[from] serde_json::Error),
 }
 
+/// Resource key used for a buffer without a URI, such as the GLB binary chunk.
+pub const BIN_RESOURCE_KEY: &str = "bin";
+
+fn buffer_resource_key(buffer: &gltf::json::Buffer) -> &str {
+    buffer.uri.as_deref().unwrap_or(BIN_RESOURCE_KEY)
+}
+
 impl GltfFormat {
+    /// Read the data of a buffer view.
+    /// Buffer data is kept in the resources after import, so extensions can use this.
+    pub fn read_buffer_view(&self, index: usize) -> Option<&[u8]> {
+        let view = self.json.buffer_views.get(index)?;
+        let buffer = self.json.buffers.get(view.buffer.value())?;
+        let data = self.resources.get(buffer_resource_key(buffer))?;
+
+        let start = view.byte_offset.map(|o| o.0 as usize).unwrap_or_default();
+        let end = start + view.byte_length.0 as usize;
+
+        data.get(start..end)
+    }
+
+    /// Append data to a buffer, creating a new buffer view for it.
+    /// Returns the index of the created buffer view.
+    pub fn push_buffer_view(&mut self, buffer: usize, data: &[u8]) -> Option<usize> {
+        let buffer_json = self.json.buffers.get_mut(buffer)?;
+        let key = buffer_resource_key(buffer_json).to_string();
+        let resource = self.resources.entry(key).or_default();
+
+        // Keep views 8-byte aligned, as required by EXT_structural_metadata,
+        // which also covers any accessors written after us.
+        let padding = (8 - resource.len() % 8) % 8;
+        resource.extend(std::iter::repeat_n(0, padding));
+
+        let byte_offset = resource.len();
+        resource.extend_from_slice(data);
+        buffer_json.byte_length = USize64(resource.len() as u64);
+
+        self.json.buffer_views.push(gltf::json::buffer::View {
+            extensions: None,
+            extras: None,
+            name: None,
+
+            buffer: gltf::json::Index::new(buffer as u32),
+            byte_length: data.len().into(),
+            byte_offset: Some(byte_offset.into()),
+            byte_stride: None,
+            target: None,
+        });
+
+        Some(self.json.buffer_views.len() - 1)
+    }
+
+    /// Parse glTF JSON.
+    /// Data that `gltf::json` cannot represent, such as pointer channels, is moved into
+    /// the root extensions, where its extension can read it.
+    pub fn parse_json(bytes: &[u8]) -> Result<gltf::json::Root, serde_json::Error> {
+        #[cfg(any(feature = "khr_animation_pointer", feature = "khr_xmp_json_ld"))]
+        {
+            let mut value = serde_json::from_slice::<serde_json::Value>(bytes)?;
+            let mut moved = false;
+
+            #[cfg(feature = "khr_animation_pointer")]
+            {
+                moved |= crate::extensions::khr_animation_pointer::json::take_channels(&mut value);
+            }
+
+            #[cfg(feature = "khr_xmp_json_ld")]
+            {
+                moved |= crate::extensions::khr_xmp_json_ld::json::take_asset_packet(&mut value);
+            }
+
+            if moved {
+                return serde_json::from_value(value);
+            }
+        }
+
+        serde_json::from_slice(bytes)
+    }
+
+    /// Serialize the glTF JSON, reversing the changes made by [`GltfFormat::parse_json`].
+    pub fn json_to_vec(&self) -> Result<Vec<u8>, serde_json::Error> {
+        let bytes = serde_json::to_vec(&self.json)?;
+
+        #[cfg(any(feature = "khr_animation_pointer", feature = "khr_xmp_json_ld"))]
+        {
+            let mut value = serde_json::from_slice::<serde_json::Value>(&bytes)?;
+            let mut moved = false;
+
+            #[cfg(feature = "khr_animation_pointer")]
+            {
+                moved |=
+                    crate::extensions::khr_animation_pointer::json::restore_channels(&mut value);
+            }
+
+            #[cfg(feature = "khr_xmp_json_ld")]
+            {
+                moved |= crate::extensions::khr_xmp_json_ld::json::restore_asset_packet(&mut value);
+            }
+
+            if moved {
+                return serde_json::to_vec(&value);
+            }
+        }
+
+        Ok(bytes)
+    }
+
     /// Write the glTF to a file.
     /// Resources will be written to the same directory.
     pub fn write_file(&self, path: &Path) -> Result<(), WriteFileError> {
@@ -36,8 +148,8 @@ impl GltfFormat {
 
         // Write json file
         let file = File::create(path)?;
-        let writer = BufWriter::new(file);
-        serde_json::to_writer(writer, &self.json)?;
+        let mut writer = BufWriter::new(file);
+        writer.write_all(&self.json_to_vec()?)?;
 
         // Write resources
         let dir = Path::new(path)
@@ -101,6 +213,24 @@ where
         mut format: GltfFormat,
         resolver: Option<impl Resolver>,
     ) -> Result<GltfDocument, GltfImportError> {
+        if E::reject_unsupported() {
+            let supported = E::supported_extensions();
+
+            let unsupported = format
+                .json
+                .extensions_required
+                .iter()
+                .filter(|name| {
+                    !supported.contains(&name.as_str()) && !CORE_EXTENSIONS.contains(&name.as_str())
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+
+            if !unsupported.is_empty() {
+                return Err(GltfImportError::UnsupportedExtensions(unsupported));
+            }
+        }
+
         let doc = import::import(graph, &mut format, resolver).await?;
 
         if let Err(e) = E::import(graph, &mut format, &doc) {
@@ -116,7 +246,7 @@ where
         path: &Path,
     ) -> Result<GltfDocument, ImportFileError> {
         let format = GltfFormat {
-            json: serde_json::from_reader(std::fs::File::open(path)?)?,
+            json: GltfFormat::parse_json(&std::fs::read(path)?)?,
             ..Default::default()
         };
 
@@ -130,3 +260,133 @@ where
         Ok(doc)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        extensions::DefaultExtensions,
+        graph::{
+            GraphNodeWeight,
+            gltf::node::{Mat4, Quat, Vec3},
+        },
+        io::resolver::DataUriResolver,
+    };
+
+    use super::*;
+
+    struct CustomExtensions;
+
+    impl ExtensionImport<GltfDocument, GltfFormat> for CustomExtensions {
+        fn import(
+            _graph: &mut Graph,
+            _format: &mut GltfFormat,
+            _doc: &GltfDocument,
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            Ok(())
+        }
+    }
+
+    struct LenientExtensions;
+
+    impl ExtensionImport<GltfDocument, GltfFormat> for LenientExtensions {
+        fn reject_unsupported() -> bool {
+            false
+        }
+
+        fn import(
+            _graph: &mut Graph,
+            _format: &mut GltfFormat,
+            _doc: &GltfDocument,
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            Ok(())
+        }
+    }
+
+    async fn import_required<E: ExtensionImport<GltfDocument, GltfFormat>>(
+        required: &[&str],
+    ) -> Result<GltfDocument, GltfImportError> {
+        let mut format = GltfFormat::default();
+        format.json.extensions_required = required.iter().map(ToString::to_string).collect();
+
+        let mut graph = Graph::default();
+        GltfImport::<E>::import(&mut graph, format, None::<DataUriResolver>).await
+    }
+
+    #[tokio::test]
+    async fn test_extensions_required() {
+        import_required::<DefaultExtensions>(&["KHR_mesh_quantization"])
+            .await
+            .expect("core extensions should import");
+
+        #[cfg(feature = "omi_seat")]
+        import_required::<DefaultExtensions>(&["OMI_seat"])
+            .await
+            .expect("supported extensions should import");
+
+        #[cfg(feature = "khr_audio_emitter")]
+        import_required::<DefaultExtensions>(&["OMI_audio_emitter"])
+            .await
+            .expect("extensions read under another name should import");
+
+        import_required::<CustomExtensions>(&["KHR_draco_mesh_compression"])
+            .await
+            .expect_err("imports should reject unsupported extensions by default");
+
+        import_required::<LenientExtensions>(&["KHR_draco_mesh_compression"])
+            .await
+            .expect("imports that opt out should not reject extensions");
+
+        let err = import_required::<DefaultExtensions>(&[
+            "KHR_draco_mesh_compression",
+            "KHR_mesh_quantization",
+            "EXT_meshopt_compression",
+        ])
+        .await
+        .expect_err("unsupported extensions should fail");
+
+        let GltfImportError::UnsupportedExtensions(names) = err else {
+            panic!("unexpected error: {err}");
+        };
+        assert_eq!(
+            names,
+            vec!["KHR_draco_mesh_compression", "EXT_meshopt_compression"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_node_matrix() {
+        let mut graph = Graph::default();
+        let doc = GltfDocument::new(&mut graph);
+
+        let shear = Mat4::from_cols_array(&[
+            1.0, 0.0, 0.0, 0.0, //
+            0.5, 1.0, 0.0, 0.0, //
+            0.0, 0.0, 1.0, 0.0, //
+            1.0, 2.0, 3.0, 1.0,
+        ]);
+
+        let mut node = doc.create_node(&mut graph);
+        node.get_mut(&mut graph).set_matrix(shear);
+
+        let mut node = doc.create_node(&mut graph);
+        node.get_mut(&mut graph)
+            .set_trs(Vec3::X, Quat::IDENTITY, Vec3::ONE);
+
+        let format = GltfExport::<DefaultExtensions>::export(&mut graph, &doc)
+            .expect("export should succeed");
+        assert_eq!(format.json.nodes[0].matrix, Some(shear.to_cols_array()));
+        assert!(format.json.nodes[0].translation.is_none());
+        assert!(format.json.nodes[1].matrix.is_none());
+
+        let mut graph = Graph::default();
+        let doc =
+            GltfImport::<DefaultExtensions>::import(&mut graph, format, None::<DataUriResolver>)
+                .await
+                .expect("import should succeed");
+
+        let nodes = doc.nodes(&graph);
+        assert_eq!(nodes[0].get(&graph).matrix(), Some(shear));
+        assert!(nodes[1].get(&graph).is_trs());
+        assert_eq!(nodes[1].get(&graph).translation, Vec3::X);
+    }
+}
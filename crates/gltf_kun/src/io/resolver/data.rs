@@ -6,6 +6,13 @@ pub struct DataUriResolver;
 
 impl Resolver for DataUriResolver {
     async fn resolve(&mut self, uri: &str) -> Result<Vec<u8>, ResolverError> {
+        Self::decode(uri)
+    }
+}
+
+impl DataUriResolver {
+    /// Decode a data URI without going through the async [Resolver] interface.
+    pub fn decode(uri: &str) -> Result<Vec<u8>, ResolverError> {
         let uri = uri.to_string();
 
         let uri = uri
@@ -39,3 +39,4 @@
 pub mod extensions;
 pub mod graph;
 pub mod io;
+pub mod transform;
@@ -0,0 +1,316 @@
+//! Merge duplicate properties.
+
+use std::{
+    collections::{HashMap, hash_map::Entry},
+    error::Error,
+    hash::Hash,
+};
+
+use petgraph::{Direction, stable_graph::NodeIndex, visit::EdgeRef};
+use tracing::debug;
+
+use crate::graph::{
+    Edge, Graph, GraphNodeWeight,
+    gltf::{
+        Accessor, GltfEdge, Image, Material, Texture, document::GltfDocument,
+        material::MaterialWeight,
+    },
+};
+
+use super::{Transform, has_extensions};
+
+/// Merges identical accessors, images, textures and materials.
+///
+/// Every reference to a duplicate is moved to a single canonical property,
+/// and the duplicate is removed from the graph.
+/// Properties with extensions are never merged.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Dedup {
+    /// Only merge properties with the same name.
+    pub keep_unique_names: bool,
+}
+
+impl Transform for Dedup {
+    fn name(&self) -> &'static str {
+        "dedup"
+    }
+
+    fn apply(&self, graph: &mut Graph, doc: GltfDocument) -> Result<(), Box<dyn Error>> {
+        let accessors = doc.accessors(graph);
+        let duplicates = find_duplicates(graph, accessors, |graph, accessor: Accessor| {
+            let weight = accessor.get(graph);
+            (
+                self.compared_name(weight.name.as_deref()),
+                weight.extras.as_ref().map(|e| e.get()),
+                weight.component_type.as_gl_enum(),
+                weight.element_type as u8,
+                weight.normalized,
+                weight.data.as_slice(),
+            )
+        });
+        let accessors = merge(graph, doc, &duplicates);
+
+        let images = doc.images(graph);
+        let duplicates = find_duplicates(graph, images, |graph, image: Image| {
+            let weight = image.get(graph);
+            (
+                self.compared_name(weight.name.as_deref()),
+                weight.extras.as_ref().map(|e| e.get()),
+                weight.mime_type.as_deref(),
+                weight.uri.as_deref(),
+                weight.data.as_slice(),
+            )
+        });
+        let images = merge(graph, doc, &duplicates);
+
+        let textures = doc.textures(graph);
+        let duplicates = find_duplicates(graph, textures, |graph, texture: Texture| {
+            let weight = texture.get(graph);
+            (
+                self.compared_name(weight.name.as_deref()),
+                weight.extras.as_ref().map(|e| e.get()),
+                weight.mag_filter.map(|f| f.as_gl_enum()),
+                weight.min_filter.map(|f| f.as_gl_enum()),
+                weight.wrap_s.as_gl_enum(),
+                weight.wrap_t.as_gl_enum(),
+                texture.image(graph),
+            )
+        });
+        let textures = merge(graph, doc, &duplicates);
+
+        let materials = doc.materials(graph);
+        let duplicates = find_duplicates(graph, materials, |graph, material: Material| {
+            MaterialKey::new(
+                graph,
+                material,
+                self.compared_name(material.get(graph).name.as_deref()),
+            )
+        });
+        let materials = merge(graph, doc, &duplicates);
+
+        debug!(
+            "Merged {} accessors, {} images, {} textures, {} materials",
+            accessors, images, textures, materials
+        );
+
+        Ok(())
+    }
+}
+
+impl Dedup {
+    /// The name to compare, if names are kept unique.
+    const fn compared_name(self, name: Option<&str>) -> Option<&str> {
+        if self.keep_unique_names { name } else { None }
+    }
+}
+
+/// Material properties, with floats compared bitwise.
+#[derive(PartialEq, Eq, Hash)]
+struct MaterialKey<'a> {
+    name: Option<&'a str>,
+    extras: Option<&'a str>,
+    alpha_cutoff: u32,
+    alpha_mode: u8,
+    base_color_factor: [u32; 4],
+    double_sided: bool,
+    emissive_factor: [u32; 3],
+    metallic_factor: u32,
+    normal_scale: u32,
+    occlusion_strength: u32,
+    roughness_factor: u32,
+    tex_coords: [usize; 5],
+    textures: [Option<Texture>; 5],
+}
+
+impl<'a> MaterialKey<'a> {
+    fn new(graph: &'a Graph, material: Material, name: Option<&'a str>) -> Self {
+        let weight: &'a MaterialWeight = material.get(graph);
+
+        Self {
+            name,
+            extras: weight.extras.as_ref().map(|e| e.get()),
+            alpha_cutoff: weight.alpha_cutoff.0.to_bits(),
+            alpha_mode: weight.alpha_mode as u8,
+            base_color_factor: weight.base_color_factor.map(f32::to_bits),
+            double_sided: weight.double_sided,
+            emissive_factor: weight.emissive_factor.map(f32::to_bits),
+            metallic_factor: weight.metallic_factor.to_bits(),
+            normal_scale: weight.normal_scale.to_bits(),
+            occlusion_strength: weight.occlusion_strength.to_bits(),
+            roughness_factor: weight.roughness_factor.to_bits(),
+            tex_coords: [
+                weight.base_color_tex_coord,
+                weight.emissive_tex_coord,
+                weight.metallic_roughness_tex_coord,
+                weight.normal_tex_coord,
+                weight.occlusion_tex_coord,
+            ],
+            textures: [
+                material.base_color_texture(graph),
+                material.emissive_texture(graph),
+                material.metallic_roughness_texture(graph),
+                material.normal_texture(graph),
+                material.occlusion_texture(graph),
+            ],
+        }
+    }
+}
+
+/// Pairs each duplicate with the first property that has the same key.
+fn find_duplicates<'a, T, K>(
+    graph: &'a Graph,
+    properties: Vec<T>,
+    key: impl Fn(&'a Graph, T) -> K,
+) -> Vec<(NodeIndex, NodeIndex)>
+where
+    T: Copy + Into<NodeIndex>,
+    K: Eq + Hash,
+{
+    let mut canonical = HashMap::new();
+    let mut duplicates = Vec::new();
+
+    for property in properties {
+        let index = property.into();
+
+        if has_extensions(graph, index) {
+            continue;
+        }
+
+        match canonical.entry(key(graph, property)) {
+            Entry::Occupied(entry) => duplicates.push((index, *entry.get())),
+            Entry::Vacant(entry) => {
+                entry.insert(index);
+            }
+        }
+    }
+
+    duplicates
+}
+
+/// Moves every incoming edge of each duplicate to its canonical property,
+/// then removes the duplicate.
+fn merge(graph: &mut Graph, doc: GltfDocument, duplicates: &[(NodeIndex, NodeIndex)]) -> usize {
+    for &(duplicate, canonical) in duplicates {
+        let edges = graph
+            .edges_directed(duplicate, Direction::Incoming)
+            .filter(|edge| {
+                edge.source() != doc.0
+                    || !matches!(edge.weight(), Edge::Gltf(GltfEdge::Document(_)))
+            })
+            .map(|edge| (edge.source(), edge.weight().clone()))
+            .collect::<Vec<_>>();
+
+        for (source, edge) in edges {
+            graph.add_edge(source, canonical, edge);
+        }
+
+        graph.remove_node(duplicate);
+    }
+
+    duplicates.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::gltf::primitive::Semantic;
+
+    use super::*;
+
+    fn create_accessor(graph: &mut Graph, doc: GltfDocument, data: &[u8]) -> Accessor {
+        let mut accessor = doc.create_accessor(graph);
+        accessor.get_mut(graph).data = data.to_vec();
+        accessor
+    }
+
+    fn create_material(graph: &mut Graph, doc: GltfDocument, name: &str) -> Material {
+        let image = doc.create_image(graph);
+        let texture = doc.create_texture(graph);
+        texture.set_image(graph, Some(image));
+
+        let mut material = doc.create_material(graph);
+        material.get_mut(graph).name = Some(name.to_string());
+        material.set_base_color_texture(graph, Some(texture));
+        material
+    }
+
+    #[test]
+    fn dedup_accessors() {
+        let mut graph = Graph::default();
+        let doc = GltfDocument::new(&mut graph);
+
+        let a = create_accessor(&mut graph, doc, &[1, 2, 3, 4]);
+        let b = create_accessor(&mut graph, doc, &[1, 2, 3, 4]);
+        let c = create_accessor(&mut graph, doc, &[5, 6, 7, 8]);
+        let mut d = create_accessor(&mut graph, doc, &[1, 2, 3, 4]);
+        d.get_mut(&mut graph).component_type = gltf::json::accessor::ComponentType::U32;
+
+        let mesh = doc.create_mesh(&mut graph);
+        let primitive = mesh.create_primitive(&mut graph);
+        primitive.set_attribute(&mut graph, Semantic::Positions, Some(a));
+        primitive.set_attribute(&mut graph, Semantic::Normals, Some(b));
+        primitive.set_indices(&mut graph, Some(b));
+
+        Dedup::default()
+            .apply(&mut graph, doc)
+            .expect("dedup should succeed");
+
+        assert_eq!(doc.accessors(&graph), vec![a, c, d]);
+        assert!(!graph.contains_node(b.into()));
+        assert_eq!(primitive.attribute(&graph, Semantic::Normals), Some(a));
+        assert_eq!(primitive.indices(&graph), Some(a));
+    }
+
+    #[test]
+    fn dedup_materials() {
+        let mut graph = Graph::default();
+        let doc = GltfDocument::new(&mut graph);
+
+        let a = create_material(&mut graph, doc, "a");
+        let b = create_material(&mut graph, doc, "b");
+        let mut c = create_material(&mut graph, doc, "c");
+        c.get_mut(&mut graph).metallic_factor = 0.5;
+
+        let mesh = doc.create_mesh(&mut graph);
+        let primitive = mesh.create_primitive(&mut graph);
+        primitive.set_material(&mut graph, Some(b));
+
+        Dedup {
+            keep_unique_names: true,
+        }
+        .apply(&mut graph, doc)
+        .expect("dedup should succeed");
+
+        assert_eq!(doc.images(&graph).len(), 1);
+        assert_eq!(doc.textures(&graph).len(), 1);
+        assert_eq!(doc.materials(&graph), vec![a, b, c]);
+        assert_eq!(a.base_color_texture(&graph), b.base_color_texture(&graph));
+
+        Dedup::default()
+            .apply(&mut graph, doc)
+            .expect("dedup should succeed");
+
+        assert_eq!(doc.materials(&graph), vec![a, c]);
+        assert_eq!(primitive.material(&graph), Some(a));
+    }
+
+    #[cfg(feature = "khr_materials_dispersion")]
+    #[test]
+    fn dedup_extensions() {
+        use crate::{
+            extensions::khr_materials_dispersion::KhrMaterialsDispersion, graph::Extensions,
+        };
+
+        let mut graph = Graph::default();
+        let doc = GltfDocument::new(&mut graph);
+
+        let a = create_material(&mut graph, doc, "a");
+        let b = create_material(&mut graph, doc, "a");
+        b.create_extension::<KhrMaterialsDispersion>(&mut graph);
+
+        Dedup::default()
+            .apply(&mut graph, doc)
+            .expect("dedup should succeed");
+
+        assert_eq!(doc.materials(&graph), vec![a, b]);
+    }
+}
@@ -0,0 +1,156 @@
+//! Flatten the node hierarchy.
+
+use std::error::Error;
+
+use bevy_math::Mat4;
+use tracing::debug;
+
+use crate::graph::{
+    Graph, GraphNodeWeight,
+    gltf::{Node, document::GltfDocument},
+};
+
+use super::{Transform, has_extensions, is_hierarchy_only};
+
+/// Moves mesh nodes directly under their scene, baking parent transforms into their own.
+///
+/// Nodes that are animated, used by a skin, referenced by an extension or have
+/// extensions of their own are left in place, along with their descendants.
+/// Emptied parent nodes are left in the document, and can be removed with
+/// [`Prune`](super::prune::Prune).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Flatten;
+
+impl Transform for Flatten {
+    fn name(&self) -> &'static str {
+        "flatten"
+    }
+
+    fn apply(&self, graph: &mut Graph, doc: GltfDocument) -> Result<(), Box<dyn Error>> {
+        for scene in doc.scenes(graph) {
+            let mut moves = Vec::new();
+
+            let mut stack = scene
+                .nodes(graph)
+                .into_iter()
+                .map(|node| (node, None, Mat4::IDENTITY, false))
+                .collect::<Vec<(Node, Option<Node>, Mat4, bool)>>();
+
+            while let Some((node, parent, parent_world, pinned)) = stack.pop() {
+                let world = parent_world * node.get(graph).local_matrix();
+                let pinned =
+                    pinned || !is_hierarchy_only(graph, node.0) || has_extensions(graph, node.0);
+
+                if let Some(parent) = parent
+                    && !pinned
+                    && node.mesh(graph).is_some()
+                {
+                    moves.push((node, parent, world));
+                }
+
+                for child in node.children(graph) {
+                    stack.push((child, Some(node), world, pinned));
+                }
+            }
+
+            debug!("Moving {} nodes to scene root", moves.len());
+
+            for (mut node, parent, world) in moves {
+                parent.remove_child(graph, &node);
+                scene.add_node(graph, node);
+                node.get_mut(graph).set_matrix(world);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_math::Vec3;
+
+    use super::*;
+
+    fn create_node(graph: &mut Graph, doc: GltfDocument, parent: Node, x: f32) -> Node {
+        let mut node = doc.create_node(graph);
+        node.get_mut(graph).translation = Vec3::new(x, 0.0, 0.0);
+        parent.add_child(graph, &node);
+
+        let mesh = doc.create_mesh(graph);
+        node.set_mesh(graph, Some(mesh));
+        node
+    }
+
+    #[test]
+    fn flatten() {
+        let mut graph = Graph::default();
+        let doc = GltfDocument::new(&mut graph);
+        let scene = doc.create_scene(&mut graph);
+
+        let mut root = doc.create_node(&mut graph);
+        root.get_mut(&mut graph).translation = Vec3::new(1.0, 0.0, 0.0);
+        scene.add_node(&mut graph, root);
+
+        let a = create_node(&mut graph, doc, root, 2.0);
+        let b = create_node(&mut graph, doc, a, 3.0);
+
+        let animated = create_node(&mut graph, doc, root, 4.0);
+        let animated_child = create_node(&mut graph, doc, animated, 5.0);
+
+        let animation = doc.create_animation(&mut graph);
+        let channel = animation.create_channel(&mut graph);
+        channel.set_target(&mut graph, Some(animated));
+
+        Flatten
+            .apply(&mut graph, doc)
+            .expect("flatten should succeed");
+
+        let mut nodes = scene.nodes(&graph);
+        nodes.sort();
+        assert_eq!(nodes, vec![root, a, b]);
+
+        assert!(a.children(&graph).is_empty());
+        assert_eq!(a.get(&graph).translation, Vec3::new(3.0, 0.0, 0.0));
+        assert_eq!(b.get(&graph).translation, Vec3::new(6.0, 0.0, 0.0));
+
+        assert_eq!(root.children(&graph), vec![animated]);
+        assert_eq!(animated.children(&graph), vec![animated_child]);
+        assert_eq!(
+            animated_child.get(&graph).translation,
+            Vec3::new(5.0, 0.0, 0.0)
+        );
+    }
+
+    #[cfg(feature = "khr_node_visibility")]
+    #[test]
+    fn flatten_hidden() {
+        use crate::{
+            extensions::khr_node_visibility::{KhrNodeVisibility, weight::NodeVisibilityWeight},
+            graph::{ByteNode, Extensions},
+        };
+
+        let mut graph = Graph::default();
+        let doc = GltfDocument::new(&mut graph);
+        let scene = doc.create_scene(&mut graph);
+
+        let root = doc.create_node(&mut graph);
+        scene.add_node(&mut graph, root);
+
+        let hidden = doc.create_node(&mut graph);
+        root.add_child(&mut graph, &hidden);
+
+        let ext = hidden.create_extension::<KhrNodeVisibility>(&mut graph);
+        ext.write(&mut graph, &NodeVisibilityWeight { visible: false });
+
+        let child = create_node(&mut graph, doc, hidden, 1.0);
+
+        Flatten
+            .apply(&mut graph, doc)
+            .expect("flatten should succeed");
+
+        assert_eq!(scene.nodes(&graph), vec![root]);
+        assert_eq!(root.children(&graph), vec![hidden]);
+        assert_eq!(hidden.children(&graph), vec![child]);
+    }
+}
@@ -0,0 +1,294 @@
+//! Helpers for reading and rewriting primitive geometry.
+
+use bevy_math::{Mat4, Vec3, Vec4};
+use gltf::json::accessor::{ComponentType, Type};
+use thiserror::Error;
+
+use crate::graph::{
+    Graph, GraphNodeWeight,
+    gltf::{
+        Accessor, Buffer, Primitive,
+        accessor::iter::{AccessorIter, AccessorIterCreateError},
+        document::GltfDocument,
+        primitive::Semantic,
+    },
+};
+
+#[derive(Debug, Error)]
+pub enum GeometryError {
+    #[error(transparent)]
+    AccessorIter(#[from] AccessorIterCreateError),
+    #[error("invalid index type {0:?} {1:?}")]
+    IndexType(ComponentType, Type),
+}
+
+/// Size in bytes of a single accessor element.
+pub fn element_size(graph: &Graph, accessor: Accessor) -> usize {
+    let weight = accessor.get(graph);
+    weight.element_type.multiplicity() * weight.component_type.size()
+}
+
+/// Number of vertices in a primitive, if all attributes agree.
+pub fn vertex_count(graph: &Graph, primitive: Primitive) -> Option<usize> {
+    let mut counts = vertex_accessors(graph, primitive)
+        .into_iter()
+        .map(|(_, accessor)| accessor.count(graph));
+
+    let count = counts.next()?;
+
+    if counts.all(|c| c == count) {
+        Some(count)
+    } else {
+        None
+    }
+}
+
+pub fn read_indices(graph: &Graph, accessor: Accessor) -> Result<Vec<u32>, GeometryError> {
+    match accessor.to_iter(graph)? {
+        AccessorIter::U8(iter) => Ok(iter.map(u32::from).collect()),
+        AccessorIter::U16(iter) => Ok(iter.map(u32::from).collect()),
+        AccessorIter::U32(iter) => Ok(iter.collect()),
+        iter => Err(GeometryError::IndexType(
+            iter.component_type(),
+            iter.element_type(),
+        )),
+    }
+}
+
+/// Reads the indices of a primitive, or generates sequential indices if it has none.
+pub fn read_indices_or_sequential(
+    graph: &Graph,
+    primitive: Primitive,
+    vertex_count: usize,
+) -> Result<Vec<u32>, GeometryError> {
+    primitive.indices(graph).map_or_else(
+        || Ok((0..vertex_count).map(index_u32).collect()),
+        |indices| read_indices(graph, indices),
+    )
+}
+
+/// Creates an index accessor, using the smallest component type that fits `vertex_count`.
+pub fn create_indices(
+    graph: &mut Graph,
+    doc: GltfDocument,
+    indices: &[u32],
+    vertex_count: usize,
+) -> Accessor {
+    let mut accessor = doc.create_accessor(graph);
+    let weight = accessor.get_mut(graph);
+    weight.element_type = Type::Scalar;
+
+    // The maximum value of each type is reserved for primitive restart.
+    if vertex_count < usize::from(u16::MAX) {
+        weight.component_type = ComponentType::U16;
+        weight.data = indices
+            .iter()
+            .flat_map(|&i| u16::try_from(i).unwrap_or(u16::MAX).to_le_bytes())
+            .collect();
+    } else {
+        weight.component_type = ComponentType::U32;
+        weight.data = indices.iter().flat_map(|i| i.to_le_bytes()).collect();
+    }
+
+    accessor
+}
+
+/// Creates a float accessor from flattened element data.
+pub fn create_f32_accessor(
+    graph: &mut Graph,
+    doc: GltfDocument,
+    buffer: Option<Buffer>,
+    element_type: Type,
+    data: &[f32],
+) -> Accessor {
+    let mut accessor = doc.create_accessor(graph);
+    let weight = accessor.get_mut(graph);
+    weight.component_type = ComponentType::F32;
+    weight.element_type = element_type;
+    weight.data = data.iter().copied().flat_map(f32::to_le_bytes).collect();
+    accessor.set_buffer(graph, buffer);
+    accessor
+}
+
+/// Creates a copy of an accessor with new data, sharing its buffer.
+pub fn copy_accessor(
+    graph: &mut Graph,
+    doc: GltfDocument,
+    accessor: Accessor,
+    data: Vec<u8>,
+) -> Accessor {
+    let mut weight = accessor.get(graph).clone();
+    weight.data = data;
+
+    let buffer = accessor.buffer(graph);
+
+    let mut copy = doc.create_accessor(graph);
+    *copy.get_mut(graph) = weight;
+    copy.set_buffer(graph, buffer);
+    copy
+}
+
+/// Creates a copy of an accessor containing the elements at each index of `order`.
+pub fn remap_accessor(
+    graph: &mut Graph,
+    doc: GltfDocument,
+    accessor: Accessor,
+    order: &[u32],
+) -> Accessor {
+    let size = element_size(graph, accessor);
+    let data = &accessor.get(graph).data;
+
+    let data = order
+        .iter()
+        .flat_map(|&i| {
+            let start = i as usize * size;
+            data[start..start + size].iter().copied()
+        })
+        .collect();
+
+    copy_accessor(graph, doc, accessor, data)
+}
+
+/// Replaces every vertex attribute of a primitive, including morph targets,
+/// with the elements at each index of `order`.
+///
+/// The previous accessors are left in the document, and can be removed with
+/// [`Prune`](super::prune::Prune).
+pub fn remap_vertices(graph: &mut Graph, doc: GltfDocument, primitive: Primitive, order: &[u32]) {
+    for (semantic, accessor) in primitive.attributes(graph) {
+        let remapped = remap_accessor(graph, doc, accessor, order);
+        primitive.set_attribute(graph, semantic, Some(remapped));
+    }
+
+    for target in primitive.morph_targets(graph) {
+        for (semantic, accessor) in target.attributes(graph) {
+            let remapped = remap_accessor(graph, doc, accessor, order);
+            target.set_attribute(graph, semantic, Some(remapped));
+        }
+    }
+}
+
+/// Removes the indices of a primitive, duplicating shared vertices so that
+/// each of its `indices` has its own vertex.
+pub fn unweld_primitive(
+    graph: &mut Graph,
+    doc: GltfDocument,
+    primitive: Primitive,
+    indices: &[u32],
+) {
+    remap_vertices(graph, doc, primitive, indices);
+    primitive.set_indices(graph, None);
+}
+
+/// Attribute accessors of a primitive, followed by those of its morph targets.
+pub fn vertex_accessors(graph: &Graph, primitive: Primitive) -> Vec<(Semantic, Accessor)> {
+    let mut accessors = primitive.attributes(graph);
+
+    for target in primitive.morph_targets(graph) {
+        accessors.extend(target.attributes(graph));
+    }
+
+    accessors
+}
+
+/// Whether an attribute can be transformed by [`transform_attribute`].
+pub fn is_transformable(graph: &Graph, semantic: &Semantic, accessor: Accessor) -> bool {
+    let weight = accessor.get(graph);
+
+    let element_type = match semantic {
+        Semantic::Positions | Semantic::Normals => Type::Vec3,
+        Semantic::Tangents => Type::Vec4,
+        _ => return true,
+    };
+
+    weight.component_type == ComponentType::F32 && weight.element_type == element_type
+}
+
+/// Creates a copy of a position, normal or tangent accessor, transformed by a matrix.
+/// Other attributes are returned unchanged.
+pub fn transform_attribute(
+    graph: &mut Graph,
+    doc: GltfDocument,
+    semantic: &Semantic,
+    accessor: Accessor,
+    matrix: Mat4,
+) -> Result<Accessor, GeometryError> {
+    if matrix == Mat4::IDENTITY || !is_transformable(graph, semantic, accessor) {
+        return Ok(accessor);
+    }
+
+    let normal_matrix = matrix.inverse().transpose();
+    let handedness = matrix.determinant().signum();
+
+    let data = match (semantic, accessor.to_iter(graph)?) {
+        (Semantic::Positions, AccessorIter::F32x3(iter)) => iter
+            .flat_map(|v| matrix.transform_point3(Vec3::from(v)).to_array())
+            .flat_map(f32::to_le_bytes)
+            .collect::<Vec<_>>(),
+        (Semantic::Normals, AccessorIter::F32x3(iter)) => iter
+            .flat_map(|v| {
+                normal_matrix
+                    .transform_vector3(Vec3::from(v))
+                    .normalize_or_zero()
+                    .to_array()
+            })
+            .flat_map(f32::to_le_bytes)
+            .collect(),
+        (Semantic::Tangents, AccessorIter::F32x4(iter)) => iter
+            .flat_map(|v| {
+                let v = Vec4::from(v);
+                matrix
+                    .transform_vector3(v.truncate())
+                    .normalize_or_zero()
+                    .extend(v.w * handedness)
+                    .to_array()
+            })
+            .flat_map(f32::to_le_bytes)
+            .collect(),
+        _ => return Ok(accessor),
+    };
+
+    Ok(copy_accessor(graph, doc, accessor, data))
+}
+
+/// Converts a vertex index, which glTF limits to 32 bits.
+pub fn index_u32(index: usize) -> u32 {
+    u32::try_from(index).expect("vertex index should fit in u32")
+}
+
+#[cfg(test)]
+pub mod test_utils {
+    use crate::graph::gltf::accessor::iter::AccessorIter;
+
+    use super::*;
+
+    pub fn create_positions(
+        graph: &mut Graph,
+        doc: GltfDocument,
+        positions: &[[f32; 3]],
+    ) -> Accessor {
+        let mut accessor = doc.create_accessor(graph);
+        let weight = accessor.get_mut(graph);
+        weight.element_type = gltf::json::accessor::Type::Vec3;
+        weight.data = positions
+            .iter()
+            .flatten()
+            .flat_map(|v| v.to_le_bytes())
+            .collect();
+        accessor
+    }
+
+    pub fn read_positions(graph: &Graph, primitive: Primitive) -> Vec<[f32; 3]> {
+        let accessor = primitive
+            .attribute(graph, Semantic::Positions)
+            .expect("primitive should have positions");
+
+        match accessor
+            .to_iter(graph)
+            .expect("accessor should be readable")
+        {
+            AccessorIter::F32x3(iter) => iter.collect(),
+            _ => panic!("positions should be f32x3"),
+        }
+    }
+}
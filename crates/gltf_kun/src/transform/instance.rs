@@ -0,0 +1,380 @@
+//! Replace nodes sharing a mesh with a single instanced node.
+
+use std::{
+    collections::{HashMap, HashSet, hash_map::Entry},
+    error::Error,
+};
+
+use bevy_math::{Mat4, Quat, Vec3};
+use gltf::json::accessor::Type;
+use tracing::debug;
+
+use crate::{
+    extensions::ext_mesh_gpu_instancing::{ExtMeshGpuInstancing, ROTATION, SCALE, TRANSLATION},
+    graph::{
+        Extensions, Graph, GraphNodeWeight,
+        gltf::{Buffer, Mesh, Node, Scene, document::GltfDocument, node::decompose_trs},
+    },
+};
+
+use super::{Transform, geometry::create_f32_accessor, has_extensions, is_hierarchy_only};
+
+/// Replaces static nodes that share a mesh with a single node using the
+/// [`EXT_mesh_gpu_instancing`](crate::extensions::ext_mesh_gpu_instancing) extension.
+///
+/// A node is static if it and its ancestors are only referenced by their parent,
+/// scene or document and have no extensions, and it has no skin or morph weights.
+/// Nodes whose world transform cannot be represented as TRS (such as with shear),
+/// or that are reachable from more than one scene, are skipped.
+/// The instanced node is added to the scene root, with each instance storing
+/// the world transform of the node it replaces.
+/// Replaced nodes keep their children but lose their mesh, and can be removed with
+/// [`Prune`](super::prune::Prune) if left empty.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Instance {
+    /// Minimum number of nodes sharing a mesh for them to be instanced.
+    pub min_instances: usize,
+}
+
+impl Default for Instance {
+    fn default() -> Self {
+        Self { min_instances: 5 }
+    }
+}
+
+impl Transform for Instance {
+    fn name(&self) -> &'static str {
+        "instance"
+    }
+
+    fn apply(&self, graph: &mut Graph, doc: GltfDocument) -> Result<(), Box<dyn Error>> {
+        let buffer = doc.buffers(graph).first().copied();
+        let shared = shared_nodes(graph, doc);
+
+        for scene in doc.scenes(graph) {
+            for (mesh, instances) in mesh_instances(graph, scene, &shared) {
+                if instances.len() < self.min_instances.max(1) {
+                    continue;
+                }
+
+                debug!("Instancing {} nodes", instances.len());
+
+                let mut node = doc.create_node(graph);
+                let name = mesh.get(graph).name.clone();
+                node.get_mut(graph).name = name;
+                node.set_mesh(graph, Some(mesh));
+                scene.add_node(graph, node);
+
+                let ext = node.create_extension::<ExtMeshGpuInstancing>(graph);
+                write_instances(graph, doc, buffer, ext, &instances);
+
+                for (instance, _) in instances {
+                    instance.set_mesh(graph, None);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Scale, rotation and translation of an instance.
+type Trs = (Vec3, Quat, Vec3);
+
+/// Nodes reachable from more than one scene.
+fn shared_nodes(graph: &Graph, doc: GltfDocument) -> HashSet<Node> {
+    let mut seen = HashSet::new();
+    let mut shared = HashSet::new();
+
+    for scene in doc.scenes(graph) {
+        let mut reached = HashSet::new();
+        let mut stack = scene.nodes(graph);
+
+        while let Some(node) = stack.pop() {
+            if reached.insert(node) {
+                stack.extend(node.children(graph));
+            }
+        }
+
+        for node in reached {
+            if !seen.insert(node) {
+                shared.insert(node);
+            }
+        }
+    }
+
+    shared
+}
+
+/// Groups the static mesh nodes of a scene by mesh, along with their world transforms.
+fn mesh_instances(
+    graph: &Graph,
+    scene: Scene,
+    shared: &HashSet<Node>,
+) -> Vec<(Mesh, Vec<(Node, Trs)>)> {
+    let mut visited = HashSet::new();
+    let mut keys = HashMap::<Mesh, usize>::new();
+    let mut groups = Vec::<(Mesh, Vec<(Node, Trs)>)>::new();
+
+    let mut stack = scene
+        .nodes(graph)
+        .into_iter()
+        .rev()
+        .map(|node| (node, Mat4::IDENTITY, false))
+        .collect::<Vec<_>>();
+
+    while let Some((node, parent_world, pinned)) = stack.pop() {
+        let world = parent_world * node.get(graph).local_matrix();
+        let pinned = pinned || !is_hierarchy_only(graph, node.0) || has_extensions(graph, node.0);
+
+        if !pinned
+            && !shared.contains(&node)
+            && visited.insert(node)
+            && let Some(mesh) = node.mesh(graph)
+            && node.skin(graph).is_none()
+            && node.get(graph).weights.is_empty()
+            && let Some(trs) = decompose_trs(world)
+        {
+            match keys.entry(mesh) {
+                Entry::Occupied(entry) => groups[*entry.get()].1.push((node, trs)),
+                Entry::Vacant(entry) => {
+                    entry.insert(groups.len());
+                    groups.push((mesh, vec![(node, trs)]));
+                }
+            }
+        }
+
+        for child in node.children(graph).into_iter().rev() {
+            stack.push((child, world, pinned));
+        }
+    }
+
+    groups
+}
+
+/// Writes instance transforms to the extension.
+/// Rotation and scale are omitted if every instance has the identity.
+fn write_instances(
+    graph: &mut Graph,
+    doc: GltfDocument,
+    buffer: Option<Buffer>,
+    ext: ExtMeshGpuInstancing,
+    instances: &[(Node, Trs)],
+) {
+    let (scales, (rotations, translations)): (Vec<Vec3>, (Vec<Quat>, Vec<Vec3>)) = instances
+        .iter()
+        .map(|&(_, (scale, rotation, translation))| (scale, (rotation, translation)))
+        .unzip();
+
+    let data = translations
+        .iter()
+        .flat_map(Vec3::to_array)
+        .collect::<Vec<_>>();
+    let accessor = create_f32_accessor(graph, doc, buffer, Type::Vec3, &data);
+    ext.set_attribute(graph, TRANSLATION, Some(accessor));
+
+    if rotations.iter().any(|r| *r != Quat::IDENTITY) {
+        let data = rotations
+            .iter()
+            .flat_map(Quat::to_array)
+            .collect::<Vec<_>>();
+        let accessor = create_f32_accessor(graph, doc, buffer, Type::Vec4, &data);
+        ext.set_attribute(graph, ROTATION, Some(accessor));
+    }
+
+    if scales.iter().any(|s| *s != Vec3::ONE) {
+        let data = scales.iter().flat_map(Vec3::to_array).collect::<Vec<_>>();
+        let accessor = create_f32_accessor(graph, doc, buffer, Type::Vec3, &data);
+        ext.set_attribute(graph, SCALE, Some(accessor));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::gltf::accessor::iter::AccessorIter;
+
+    use super::*;
+
+    fn create_node(graph: &mut Graph, doc: GltfDocument, parent: Node, mesh: Mesh, x: f32) -> Node {
+        let mut node = doc.create_node(graph);
+        node.get_mut(graph).translation = Vec3::new(x, 0.0, 0.0);
+        node.set_mesh(graph, Some(mesh));
+        parent.add_child(graph, &node);
+        node
+    }
+
+    #[test]
+    fn instance() {
+        let mut graph = Graph::default();
+        let doc = GltfDocument::new(&mut graph);
+        let scene = doc.create_scene(&mut graph);
+
+        let mut root = doc.create_node(&mut graph);
+        root.get_mut(&mut graph).translation = Vec3::new(0.0, 1.0, 0.0);
+        scene.add_node(&mut graph, root);
+
+        let mesh = doc.create_mesh(&mut graph);
+        let other = doc.create_mesh(&mut graph);
+
+        let nodes = [1.0, 2.0, 3.0]
+            .map(|x| create_node(&mut graph, doc, root, mesh, x))
+            .to_vec();
+        let single = create_node(&mut graph, doc, root, other, 4.0);
+
+        let skinned = create_node(&mut graph, doc, root, mesh, 5.0);
+        let skin = doc.create_skin(&mut graph);
+        skinned.set_skin(&mut graph, Some(skin));
+
+        Instance { min_instances: 2 }
+            .apply(&mut graph, doc)
+            .expect("instance should succeed");
+
+        for node in &nodes {
+            assert!(node.mesh(&graph).is_none());
+        }
+        assert_eq!(single.mesh(&graph), Some(other));
+        assert_eq!(skinned.mesh(&graph), Some(mesh));
+
+        let roots = scene.nodes(&graph);
+        assert_eq!(roots.len(), 2);
+
+        let instanced = roots[1];
+        assert_eq!(instanced.mesh(&graph), Some(mesh));
+
+        let ext = instanced
+            .get_extension::<ExtMeshGpuInstancing>(&graph)
+            .expect("instanced node should have the extension");
+        assert!(ext.attribute(&graph, ROTATION).is_none());
+        assert!(ext.attribute(&graph, SCALE).is_none());
+
+        let translations = ext
+            .attribute(&graph, TRANSLATION)
+            .expect("instances should have translations");
+        match translations
+            .to_iter(&graph)
+            .expect("accessor should be readable")
+        {
+            AccessorIter::F32x3(iter) => assert_eq!(
+                iter.collect::<Vec<_>>(),
+                vec![[1.0, 1.0, 0.0], [2.0, 1.0, 0.0], [3.0, 1.0, 0.0]]
+            ),
+            _ => panic!("translations should be f32x3"),
+        }
+    }
+
+    #[test]
+    fn instance_threshold() {
+        let mut graph = Graph::default();
+        let doc = GltfDocument::new(&mut graph);
+        let scene = doc.create_scene(&mut graph);
+        let root = doc.create_node(&mut graph);
+        scene.add_node(&mut graph, root);
+
+        let mesh = doc.create_mesh(&mut graph);
+        create_node(&mut graph, doc, root, mesh, 1.0);
+        create_node(&mut graph, doc, root, mesh, 2.0);
+
+        Instance::default()
+            .apply(&mut graph, doc)
+            .expect("instance should succeed");
+
+        assert_eq!(scene.nodes(&graph), vec![root]);
+        assert_eq!(doc.accessors(&graph).len(), 0);
+    }
+
+    #[cfg(feature = "khr_node_visibility")]
+    #[test]
+    fn instance_hidden() {
+        use crate::{
+            extensions::khr_node_visibility::{KhrNodeVisibility, weight::NodeVisibilityWeight},
+            graph::ByteNode,
+        };
+
+        let mut graph = Graph::default();
+        let doc = GltfDocument::new(&mut graph);
+        let scene = doc.create_scene(&mut graph);
+        let hidden = doc.create_node(&mut graph);
+        scene.add_node(&mut graph, hidden);
+
+        let ext = hidden.create_extension::<KhrNodeVisibility>(&mut graph);
+        ext.write(&mut graph, &NodeVisibilityWeight { visible: false });
+
+        let mesh = doc.create_mesh(&mut graph);
+        create_node(&mut graph, doc, hidden, mesh, 1.0);
+        create_node(&mut graph, doc, hidden, mesh, 2.0);
+
+        Instance { min_instances: 2 }
+            .apply(&mut graph, doc)
+            .expect("instance should succeed");
+
+        assert_eq!(scene.nodes(&graph), vec![hidden]);
+        assert_eq!(doc.accessors(&graph).len(), 0);
+    }
+
+    #[test]
+    fn instance_shear() {
+        let mut graph = Graph::default();
+        let doc = GltfDocument::new(&mut graph);
+        let scene = doc.create_scene(&mut graph);
+
+        let mut root = doc.create_node(&mut graph);
+        let weight = root.get_mut(&mut graph);
+        weight.rotation = Quat::from_rotation_x(1.0);
+        weight.scale = Vec3::new(2.0, 1.0, 1.0);
+        scene.add_node(&mut graph, root);
+
+        let mesh = doc.create_mesh(&mut graph);
+        let upright = [1.0, 2.0].map(|x| create_node(&mut graph, doc, root, mesh, x));
+        let sheared = [3.0, 4.0].map(|x| {
+            let mut node = create_node(&mut graph, doc, root, mesh, x);
+            node.get_mut(&mut graph).rotation = Quat::from_rotation_z(1.0);
+            node
+        });
+
+        Instance { min_instances: 2 }
+            .apply(&mut graph, doc)
+            .expect("instance should succeed");
+
+        for node in upright {
+            assert!(node.mesh(&graph).is_none());
+        }
+        for node in sheared {
+            assert_eq!(node.mesh(&graph), Some(mesh));
+        }
+
+        let ext = scene.nodes(&graph)[1]
+            .get_extension::<ExtMeshGpuInstancing>(&graph)
+            .expect("instanced node should have the extension");
+        let scales = ext
+            .attribute(&graph, SCALE)
+            .expect("instances should have scales");
+        assert_eq!(scales.count(&graph), 2);
+    }
+
+    #[test]
+    fn instance_shared_scene() {
+        let mut graph = Graph::default();
+        let doc = GltfDocument::new(&mut graph);
+        let root = doc.create_node(&mut graph);
+
+        let scenes = [(); 2].map(|()| {
+            let scene = doc.create_scene(&mut graph);
+            scene.add_node(&mut graph, root);
+            scene
+        });
+
+        let mesh = doc.create_mesh(&mut graph);
+        let nodes = [1.0, 2.0].map(|x| create_node(&mut graph, doc, root, mesh, x));
+
+        Instance { min_instances: 2 }
+            .apply(&mut graph, doc)
+            .expect("instance should succeed");
+
+        for node in nodes {
+            assert_eq!(node.mesh(&graph), Some(mesh));
+        }
+        for scene in scenes {
+            assert_eq!(scene.nodes(&graph), vec![root]);
+        }
+    }
+}
@@ -0,0 +1,458 @@
+//! Merge compatible primitives to reduce draw calls.
+
+use std::{
+    collections::{HashMap, HashSet, hash_map::Entry},
+    error::Error,
+};
+
+use bevy_math::{Quat, Vec3};
+use petgraph::Direction;
+use tracing::debug;
+
+use crate::graph::{
+    Edge, Graph, GraphNodeWeight,
+    gltf::{
+        GltfEdge, Material, Mesh, Node, Primitive,
+        document::GltfDocument,
+        mesh::MeshEdge,
+        node::NodeEdge,
+        primitive::{Mode, Semantic},
+    },
+};
+
+use super::{
+    Transform,
+    geometry::{
+        copy_accessor, create_indices, index_u32, is_transformable, read_indices_or_sequential,
+        transform_attribute, vertex_count,
+    },
+    has_extensions, is_hierarchy_only,
+};
+
+/// Merges primitives within a mesh that share a material, mode and attribute layout.
+///
+/// Only point, line and triangle list primitives without morph targets or extensions
+/// are joined. Replaced accessors are left in the document, and can be removed with
+/// [`Prune`](super::prune::Prune).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Join {
+    /// Also join the meshes of static sibling nodes, baking node transforms into
+    /// vertex positions, normals and tangents.
+    ///
+    /// A node is static if it has no children, skin, morph weights or extensions,
+    /// and is not referenced by anything other than its parent, scene or document.
+    /// Nodes with a singular transform, or a mirroring transform and triangle
+    /// strips or fans, are skipped. Triangle winding is reversed for mirrored nodes.
+    /// The first node of each group receives the joined mesh and an identity transform,
+    /// the others are left empty.
+    pub across_nodes: bool,
+}
+
+impl Transform for Join {
+    fn name(&self) -> &'static str {
+        "join"
+    }
+
+    fn apply(&self, graph: &mut Graph, doc: GltfDocument) -> Result<(), Box<dyn Error>> {
+        if self.across_nodes {
+            for siblings in sibling_groups(graph, doc) {
+                let nodes = siblings
+                    .into_iter()
+                    .filter(|node| is_joinable_node(graph, *node))
+                    .collect::<Vec<_>>();
+
+                if nodes.len() > 1 {
+                    join_nodes(graph, doc, &nodes)?;
+                }
+            }
+        }
+
+        for mesh in doc.meshes(graph) {
+            join_mesh(graph, doc, mesh)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Properties that must match for primitives to be joined.
+#[derive(PartialEq, Eq, Hash)]
+struct PrimitiveKey {
+    material: Option<Material>,
+    mode: u32,
+    attributes: Vec<(Semantic, u32, u8, bool)>,
+}
+
+impl PrimitiveKey {
+    fn new(graph: &Graph, primitive: Primitive) -> Option<Self> {
+        let mode = primitive.get(graph).mode;
+
+        if !matches!(mode, Mode::Points | Mode::Lines | Mode::Triangles)
+            || !primitive.morph_targets(graph).is_empty()
+            || has_extensions(graph, primitive.0)
+            || vertex_count(graph, primitive).is_none()
+        {
+            return None;
+        }
+
+        let mut attributes = primitive
+            .attributes(graph)
+            .into_iter()
+            .map(|(semantic, accessor)| {
+                let weight = accessor.get(graph);
+                (
+                    semantic,
+                    weight.component_type.as_gl_enum(),
+                    weight.element_type as u8,
+                    weight.normalized,
+                )
+            })
+            .collect::<Vec<_>>();
+        attributes.sort();
+
+        Some(Self {
+            material: primitive.material(graph),
+            mode: mode.as_gl_enum(),
+            attributes,
+        })
+    }
+}
+
+fn join_mesh(graph: &mut Graph, doc: GltfDocument, mesh: Mesh) -> Result<(), Box<dyn Error>> {
+    let mut keys = HashMap::<PrimitiveKey, usize>::new();
+    let mut groups = Vec::<Vec<Primitive>>::new();
+
+    for primitive in mesh.primitives(graph) {
+        let Some(key) = PrimitiveKey::new(graph, primitive) else {
+            continue;
+        };
+
+        match keys.entry(key) {
+            Entry::Occupied(entry) => groups[*entry.get()].push(primitive),
+            Entry::Vacant(entry) => {
+                entry.insert(groups.len());
+                groups.push(vec![primitive]);
+            }
+        }
+    }
+
+    for group in groups.into_iter().filter(|group| group.len() > 1) {
+        debug!("Joining {} primitives", group.len());
+        join_primitives(graph, doc, mesh, &group)?;
+    }
+
+    Ok(())
+}
+
+/// Concatenates the vertices of each primitive into the first, offsetting indices.
+fn join_primitives(
+    graph: &mut Graph,
+    doc: GltfDocument,
+    mesh: Mesh,
+    group: &[Primitive],
+) -> Result<(), Box<dyn Error>> {
+    let first = group[0];
+
+    let mut indices = Vec::new();
+    let mut offset = 0;
+
+    for primitive in group {
+        let count = vertex_count(graph, *primitive).expect("primitive should have vertices");
+        let primitive_indices = read_indices_or_sequential(graph, *primitive, count)?;
+        indices.extend(primitive_indices.into_iter().map(|i| i + offset));
+        offset += index_u32(count);
+    }
+
+    for (semantic, accessor) in first.attributes(graph) {
+        let data = group
+            .iter()
+            .flat_map(|primitive| {
+                let accessor = primitive
+                    .attribute(graph, semantic.clone())
+                    .expect("attribute layouts should match");
+                accessor.get(graph).data.iter().copied()
+            })
+            .collect();
+
+        let joined = copy_accessor(graph, doc, accessor, data);
+        first.set_attribute(graph, semantic, Some(joined));
+    }
+
+    let indices = create_indices(graph, doc, &indices, offset as usize);
+    first.set_indices(graph, Some(indices));
+
+    for primitive in &group[1..] {
+        mesh.remove_primitive(graph, primitive);
+        graph.remove_node(primitive.0);
+    }
+
+    Ok(())
+}
+
+/// Moves the primitives of each node into a new mesh on the first node,
+/// baking node transforms into the vertices.
+fn join_nodes(graph: &mut Graph, doc: GltfDocument, nodes: &[Node]) -> Result<(), Box<dyn Error>> {
+    debug!("Joining {} sibling nodes", nodes.len());
+
+    let mesh = doc.create_mesh(graph);
+
+    for node in nodes {
+        let matrix = node.get(graph).local_matrix();
+        let mirrored = matrix.determinant() < 0.0;
+        let source = node.mesh(graph).expect("joinable node should have a mesh");
+
+        for primitive in source.primitives(graph) {
+            let mut copy = mesh.create_primitive(graph);
+            *copy.get_mut(graph) = primitive.get(graph).clone();
+            copy.set_material(graph, primitive.material(graph));
+            copy.set_indices(graph, primitive.indices(graph));
+
+            for (semantic, accessor) in primitive.attributes(graph) {
+                let accessor = transform_attribute(graph, doc, &semantic, accessor, matrix)?;
+                copy.set_attribute(graph, semantic, Some(accessor));
+            }
+
+            if mirrored && copy.get(graph).mode == Mode::Triangles {
+                flip_winding(graph, doc, copy)?;
+            }
+        }
+
+        node.set_mesh(graph, None);
+    }
+
+    let mut first = nodes[0];
+    first
+        .get_mut(graph)
+        .set_trs(Vec3::ZERO, Quat::IDENTITY, Vec3::ONE);
+    first.set_mesh(graph, Some(mesh));
+
+    Ok(())
+}
+
+/// Reverses the winding order of a triangle list.
+fn flip_winding(
+    graph: &mut Graph,
+    doc: GltfDocument,
+    primitive: Primitive,
+) -> Result<(), Box<dyn Error>> {
+    let count = vertex_count(graph, primitive).expect("primitive should have vertices");
+    let mut indices = read_indices_or_sequential(graph, primitive, count)?;
+
+    for triangle in indices.chunks_exact_mut(3) {
+        triangle.swap(1, 2);
+    }
+
+    let indices = create_indices(graph, doc, &indices, count);
+    primitive.set_indices(graph, Some(indices));
+
+    Ok(())
+}
+
+/// Root nodes of each scene, followed by the children of each node.
+fn sibling_groups(graph: &Graph, doc: GltfDocument) -> Vec<Vec<Node>> {
+    let mut visited = HashSet::new();
+    let mut groups = Vec::new();
+    let mut stack = Vec::new();
+
+    for scene in doc.scenes(graph) {
+        let nodes = scene.nodes(graph);
+        stack.extend(nodes.iter().copied());
+        groups.push(nodes);
+    }
+
+    while let Some(node) = stack.pop() {
+        if visited.insert(node) {
+            let children = node.children(graph);
+            stack.extend(children.iter().copied());
+            groups.push(children);
+        }
+    }
+
+    groups
+}
+
+fn is_joinable_node(graph: &Graph, node: Node) -> bool {
+    let Some(mesh) = node.mesh(graph) else {
+        return false;
+    };
+
+    let outgoing = graph
+        .edges_directed(node.0, Direction::Outgoing)
+        .all(|edge| matches!(edge.weight(), Edge::Gltf(GltfEdge::Node(NodeEdge::Mesh))));
+
+    let mesh_edges = graph
+        .edges_directed(mesh.0, Direction::Outgoing)
+        .all(|edge| {
+            matches!(
+                edge.weight(),
+                Edge::Gltf(GltfEdge::Mesh(MeshEdge::Primitive))
+            )
+        });
+
+    let determinant = node.get(graph).local_matrix().determinant();
+
+    outgoing
+        && is_hierarchy_only(graph, node.0)
+        && mesh_edges
+        && node.get(graph).weights.is_empty()
+        && determinant.is_normal()
+        && mesh.primitives(graph).into_iter().all(|primitive| {
+            let mode = primitive.get(graph).mode;
+
+            (determinant > 0.0 || !matches!(mode, Mode::TriangleStrip | Mode::TriangleFan))
+                && primitive.morph_targets(graph).is_empty()
+                && !has_extensions(graph, primitive.0)
+                && primitive
+                    .attributes(graph)
+                    .iter()
+                    .all(|(semantic, accessor)| is_transformable(graph, semantic, *accessor))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::geometry::{
+            read_indices,
+            test_utils::{create_positions, read_positions},
+        },
+        *,
+    };
+
+    fn create_triangle(graph: &mut Graph, doc: GltfDocument, mesh: Mesh) -> Primitive {
+        let positions = create_positions(
+            graph,
+            doc,
+            &[[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+        );
+
+        let primitive = mesh.create_primitive(graph);
+        primitive.set_attribute(graph, Semantic::Positions, Some(positions));
+        primitive
+    }
+
+    #[test]
+    fn join_primitives() {
+        let mut graph = Graph::default();
+        let doc = GltfDocument::new(&mut graph);
+
+        let mesh = doc.create_mesh(&mut graph);
+        let a = create_triangle(&mut graph, doc, mesh);
+        create_triangle(&mut graph, doc, mesh);
+        let c = create_triangle(&mut graph, doc, mesh);
+
+        let material = doc.create_material(&mut graph);
+        c.set_material(&mut graph, Some(material));
+
+        Join::default()
+            .apply(&mut graph, doc)
+            .expect("join should succeed");
+
+        assert_eq!(mesh.primitives(&graph), vec![a, c]);
+        assert_eq!(read_positions(&graph, a).len(), 6);
+        assert_eq!(read_positions(&graph, c).len(), 3);
+
+        let indices = a
+            .indices(&graph)
+            .expect("joined primitive should be indexed");
+        assert_eq!(
+            read_indices(&graph, indices).expect("indices are readable"),
+            vec![0, 1, 2, 3, 4, 5]
+        );
+    }
+
+    #[test]
+    fn join_nodes() {
+        let mut graph = Graph::default();
+        let doc = GltfDocument::new(&mut graph);
+        let scene = doc.create_scene(&mut graph);
+
+        let mesh = doc.create_mesh(&mut graph);
+        create_triangle(&mut graph, doc, mesh);
+
+        let mut nodes = Vec::new();
+
+        for x in [0.0, 2.0] {
+            let mut node = doc.create_node(&mut graph);
+            node.get_mut(&mut graph).translation = Vec3::new(x, 0.0, 0.0);
+            node.set_mesh(&mut graph, Some(mesh));
+            scene.add_node(&mut graph, node);
+            nodes.push(node);
+        }
+
+        let mut skinned = doc.create_node(&mut graph);
+        skinned.get_mut(&mut graph).translation = Vec3::new(4.0, 0.0, 0.0);
+        skinned.set_mesh(&mut graph, Some(mesh));
+        let skin = doc.create_skin(&mut graph);
+        skinned.set_skin(&mut graph, Some(skin));
+        scene.add_node(&mut graph, skinned);
+
+        Join { across_nodes: true }
+            .apply(&mut graph, doc)
+            .expect("join should succeed");
+
+        let joined = nodes[0]
+            .mesh(&graph)
+            .expect("first node should have a mesh");
+        assert!(nodes[1].mesh(&graph).is_none());
+        assert_eq!(skinned.mesh(&graph), Some(mesh));
+        assert_eq!(nodes[0].get(&graph).translation, Vec3::ZERO);
+
+        let primitives = joined.primitives(&graph);
+        assert_eq!(primitives.len(), 1);
+        assert_eq!(
+            read_positions(&graph, primitives[0]),
+            vec![
+                [0.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0],
+                [2.0, 0.0, 0.0],
+                [3.0, 0.0, 0.0],
+                [2.0, 1.0, 0.0]
+            ]
+        );
+    }
+
+    #[test]
+    fn join_mirrored() {
+        let mut graph = Graph::default();
+        let doc = GltfDocument::new(&mut graph);
+        let scene = doc.create_scene(&mut graph);
+
+        let mesh = doc.create_mesh(&mut graph);
+        create_triangle(&mut graph, doc, mesh);
+
+        let nodes = [Vec3::ONE, Vec3::new(-1.0, 1.0, 1.0), Vec3::ZERO].map(|scale| {
+            let mut node = doc.create_node(&mut graph);
+            node.get_mut(&mut graph).scale = scale;
+            node.set_mesh(&mut graph, Some(mesh));
+            scene.add_node(&mut graph, node);
+            node
+        });
+
+        Join { across_nodes: true }
+            .apply(&mut graph, doc)
+            .expect("join should succeed");
+
+        assert!(nodes[1].mesh(&graph).is_none());
+        assert_eq!(nodes[2].mesh(&graph), Some(mesh));
+
+        let joined = nodes[0]
+            .mesh(&graph)
+            .expect("first node should have a mesh");
+        let primitives = joined.primitives(&graph);
+        assert_eq!(primitives.len(), 1);
+        assert_eq!(
+            read_positions(&graph, primitives[0])[3..],
+            [[0.0, 0.0, 0.0], [-1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]
+        );
+
+        let indices = primitives[0]
+            .indices(&graph)
+            .expect("joined primitive should be indexed");
+        assert_eq!(
+            read_indices(&graph, indices).expect("indices are readable"),
+            vec![0, 1, 2, 3, 5, 4]
+        );
+    }
+}
@@ -0,0 +1,239 @@
+//! Document transforms.
+//!
+//! A [`Transform`] modifies a document in place.
+//! Transforms can be run on their own, or chained together in a [`Pipeline`].
+//!
+//! ```
+//! use gltf_kun::{
+//!     graph::{Graph, gltf::document::GltfDocument},
+//!     transform::{Pipeline, from_fn},
+//! };
+//!
+//! let mut graph = Graph::default();
+//! let doc = GltfDocument::new(&mut graph);
+//!
+//! let reports = Pipeline::default()
+//!     .with(from_fn("add_scene", |graph, doc| {
+//!         doc.create_scene(graph);
+//!         Ok(())
+//!     }))
+//!     .run(&mut graph, doc)
+//!     .expect("pipeline should succeed");
+//!
+//! assert_eq!(doc.scenes(&graph).len(), 1);
+//! assert_eq!(reports[0].name, "add_scene");
+//! ```
+
+use std::{
+    error::Error,
+    time::{Duration, Instant},
+};
+
+use petgraph::{Direction, stable_graph::NodeIndex};
+use thiserror::Error;
+use tracing::{debug, info};
+
+use crate::graph::{
+    Edge, Graph,
+    gltf::{GltfEdge, document::GltfDocument, node::NodeEdge},
+};
+
+pub mod dedup;
+pub mod flatten;
+mod geometry;
+#[cfg(feature = "ext_mesh_gpu_instancing")]
+pub mod instance;
+pub mod join;
+pub mod normals;
+pub mod prune;
+pub mod reorder;
+pub mod simplify;
+pub mod tangents;
+pub mod unweld;
+pub mod weld;
+
+/// An operation on a document.
+pub trait Transform: Send + Sync {
+    /// Name of the transform, used in logs and reports.
+    fn name(&self) -> &str;
+
+    fn apply(&self, graph: &mut Graph, doc: GltfDocument) -> Result<(), Box<dyn Error>>;
+}
+
+/// A [`Transform`] from a closure.
+pub struct FnTransform<F> {
+    name: String,
+    f: F,
+}
+
+impl<F> Transform for FnTransform<F>
+where
+    F: Fn(&mut Graph, GltfDocument) -> Result<(), Box<dyn Error>> + Send + Sync,
+{
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn apply(&self, graph: &mut Graph, doc: GltfDocument) -> Result<(), Box<dyn Error>> {
+        (self.f)(graph, doc)
+    }
+}
+
+/// Creates a [`Transform`] from a closure.
+pub fn from_fn<F>(name: impl Into<String>, f: F) -> FnTransform<F>
+where
+    F: Fn(&mut Graph, GltfDocument) -> Result<(), Box<dyn Error>> + Send + Sync,
+{
+    FnTransform {
+        name: name.into(),
+        f,
+    }
+}
+
+/// Timing of a completed pipeline step.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StepReport {
+    pub name: String,
+    pub duration: Duration,
+}
+
+#[derive(Debug, Error)]
+#[error("transform {name} failed")]
+pub struct PipelineError {
+    /// Name of the failed transform.
+    pub name: String,
+    #[source]
+    pub source: Box<dyn Error>,
+    /// Steps completed before the failure.
+    pub completed: Vec<StepReport>,
+}
+
+/// An ordered list of transforms.
+#[derive(Default)]
+pub struct Pipeline {
+    steps: Vec<Box<dyn Transform>>,
+}
+
+impl Pipeline {
+    /// Adds a transform to the end of the pipeline.
+    #[must_use]
+    pub fn with(mut self, transform: impl Transform + 'static) -> Self {
+        self.add(transform);
+        self
+    }
+
+    /// Adds a transform to the end of the pipeline.
+    pub fn add(&mut self, transform: impl Transform + 'static) -> &mut Self {
+        self.steps.push(Box::new(transform));
+        self
+    }
+
+    /// Names of the transforms, in order.
+    pub fn names(&self) -> Vec<&str> {
+        self.steps.iter().map(|t| t.name()).collect()
+    }
+
+    /// Runs each transform in order, stopping at the first failure.
+    pub fn run(
+        &self,
+        graph: &mut Graph,
+        doc: GltfDocument,
+    ) -> Result<Vec<StepReport>, PipelineError> {
+        let mut completed = Vec::with_capacity(self.steps.len());
+
+        for transform in &self.steps {
+            let name = transform.name().to_string();
+            debug!("Running transform: {}", name);
+
+            let start = Instant::now();
+
+            if let Err(source) = transform.apply(graph, doc) {
+                return Err(PipelineError {
+                    name,
+                    source,
+                    completed,
+                });
+            }
+
+            let duration = start.elapsed();
+            info!("Transform {} completed in {:?}", name, duration);
+
+            completed.push(StepReport { name, duration });
+        }
+
+        Ok(completed)
+    }
+}
+
+/// Whether a graph node has extensions or other attached data.
+fn has_extensions(graph: &Graph, index: NodeIndex) -> bool {
+    graph
+        .edges_directed(index, Direction::Outgoing)
+        .any(|edge| matches!(edge.weight(), Edge::Extension(_) | Edge::Other(_)))
+}
+
+/// Whether a node is only referenced by its parent, scene or document,
+/// and not by animations, skins or extensions.
+fn is_hierarchy_only(graph: &Graph, node: NodeIndex) -> bool {
+    graph.edges_directed(node, Direction::Incoming).all(|edge| {
+        matches!(
+            edge.weight(),
+            Edge::Gltf(
+                GltfEdge::Document(_) | GltfEdge::Scene(_) | GltfEdge::Node(NodeEdge::Child)
+            )
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pipeline_order() {
+        let mut graph = Graph::default();
+        let doc = GltfDocument::new(&mut graph);
+
+        let mut pipeline = Pipeline::default();
+        pipeline
+            .add(from_fn("first", |graph, doc| {
+                doc.create_node(graph);
+                Ok(())
+            }))
+            .add(from_fn("second", |graph, doc| {
+                assert_eq!(doc.nodes(graph).len(), 1);
+                doc.create_node(graph);
+                Ok(())
+            }));
+
+        assert_eq!(pipeline.names(), vec!["first", "second"]);
+
+        let reports = pipeline
+            .run(&mut graph, doc)
+            .expect("pipeline should succeed");
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[1].name, "second");
+        assert_eq!(doc.nodes(&graph).len(), 2);
+    }
+
+    #[test]
+    fn pipeline_error() {
+        let mut graph = Graph::default();
+        let doc = GltfDocument::new(&mut graph);
+
+        let err = Pipeline::default()
+            .with(from_fn("ok", |_, _| Ok(())))
+            .with(from_fn("fail", |_, _| Err("oops".into())))
+            .with(from_fn("unreachable", |_, _| panic!("should not run")))
+            .run(&mut graph, doc)
+            .expect_err("pipeline should fail");
+
+        assert_eq!(err.name, "fail");
+        assert_eq!(err.completed.len(), 1);
+        assert_eq!(err.to_string(), "transform fail failed");
+        assert_eq!(
+            err.source().map(ToString::to_string),
+            Some("oops".to_string())
+        );
+    }
+}
@@ -0,0 +1,311 @@
+//! Generate vertex normals.
+
+use std::{collections::HashMap, error::Error};
+
+use bevy_math::Vec3;
+use gltf::json::accessor::Type;
+use tracing::warn;
+
+use crate::graph::{
+    Graph, GraphNodeWeight,
+    gltf::{
+        Primitive,
+        accessor::iter::AccessorIter,
+        document::GltfDocument,
+        primitive::{Mode, Semantic},
+    },
+};
+
+use super::{
+    Transform,
+    geometry::{create_f32_accessor, read_indices_or_sequential, unweld_primitive, vertex_count},
+    weld::Weld,
+};
+
+/// Generates normals for triangle primitives from their positions.
+///
+/// Indexed primitives given flat normals are unwelded to split their vertices,
+/// then welded again.
+/// Replaced accessors are left in the document, and can be removed with
+/// [`Prune`](super::prune::Prune).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Normals {
+    /// Give each triangle its own normal, instead of averaging the normals
+    /// of every triangle sharing a vertex position.
+    pub flat: bool,
+    /// Replace existing normals.
+    pub overwrite: bool,
+}
+
+impl Transform for Normals {
+    fn name(&self) -> &'static str {
+        "normals"
+    }
+
+    fn apply(&self, graph: &mut Graph, doc: GltfDocument) -> Result<(), Box<dyn Error>> {
+        for mesh in doc.meshes(graph) {
+            for primitive in mesh.primitives(graph) {
+                if primitive.get(graph).mode != Mode::Triangles
+                    || (!self.overwrite && primitive.attribute(graph, Semantic::Normals).is_some())
+                {
+                    continue;
+                }
+
+                let Some(count) = vertex_count(graph, primitive) else {
+                    warn!("Primitive attributes have different lengths, skipping normals");
+                    continue;
+                };
+
+                let indices = read_indices_or_sequential(graph, primitive, count)?;
+
+                if indices.iter().any(|&i| i as usize >= count) {
+                    warn!("Primitive indices out of bounds, skipping normals");
+                    continue;
+                }
+
+                if indices.len() % 3 != 0 {
+                    warn!("Primitive is not a triangle list, skipping normals");
+                    continue;
+                }
+
+                let Some(positions) = read_positions(graph, primitive)? else {
+                    continue;
+                };
+
+                if self.flat {
+                    flat_normals(graph, doc, primitive, &positions, &indices)?;
+                } else {
+                    smooth_normals(graph, doc, primitive, &positions, &indices);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads the positions of a primitive, warning if they are missing or not f32.
+fn read_positions(
+    graph: &Graph,
+    primitive: Primitive,
+) -> Result<Option<Vec<Vec3>>, Box<dyn Error>> {
+    let Some(positions) = primitive.attribute(graph, Semantic::Positions) else {
+        warn!("Primitive has no positions, skipping normals");
+        return Ok(None);
+    };
+
+    let AccessorIter::F32x3(iter) = positions.to_iter(graph)? else {
+        warn!("Positions are not f32x3, skipping normals");
+        return Ok(None);
+    };
+
+    Ok(Some(iter.map(Vec3::from).collect()))
+}
+
+fn triangle_normal(a: Vec3, b: Vec3, c: Vec3) -> Vec3 {
+    (b - a).cross(c - a)
+}
+
+/// Averages the area-weighted normals of every triangle around each position.
+fn smooth_normals(
+    graph: &mut Graph,
+    doc: GltfDocument,
+    primitive: Primitive,
+    positions: &[Vec3],
+    indices: &[u32],
+) {
+    // Adding zero folds -0.0 into 0.0.
+    let keys = positions
+        .iter()
+        .map(|p| (*p + Vec3::ZERO).to_array().map(f32::to_bits))
+        .collect::<Vec<_>>();
+
+    let mut sums = HashMap::<[u32; 3], Vec3>::new();
+
+    for triangle in indices.chunks_exact(3) {
+        let [a, b, c] = [0, 1, 2].map(|i| triangle[i] as usize);
+        let normal = triangle_normal(positions[a], positions[b], positions[c]);
+
+        for i in [a, b, c] {
+            *sums.entry(keys[i]).or_default() += normal;
+        }
+    }
+
+    let data = keys
+        .iter()
+        .flat_map(|key| {
+            sums.get(key)
+                .copied()
+                .unwrap_or_default()
+                .normalize_or(Vec3::Z)
+                .to_array()
+        })
+        .collect::<Vec<_>>();
+
+    write_normals(graph, doc, primitive, &data);
+}
+
+/// Splits vertices so each triangle has its own normal.
+fn flat_normals(
+    graph: &mut Graph,
+    doc: GltfDocument,
+    primitive: Primitive,
+    positions: &[Vec3],
+    indices: &[u32],
+) -> Result<(), Box<dyn Error>> {
+    let data = indices
+        .chunks_exact(3)
+        .flat_map(|triangle| {
+            let [a, b, c] = [0, 1, 2].map(|i| positions[triangle[i] as usize]);
+            [triangle_normal(a, b, c).normalize_or(Vec3::Z).to_array(); 3]
+        })
+        .flatten()
+        .collect::<Vec<_>>();
+
+    let indexed = primitive.indices(graph).is_some();
+
+    if indexed {
+        unweld_primitive(graph, doc, primitive, indices);
+    }
+
+    write_normals(graph, doc, primitive, &data);
+
+    if indexed {
+        Weld::default().weld_primitive(graph, doc, primitive)?;
+    }
+
+    Ok(())
+}
+
+fn write_normals(graph: &mut Graph, doc: GltfDocument, primitive: Primitive, data: &[f32]) {
+    let buffer = primitive
+        .attribute(graph, Semantic::Positions)
+        .and_then(|positions| positions.buffer(graph));
+
+    let normals = create_f32_accessor(graph, doc, buffer, Type::Vec3, data);
+    primitive.set_attribute(graph, Semantic::Normals, Some(normals));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::geometry::{create_indices, test_utils::create_positions},
+        *,
+    };
+
+    fn read_normals(graph: &Graph, primitive: Primitive) -> Vec<[f32; 3]> {
+        let normals = primitive
+            .attribute(graph, Semantic::Normals)
+            .expect("primitive should have normals");
+
+        match normals.to_iter(graph).expect("accessor should be readable") {
+            AccessorIter::F32x3(iter) => iter.collect(),
+            _ => panic!("normals should be f32x3"),
+        }
+    }
+
+    /// Two triangles folded at a right angle along the y axis.
+    fn create_fold(graph: &mut Graph, doc: GltfDocument) -> Primitive {
+        let positions = create_positions(
+            graph,
+            doc,
+            &[
+                [0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0],
+                [1.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0],
+            ],
+        );
+        let indices = create_indices(graph, doc, &[0, 2, 1, 0, 1, 3], 4);
+
+        let primitive = doc.create_mesh(graph).create_primitive(graph);
+        primitive.set_attribute(graph, Semantic::Positions, Some(positions));
+        primitive.set_indices(graph, Some(indices));
+        primitive
+    }
+
+    #[test]
+    fn smooth_normals() {
+        let mut graph = Graph::default();
+        let doc = GltfDocument::new(&mut graph);
+        let primitive = create_fold(&mut graph, doc);
+
+        Normals::default()
+            .apply(&mut graph, doc)
+            .expect("normals should succeed");
+
+        let normals = read_normals(&graph, primitive);
+        let shared = Vec3::new(1.0, 0.0, 1.0).normalize().to_array();
+        assert_eq!(
+            normals,
+            vec![shared, shared, [0.0, 0.0, 1.0], [1.0, 0.0, 0.0]]
+        );
+
+        let existing = primitive.attribute(&graph, Semantic::Normals);
+
+        Normals {
+            flat: true,
+            overwrite: false,
+        }
+        .apply(&mut graph, doc)
+        .expect("normals should succeed");
+
+        assert_eq!(primitive.attribute(&graph, Semantic::Normals), existing);
+    }
+
+    #[test]
+    fn flat_normals() {
+        let mut graph = Graph::default();
+        let doc = GltfDocument::new(&mut graph);
+        let primitive = create_fold(&mut graph, doc);
+
+        Normals {
+            flat: true,
+            overwrite: true,
+        }
+        .apply(&mut graph, doc)
+        .expect("normals should succeed");
+
+        assert!(primitive.indices(&graph).is_some());
+
+        let mut normals = read_normals(&graph, primitive);
+        normals.sort_by(|a, b| a.partial_cmp(b).expect("normals should be finite"));
+        assert_eq!(
+            normals,
+            vec![
+                [0.0, 0.0, 1.0],
+                [0.0, 0.0, 1.0],
+                [0.0, 0.0, 1.0],
+                [1.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0]
+            ]
+        );
+    }
+
+    #[test]
+    fn flat_normals_quantized() {
+        let mut graph = Graph::default();
+        let doc = GltfDocument::new(&mut graph);
+        let primitive = create_fold(&mut graph, doc);
+
+        let mut positions = doc.create_accessor(&mut graph);
+        let weight = positions.get_mut(&mut graph);
+        weight.component_type = gltf::json::accessor::ComponentType::I16;
+        weight.element_type = Type::Vec3;
+        weight.data = vec![0; 4 * 3 * 2];
+        primitive.set_attribute(&mut graph, Semantic::Positions, Some(positions));
+
+        let indices = primitive.indices(&graph);
+
+        Normals {
+            flat: true,
+            overwrite: true,
+        }
+        .apply(&mut graph, doc)
+        .expect("normals should succeed");
+
+        assert_eq!(primitive.indices(&graph), indices);
+        assert!(primitive.attribute(&graph, Semantic::Normals).is_none());
+    }
+}
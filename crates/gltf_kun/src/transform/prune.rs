@@ -0,0 +1,324 @@
+//! Remove unused properties.
+
+use std::{collections::HashSet, error::Error};
+
+use petgraph::{Direction, stable_graph::NodeIndex, visit::EdgeRef};
+use tracing::debug;
+
+use crate::graph::{
+    Edge, Graph, Weight,
+    gltf::{
+        GltfEdge, GltfWeight,
+        document::{DocumentEdge, GltfDocument},
+    },
+};
+
+use super::{Transform, is_hierarchy_only};
+
+/// Removes properties that are not used by any scene or animation.
+///
+/// This removes unreferenced accessors, buffers, images, materials, meshes, nodes,
+/// skins and textures, along with any primitives or extension data they own.
+/// Properties that were removed from the document with `GltfDocument::remove_*`
+/// are deleted from the graph, even if other properties still reference them.
+///
+/// Scenes, animations and document extensions are never pruned.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Prune {
+    /// Keep nodes with no children, mesh, skin or extensions.
+    pub keep_leaves: bool,
+    /// Keep properties with extras, even if unused.
+    pub keep_extras: bool,
+}
+
+impl Transform for Prune {
+    fn name(&self) -> &'static str {
+        "prune"
+    }
+
+    fn apply(&self, graph: &mut Graph, doc: GltfDocument) -> Result<(), Box<dyn Error>> {
+        // Everything owned by the document, before anything is removed.
+        let owned = reachable(graph, [doc.0]);
+        let mut removed = 0;
+
+        let detached = owned
+            .iter()
+            .copied()
+            .filter(|&index| is_property(graph, index) && !in_document(graph, doc, index))
+            .collect::<Vec<_>>();
+
+        for index in detached {
+            graph.remove_node(index);
+            removed += 1;
+        }
+
+        if !self.keep_leaves {
+            removed += self.prune_leaves(graph, doc);
+        }
+
+        let mut roots = graph
+            .edges_directed(doc.0, Direction::Outgoing)
+            .filter(|edge| match edge.weight() {
+                Edge::Gltf(GltfEdge::Document(edge)) => matches!(
+                    edge,
+                    DocumentEdge::Animation | DocumentEdge::DefaultScene | DocumentEdge::Scene
+                ),
+                _ => true,
+            })
+            .map(|edge| edge.target())
+            .collect::<Vec<_>>();
+
+        if self.keep_extras {
+            roots.extend(
+                owned
+                    .iter()
+                    .copied()
+                    .filter(|&index| graph.contains_node(index) && has_extras(graph, index)),
+            );
+        }
+
+        let used = reachable(graph, roots);
+
+        for index in owned {
+            if index != doc.0 && !used.contains(&index) && graph.remove_node(index).is_some() {
+                removed += 1;
+            }
+        }
+
+        debug!("Pruned {} graph nodes", removed);
+
+        Ok(())
+    }
+}
+
+impl Prune {
+    /// Removes empty leaf nodes, repeating until none are left.
+    fn prune_leaves(self, graph: &mut Graph, doc: GltfDocument) -> usize {
+        let mut removed = 0;
+
+        loop {
+            let leaves = doc
+                .nodes(graph)
+                .into_iter()
+                .map(NodeIndex::from)
+                .filter(|&index| {
+                    is_empty_leaf(graph, index) && !(self.keep_extras && has_extras(graph, index))
+                })
+                .collect::<Vec<_>>();
+
+            if leaves.is_empty() {
+                return removed;
+            }
+
+            for index in leaves {
+                graph.remove_node(index);
+                removed += 1;
+            }
+        }
+    }
+}
+
+/// Collects every graph node reachable from the roots.
+fn reachable(graph: &Graph, roots: impl IntoIterator<Item = NodeIndex>) -> HashSet<NodeIndex> {
+    let mut visited = HashSet::new();
+    let mut stack = roots.into_iter().collect::<Vec<_>>();
+
+    while let Some(index) = stack.pop() {
+        if visited.insert(index) {
+            stack.extend(graph.neighbors_directed(index, Direction::Outgoing));
+        }
+    }
+
+    visited
+}
+
+fn is_property(graph: &Graph, index: NodeIndex) -> bool {
+    matches!(
+        graph.node_weight(index),
+        Some(Weight::Gltf(
+            GltfWeight::Accessor(_)
+                | GltfWeight::Animation(_)
+                | GltfWeight::Buffer(_)
+                | GltfWeight::Image(_)
+                | GltfWeight::Material(_)
+                | GltfWeight::Mesh(_)
+                | GltfWeight::Node(_)
+                | GltfWeight::Scene(_)
+                | GltfWeight::Skin(_)
+                | GltfWeight::Texture(_)
+        ))
+    )
+}
+
+fn in_document(graph: &Graph, doc: GltfDocument, index: NodeIndex) -> bool {
+    graph
+        .edges_directed(index, Direction::Incoming)
+        .any(|edge| {
+            edge.source() == doc.0 && matches!(edge.weight(), Edge::Gltf(GltfEdge::Document(_)))
+        })
+}
+
+/// A node with nothing attached, referenced only by its parent, scene or document.
+fn is_empty_leaf(graph: &Graph, index: NodeIndex) -> bool {
+    if graph
+        .edges_directed(index, Direction::Outgoing)
+        .next()
+        .is_some()
+    {
+        return false;
+    }
+
+    is_hierarchy_only(graph, index)
+}
+
+fn has_extras(graph: &Graph, index: NodeIndex) -> bool {
+    let Some(Weight::Gltf(weight)) = graph.node_weight(index) else {
+        return false;
+    };
+
+    let extras = match weight {
+        GltfWeight::Accessor(w) => &w.extras,
+        GltfWeight::Animation(w) => &w.extras,
+        GltfWeight::AnimationChannel(w) => &w.extras,
+        GltfWeight::AnimationSampler(w) => &w.extras,
+        GltfWeight::Buffer(w) => &w.extras,
+        GltfWeight::Image(w) => &w.extras,
+        GltfWeight::Material(w) => &w.extras,
+        GltfWeight::Mesh(w) => &w.extras,
+        GltfWeight::Node(w) => &w.extras,
+        GltfWeight::Primitive(w) => &w.extras,
+        GltfWeight::Scene(w) => &w.extras,
+        GltfWeight::Skin(w) => &w.extras,
+        GltfWeight::Texture(w) => &w.extras,
+        GltfWeight::Document | GltfWeight::MorphTarget => return false,
+    };
+
+    extras.is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::{
+        GraphNodeWeight,
+        gltf::{Accessor, Material, Node, primitive::Semantic},
+    };
+
+    use super::*;
+
+    struct Fixture {
+        doc: GltfDocument,
+        node: Node,
+        leaf: Node,
+        used_accessor: Accessor,
+        unused_accessor: Accessor,
+        used_material: Material,
+        unused_material: Material,
+    }
+
+    fn fixture(graph: &mut Graph) -> Fixture {
+        let doc = GltfDocument::new(graph);
+        let buffer = doc.create_buffer(graph);
+        doc.create_buffer(graph);
+
+        let scene = doc.create_scene(graph);
+        let node = doc.create_node(graph);
+        scene.add_node(graph, node);
+
+        let leaf = doc.create_node(graph);
+        node.add_child(graph, &leaf);
+
+        let mesh = doc.create_mesh(graph);
+        node.set_mesh(graph, Some(mesh));
+
+        let used_accessor = doc.create_accessor(graph);
+        used_accessor.set_buffer(graph, Some(buffer));
+        let unused_accessor = doc.create_accessor(graph);
+        unused_accessor.set_buffer(graph, Some(buffer));
+
+        let image = doc.create_image(graph);
+        image.set_buffer(graph, Some(buffer));
+        let texture = doc.create_texture(graph);
+        texture.set_image(graph, Some(image));
+
+        let used_material = doc.create_material(graph);
+        used_material.set_base_color_texture(graph, Some(texture));
+        let unused_material = doc.create_material(graph);
+        unused_material.set_base_color_texture(graph, Some(texture));
+
+        let primitive = mesh.create_primitive(graph);
+        primitive.set_attribute(graph, Semantic::Positions, Some(used_accessor));
+        primitive.set_material(graph, Some(used_material));
+
+        Fixture {
+            doc,
+            node,
+            leaf,
+            used_accessor,
+            unused_accessor,
+            used_material,
+            unused_material,
+        }
+    }
+
+    #[test]
+    fn prune_unused() {
+        let mut graph = Graph::default();
+        let f = fixture(&mut graph);
+
+        Prune::default()
+            .apply(&mut graph, f.doc)
+            .expect("prune should succeed");
+
+        assert_eq!(f.doc.nodes(&graph), vec![f.node]);
+        assert_eq!(f.doc.accessors(&graph), vec![f.used_accessor]);
+        assert_eq!(f.doc.materials(&graph), vec![f.used_material]);
+        assert_eq!(f.doc.textures(&graph).len(), 1);
+        assert_eq!(f.doc.images(&graph).len(), 1);
+        assert_eq!(f.doc.buffers(&graph).len(), 1);
+        assert!(!graph.contains_node(f.unused_accessor.into()));
+        assert!(!graph.contains_node(f.unused_material.into()));
+    }
+
+    #[test]
+    fn prune_options() {
+        let mut graph = Graph::default();
+        let mut f = fixture(&mut graph);
+
+        f.unused_material.get_mut(&mut graph).extras = Some(
+            serde_json::value::to_raw_value(&serde_json::json!({ "keep": true }))
+                .expect("extras should serialize"),
+        );
+
+        Prune {
+            keep_leaves: true,
+            keep_extras: true,
+        }
+        .apply(&mut graph, f.doc)
+        .expect("prune should succeed");
+
+        assert_eq!(f.doc.nodes(&graph), vec![f.node, f.leaf]);
+        assert_eq!(
+            f.doc.materials(&graph),
+            vec![f.used_material, f.unused_material]
+        );
+        assert_eq!(f.doc.accessors(&graph), vec![f.used_accessor]);
+    }
+
+    #[test]
+    fn prune_detached() {
+        let mut graph = Graph::default();
+        let f = fixture(&mut graph);
+
+        f.doc.remove_material(&mut graph, f.used_material);
+
+        Prune::default()
+            .apply(&mut graph, f.doc)
+            .expect("prune should succeed");
+
+        assert!(!graph.contains_node(f.used_material.into()));
+        assert!(f.doc.materials(&graph).is_empty());
+        assert!(f.doc.textures(&graph).is_empty());
+        assert!(f.doc.images(&graph).is_empty());
+        assert_eq!(f.doc.accessors(&graph), vec![f.used_accessor]);
+    }
+}
@@ -0,0 +1,691 @@
+//! Reorder indices and vertices for the GPU.
+//!
+//! Vertex cache and vertex fetch efficiency are always optimized.
+//! Overdraw optimization, which sorts clusters of triangles so outward facing surfaces
+//! are drawn first, trades away some vertex cache efficiency, so it is opt-in.
+
+use std::{collections::HashMap, error::Error};
+
+use bevy_math::Vec3;
+use tracing::debug;
+
+use crate::graph::{
+    Graph, GraphNodeWeight,
+    gltf::{
+        Accessor, Primitive,
+        accessor::iter::AccessorIter,
+        document::GltfDocument,
+        primitive::{Mode, Semantic},
+    },
+};
+
+use super::{
+    Transform,
+    geometry::{
+        create_indices, index_u32, read_indices, remap_accessor, vertex_accessors, vertex_count,
+    },
+};
+
+const CACHE_DECAY_POWER: f32 = 1.5;
+const LAST_TRIANGLE_SCORE: f32 = 0.75;
+const VALENCE_BOOST_SCALE: f32 = 2.0;
+const VALENCE_BOOST_POWER: f32 = 0.5;
+
+/// Reorders triangles for the post-transform vertex cache,
+/// then reorders vertices in the order they are first used, for fetch locality.
+///
+/// Primitives that share any vertex accessor are reordered together,
+/// so every shared accessor stays consistent.
+/// Non-indexed primitives, and primitives sharing vertices with them, are left unchanged.
+/// Replaced accessors are left in the document, and can be removed with
+/// [`Prune`](super::prune::Prune).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Reorder {
+    /// Number of vertices in the simulated post-transform cache.
+    pub cache_size: usize,
+    /// Sort clusters of triangles so outward facing ones are drawn first, reducing overdraw.
+    ///
+    /// Triangles are split into clusters whose cache miss ratio is within this factor
+    /// of the unsplit ratio, so `1.05` lets clusters be 5% less cache efficient.
+    /// Clusters are only sorted when set.
+    pub overdraw: Option<f32>,
+}
+
+impl Default for Reorder {
+    fn default() -> Self {
+        Self {
+            cache_size: 16,
+            overdraw: None,
+        }
+    }
+}
+
+impl Transform for Reorder {
+    fn name(&self) -> &'static str {
+        "reorder"
+    }
+
+    fn apply(&self, graph: &mut Graph, doc: GltfDocument) -> Result<(), Box<dyn Error>> {
+        for group in shared_groups(graph, doc) {
+            self.reorder_group(graph, doc, &group)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Reorder {
+    fn reorder_group(
+        self,
+        graph: &mut Graph,
+        doc: GltfDocument,
+        group: &[Primitive],
+    ) -> Result<(), Box<dyn Error>> {
+        let counts = group
+            .iter()
+            .map(|primitive| vertex_count(graph, *primitive))
+            .collect::<Option<Vec<_>>>();
+
+        let Some(count) = counts.and_then(|counts| {
+            let first = *counts.first()?;
+            counts.iter().all(|c| *c == first).then_some(first)
+        }) else {
+            debug!("Primitives sharing vertices have different lengths, skipping reorder");
+            return Ok(());
+        };
+
+        let mut group_indices = Vec::with_capacity(group.len());
+
+        for primitive in group {
+            let Some(indices) = primitive.indices(graph) else {
+                return Ok(());
+            };
+
+            let indices = read_indices(graph, indices)?;
+
+            if indices.iter().any(|&i| i as usize >= count) {
+                debug!("Primitive indices out of bounds, skipping reorder");
+                return Ok(());
+            }
+
+            if primitive.get(graph).mode == Mode::Triangles && indices.len() % 3 == 0 {
+                let mut indices = optimize_vertex_cache(&indices, count, self.cache_size);
+
+                if let Some(threshold) = self.overdraw
+                    && let Some(positions) = read_positions(graph, *primitive, count)?
+                {
+                    indices = optimize_overdraw(&indices, &positions, self.cache_size, threshold);
+                }
+
+                group_indices.push(indices);
+            } else {
+                group_indices.push(indices);
+            }
+        }
+
+        let order = fetch_order(&group_indices, count);
+
+        let mut remap = vec![0; count];
+        for (new, &old) in order.iter().enumerate() {
+            remap[old as usize] = index_u32(new);
+        }
+
+        let mut remapped = HashMap::<Accessor, Accessor>::new();
+
+        for (primitive, indices) in group.iter().zip(group_indices) {
+            for (semantic, accessor) in primitive.attributes(graph) {
+                let accessor = remapped_accessor(graph, doc, &mut remapped, accessor, &order);
+                primitive.set_attribute(graph, semantic, Some(accessor));
+            }
+
+            for target in primitive.morph_targets(graph) {
+                for (semantic, accessor) in target.attributes(graph) {
+                    let accessor = remapped_accessor(graph, doc, &mut remapped, accessor, &order);
+                    target.set_attribute(graph, semantic, Some(accessor));
+                }
+            }
+
+            let indices = indices
+                .iter()
+                .map(|&i| remap[i as usize])
+                .collect::<Vec<_>>();
+            let indices = create_indices(graph, doc, &indices, count);
+            primitive.set_indices(graph, Some(indices));
+        }
+
+        debug!(
+            "Reordered {} vertices across {} primitives",
+            count,
+            group.len()
+        );
+
+        Ok(())
+    }
+}
+
+fn read_positions(
+    graph: &Graph,
+    primitive: Primitive,
+    count: usize,
+) -> Result<Option<Vec<Vec3>>, Box<dyn Error>> {
+    let Some(positions) = primitive.attribute(graph, Semantic::Positions) else {
+        return Ok(None);
+    };
+
+    let AccessorIter::F32x3(iter) = positions.to_iter(graph)? else {
+        debug!("Positions are not f32x3, skipping overdraw optimization");
+        return Ok(None);
+    };
+
+    let positions = iter.map(Vec3::from).collect::<Vec<_>>();
+
+    Ok((positions.len() == count).then_some(positions))
+}
+
+/// Remaps each accessor once, reusing the copy for every primitive that shares it.
+fn remapped_accessor(
+    graph: &mut Graph,
+    doc: GltfDocument,
+    remapped: &mut HashMap<Accessor, Accessor>,
+    accessor: Accessor,
+    order: &[u32],
+) -> Accessor {
+    if let Some(copy) = remapped.get(&accessor) {
+        return *copy;
+    }
+
+    let copy = remap_accessor(graph, doc, accessor, order);
+    remapped.insert(accessor, copy);
+    copy
+}
+
+/// Groups primitives that share any vertex accessor, directly or through other primitives.
+fn shared_groups(graph: &Graph, doc: GltfDocument) -> Vec<Vec<Primitive>> {
+    let primitives = doc
+        .meshes(graph)
+        .into_iter()
+        .flat_map(|mesh| mesh.primitives(graph))
+        .collect::<Vec<_>>();
+
+    let mut parents = (0..primitives.len()).collect::<Vec<_>>();
+    let mut owners = HashMap::<Accessor, usize>::new();
+
+    for (i, primitive) in primitives.iter().enumerate() {
+        for (_, accessor) in vertex_accessors(graph, *primitive) {
+            let owner = *owners.entry(accessor).or_insert(i);
+            let (a, b) = (find_root(&mut parents, owner), find_root(&mut parents, i));
+            parents[b] = a;
+        }
+    }
+
+    let mut groups = HashMap::<usize, Vec<Primitive>>::new();
+    let mut roots = Vec::new();
+
+    for (i, primitive) in primitives.into_iter().enumerate() {
+        let root = find_root(&mut parents, i);
+        groups
+            .entry(root)
+            .or_insert_with(|| {
+                roots.push(root);
+                Vec::new()
+            })
+            .push(primitive);
+    }
+
+    roots
+        .into_iter()
+        .filter_map(|root| groups.remove(&root))
+        .collect()
+}
+
+fn find_root(parents: &mut [usize], mut i: usize) -> usize {
+    while parents[i] != i {
+        parents[i] = parents[parents[i]];
+        i = parents[i];
+    }
+    i
+}
+
+/// Vertices in the order they are first used, followed by unused vertices.
+fn fetch_order(group_indices: &[Vec<u32>], count: usize) -> Vec<u32> {
+    let mut used = vec![false; count];
+    let mut order = Vec::with_capacity(count);
+
+    for &i in group_indices.iter().flatten() {
+        if !used[i as usize] {
+            used[i as usize] = true;
+            order.push(i);
+        }
+    }
+
+    order.extend((0..count).filter(|&i| !used[i]).map(index_u32));
+    order
+}
+
+/// Reorders triangles to reuse recently transformed vertices,
+/// using Tom Forsyth's linear-speed vertex cache optimization.
+fn optimize_vertex_cache(indices: &[u32], vertex_count: usize, cache_size: usize) -> Vec<u32> {
+    let cache_size = cache_size.max(4);
+    let triangle_count = indices.len() / 3;
+
+    let mut vertex_triangles = vec![Vec::new(); vertex_count];
+    for (t, triangle) in indices.chunks_exact(3).enumerate() {
+        for &i in triangle {
+            vertex_triangles[i as usize].push(t);
+        }
+    }
+
+    let mut remaining = vertex_triangles.iter().map(Vec::len).collect::<Vec<_>>();
+    let mut cache_position = vec![None; vertex_count];
+    let mut vertex_scores = (0..vertex_count)
+        .map(|v| vertex_score(None, remaining[v], cache_size))
+        .collect::<Vec<_>>();
+
+    let triangle_score = |scores: &[f32], t: usize| {
+        indices[t * 3..t * 3 + 3]
+            .iter()
+            .map(|&i| scores[i as usize])
+            .sum::<f32>()
+    };
+
+    let mut triangle_scores = (0..triangle_count)
+        .map(|t| triangle_score(&vertex_scores, t))
+        .collect::<Vec<_>>();
+    let mut emitted = vec![false; triangle_count];
+
+    let mut cache = Vec::<u32>::with_capacity(cache_size + 3);
+    let mut output = Vec::with_capacity(indices.len());
+    let mut next_unemitted = 0;
+
+    let mut best = best_triangle(&triangle_scores, &emitted, 0..triangle_count);
+
+    while let Some(t) = best {
+        emitted[t] = true;
+        let triangle = &indices[t * 3..t * 3 + 3];
+        output.extend_from_slice(triangle);
+
+        for &v in triangle {
+            remaining[v as usize] -= 1;
+            cache.retain(|&c| c != v);
+        }
+        for &v in triangle.iter().rev() {
+            cache.insert(0, v);
+        }
+
+        for &v in cache.iter().skip(cache_size) {
+            cache_position[v as usize] = None;
+        }
+        let evicted = cache.split_off(cache.len().min(cache_size));
+
+        for (position, &v) in cache.iter().enumerate() {
+            cache_position[v as usize] = Some(position);
+        }
+
+        let mut candidates = Vec::new();
+
+        for &v in cache.iter().chain(&evicted) {
+            let v = v as usize;
+            vertex_scores[v] = vertex_score(cache_position[v], remaining[v], cache_size);
+
+            for &t in &vertex_triangles[v] {
+                if !emitted[t] {
+                    candidates.push(t);
+                }
+            }
+        }
+
+        for &t in &candidates {
+            triangle_scores[t] = triangle_score(&vertex_scores, t);
+        }
+
+        best = best_triangle(&triangle_scores, &emitted, candidates).or_else(|| {
+            while next_unemitted < triangle_count && emitted[next_unemitted] {
+                next_unemitted += 1;
+            }
+            (next_unemitted < triangle_count).then_some(next_unemitted)
+        });
+    }
+
+    output
+}
+
+/// Sorts clusters of cache optimized triangles so outward facing clusters come first,
+/// following the overdraw optimization of Sander et al. as done by meshoptimizer.
+fn optimize_overdraw(
+    indices: &[u32],
+    positions: &[Vec3],
+    cache_size: usize,
+    threshold: f32,
+) -> Vec<u32> {
+    if indices.is_empty() {
+        return Vec::new();
+    }
+
+    let mut cache = FifoCache::new(positions.len(), cache_size);
+    let hard = hard_boundaries(indices, &mut cache);
+    let boundaries = soft_boundaries(indices, &hard, &mut cache, threshold);
+
+    let clusters = boundaries
+        .iter()
+        .zip(boundaries.iter().skip(1).chain([&(indices.len() / 3)]))
+        .map(|(&start, &end)| &indices[start * 3..end * 3])
+        .collect::<Vec<_>>();
+
+    let mesh_center =
+        indices.iter().map(|&i| positions[i as usize]).sum::<Vec3>() / indices.len() as f32;
+
+    let keys = clusters
+        .iter()
+        .map(|cluster| cluster_key(cluster, positions, mesh_center))
+        .collect::<Vec<_>>();
+
+    let mut order = (0..clusters.len()).collect::<Vec<_>>();
+    order.sort_by(|&a, &b| keys[b].total_cmp(&keys[a]));
+
+    order
+        .into_iter()
+        .flat_map(|c| clusters[c].iter().copied())
+        .collect()
+}
+
+/// Triangles where every vertex misses the cache, which usually start a disjoint patch.
+fn hard_boundaries(indices: &[u32], cache: &mut FifoCache) -> Vec<usize> {
+    indices
+        .chunks_exact(3)
+        .enumerate()
+        .filter_map(|(t, triangle)| {
+            let misses = cache.misses(triangle);
+            (t == 0 || misses == 3).then_some(t)
+        })
+        .collect()
+}
+
+/// Splits each hard cluster into the smallest clusters whose cache miss ratio
+/// is within `threshold` of the whole hard cluster's.
+fn soft_boundaries(
+    indices: &[u32],
+    hard: &[usize],
+    cache: &mut FifoCache,
+    threshold: f32,
+) -> Vec<usize> {
+    let triangle_count = indices.len() / 3;
+    let mut boundaries = Vec::new();
+
+    for (i, &start) in hard.iter().enumerate() {
+        let end = hard.get(i + 1).copied().unwrap_or(triangle_count);
+        let triangles = &indices[start * 3..end * 3];
+
+        cache.reset();
+        let misses = triangles
+            .chunks_exact(3)
+            .map(|triangle| cache.misses(triangle))
+            .sum::<usize>();
+        let target = threshold * misses as f32 / (end - start) as f32;
+
+        boundaries.push(start);
+        cache.reset();
+
+        let mut running_misses = 0;
+        let mut running_triangles = 0;
+
+        for (t, triangle) in (start..).zip(triangles.chunks_exact(3)) {
+            running_misses += cache.misses(triangle);
+            running_triangles += 1;
+
+            if running_misses as f32 / running_triangles as f32 <= target {
+                boundaries.push(t + 1);
+                cache.reset();
+                running_misses = 0;
+                running_triangles = 0;
+            }
+        }
+
+        // The last cluster rarely reaches the target, so it is merged into the one before.
+        // When it does, this removes the boundary at the end of the hard cluster instead.
+        if boundaries.last() != Some(&start) {
+            boundaries.pop();
+        }
+    }
+
+    boundaries
+}
+
+/// How far a cluster faces away from the mesh center, weighted by triangle area.
+fn cluster_key(indices: &[u32], positions: &[Vec3], mesh_center: Vec3) -> f32 {
+    let mut area = 0.0;
+    let mut center = Vec3::ZERO;
+    let mut normal = Vec3::ZERO;
+
+    for triangle in indices.chunks_exact(3) {
+        let [a, b, c] = [0, 1, 2].map(|i| positions[triangle[i] as usize]);
+        let cross = (b - a).cross(c - a);
+        let triangle_area = cross.length();
+
+        area += triangle_area;
+        center += (a + b + c) / 3.0 * triangle_area;
+        normal += cross;
+    }
+
+    if area == 0.0 {
+        return 0.0;
+    }
+
+    (center / area - mesh_center).dot(normal.normalize_or_zero())
+}
+
+/// Simulated FIFO post-transform cache, which can be emptied in constant time.
+struct FifoCache {
+    timestamps: Vec<usize>,
+    time: usize,
+    size: usize,
+}
+
+impl FifoCache {
+    fn new(vertex_count: usize, size: usize) -> Self {
+        Self {
+            timestamps: vec![0; vertex_count],
+            time: size + 1,
+            size,
+        }
+    }
+
+    const fn reset(&mut self) {
+        self.time += self.size + 1;
+    }
+
+    /// Number of vertices of the triangle missing the cache, which are then added to it.
+    fn misses(&mut self, triangle: &[u32]) -> usize {
+        let mut misses = 0;
+
+        for &v in triangle {
+            let v = v as usize;
+
+            if self.time - self.timestamps[v] > self.size {
+                self.timestamps[v] = self.time;
+                self.time += 1;
+                misses += 1;
+            }
+        }
+
+        misses
+    }
+}
+
+fn best_triangle(
+    scores: &[f32],
+    emitted: &[bool],
+    candidates: impl IntoIterator<Item = usize>,
+) -> Option<usize> {
+    candidates
+        .into_iter()
+        .filter(|&t| !emitted[t])
+        .max_by(|&a, &b| scores[a].total_cmp(&scores[b]).then(b.cmp(&a)))
+}
+
+fn vertex_score(cache_position: Option<usize>, remaining: usize, cache_size: usize) -> f32 {
+    if remaining == 0 {
+        return -1.0;
+    }
+
+    // Positions and valences are small, so they fit in an f32.
+    let cache_score = match cache_position {
+        None => 0.0,
+        Some(position) if position < 3 => LAST_TRIANGLE_SCORE,
+        Some(position) => {
+            ((cache_size - position) as f32 / (cache_size - 3) as f32).powf(CACHE_DECAY_POWER)
+        }
+    };
+
+    let valence_boost = VALENCE_BOOST_SCALE * (remaining as f32).powf(-VALENCE_BOOST_POWER);
+
+    cache_score + valence_boost
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::gltf::primitive::Semantic;
+
+    use super::{
+        super::geometry::test_utils::{create_positions, read_positions},
+        *,
+    };
+
+    /// Average number of cache misses per triangle, with a FIFO cache.
+    fn miss_ratio(indices: &[u32], cache_size: usize) -> f32 {
+        let mut cache = Vec::new();
+        let mut misses = 0_u16;
+
+        for &i in indices {
+            if !cache.contains(&i) {
+                misses += 1;
+                cache.insert(0, i);
+                cache.truncate(cache_size);
+            }
+        }
+
+        f32::from(misses) / f32::from(u16::try_from(indices.len() / 3).expect("small mesh"))
+    }
+
+    fn triangles(graph: &Graph, primitive: Primitive) -> Vec<[[f32; 3]; 3]> {
+        let positions = read_positions(graph, primitive);
+        let indices = primitive
+            .indices(graph)
+            .expect("primitive should be indexed");
+        let mut triangles = read_indices(graph, indices)
+            .expect("indices should be readable")
+            .chunks_exact(3)
+            .map(|t| [0, 1, 2].map(|i| positions[t[i] as usize]))
+            .collect::<Vec<_>>();
+        triangles.sort_by(|a, b| a.partial_cmp(b).expect("positions should be finite"));
+        triangles
+    }
+
+    #[test]
+    fn reorder_shared() {
+        let mut graph = Graph::default();
+        let doc = GltfDocument::new(&mut graph);
+
+        let size = 8_u16;
+        let positions = (0..=size)
+            .flat_map(|y| (0..=size).map(move |x| [f32::from(x), f32::from(y), 0.0]))
+            .collect::<Vec<_>>();
+        let count = positions.len();
+        let positions = create_positions(&mut graph, doc, &positions);
+
+        // Stripes of quads, split between two primitives in a cache-unfriendly order.
+        let stride = u32::from(size) + 1;
+        let mut quads = [Vec::new(), Vec::new()];
+        for x in 0..u32::from(size) {
+            for y in (0..u32::from(size)).rev() {
+                let i = y * stride + x;
+                let j = i + stride;
+                quads[(y % 2) as usize].extend([i, i + 1, j + 1, i, j + 1, j]);
+            }
+        }
+
+        let mesh = doc.create_mesh(&mut graph);
+        let primitives = quads.each_ref().map(|indices| {
+            let primitive = mesh.create_primitive(&mut graph);
+            primitive.set_attribute(&mut graph, Semantic::Positions, Some(positions));
+            let indices = create_indices(&mut graph, doc, indices, count);
+            primitive.set_indices(&mut graph, Some(indices));
+            primitive
+        });
+
+        let before = primitives.map(|p| triangles(&graph, p));
+        let miss_before = miss_ratio(&quads.concat(), 16);
+
+        Reorder::default()
+            .apply(&mut graph, doc)
+            .expect("reorder should succeed");
+
+        let shared = primitives[0].attribute(&graph, Semantic::Positions);
+        assert_ne!(shared, Some(positions));
+        assert_eq!(primitives[1].attribute(&graph, Semantic::Positions), shared);
+
+        assert_eq!(primitives.map(|p| triangles(&graph, p)), before);
+
+        let indices = primitives
+            .iter()
+            .flat_map(|p| {
+                let indices = p.indices(&graph).expect("primitive should be indexed");
+                read_indices(&graph, indices).expect("indices should be readable")
+            })
+            .collect::<Vec<_>>();
+        assert!(miss_ratio(&indices, 16) < miss_before);
+
+        // Vertices are in order of first use.
+        let mut next = 0;
+        for i in indices {
+            assert!(i <= next);
+            if i == next {
+                next += 1;
+            }
+        }
+    }
+
+    #[test]
+    fn reorder_overdraw() {
+        let mut graph = Graph::default();
+        let doc = GltfDocument::new(&mut graph);
+
+        // Two quads facing up, with the lower one first.
+        let positions = [-1.0, 1.0]
+            .into_iter()
+            .flat_map(|z| [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]].map(|[x, y]| [x, y, z]))
+            .collect::<Vec<_>>();
+        let indices = [0, 1, 2, 0, 2, 3, 4, 5, 6, 4, 6, 7];
+
+        let mesh = doc.create_mesh(&mut graph);
+        let primitive = mesh.create_primitive(&mut graph);
+        let accessor = create_positions(&mut graph, doc, &positions);
+        primitive.set_attribute(&mut graph, Semantic::Positions, Some(accessor));
+        let accessor = create_indices(&mut graph, doc, &indices, positions.len());
+        primitive.set_indices(&mut graph, Some(accessor));
+
+        let first_height = |graph: &Graph| {
+            let indices = primitive
+                .indices(graph)
+                .expect("primitive should be indexed");
+            let first = read_indices(graph, indices).expect("indices should be readable")[0];
+            read_positions(graph, primitive)[first as usize][2]
+        };
+
+        Reorder::default()
+            .apply(&mut graph, doc)
+            .expect("reorder should succeed");
+        assert!(first_height(&graph) < 0.0);
+
+        let before = triangles(&graph, primitive);
+
+        Reorder {
+            overdraw: Some(1.05),
+            ..Default::default()
+        }
+        .apply(&mut graph, doc)
+        .expect("reorder should succeed");
+        assert!(first_height(&graph) > 0.0);
+
+        assert_eq!(triangles(&graph, primitive), before);
+    }
+}
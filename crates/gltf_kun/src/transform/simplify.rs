@@ -0,0 +1,875 @@
+//! Reduce triangle counts with quadric error simplification.
+
+#[cfg(feature = "msft_lod")]
+use std::collections::HashSet;
+use std::{
+    collections::{HashMap, hash_map::Entry},
+    error::Error,
+};
+
+use bevy_math::DVec3;
+use tracing::{debug, warn};
+
+use crate::graph::{
+    Graph, GraphNodeWeight,
+    gltf::{
+        Primitive,
+        accessor::iter::AccessorIter,
+        document::GltfDocument,
+        primitive::{Mode, Semantic},
+    },
+};
+#[cfg(feature = "msft_lod")]
+use crate::{
+    extensions::msft_lod::MsftLod,
+    graph::{
+        Extensions,
+        gltf::{Mesh, Node},
+    },
+};
+
+use super::{
+    Transform,
+    geometry::{
+        create_indices, index_u32, read_indices_or_sequential, remap_vertices, vertex_count,
+    },
+};
+
+/// Weight of the planes keeping borders in place, relative to surface planes.
+const BORDER_WEIGHT: f64 = 10.0;
+
+/// Collapses edges of triangle primitives, picking the collapses that least change the surface.
+///
+/// Vertices are moved onto their neighbours rather than to new positions, so every
+/// attribute, including skin joints and weights, keeps its original values.
+/// Attribute seams and mesh borders are only collapsed along their length,
+/// and vertices where seams or borders meet are never moved.
+/// Replaced accessors are left in the document, and can be removed with
+/// [`Prune`](super::prune::Prune).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Simplify {
+    /// Target fraction of triangles to keep, from 0 to 1.
+    pub ratio: f32,
+    /// Maximum distance a surface may move, relative to the size of the primitive.
+    /// Simplification stops before exceeding this error, even if `ratio` has not been reached.
+    pub error: f32,
+    /// Add the simplified meshes as an [`MSFT_lod`](crate::extensions::msft_lod) level
+    /// of each node, instead of replacing the original meshes.
+    ///
+    /// Levels are appended after any existing levels, so running the transform
+    /// again with a lower ratio adds a coarser level.
+    /// Extensions on the original primitives are not copied to the levels.
+    /// Levels also do not copy the node's children, so viewers that swap the whole
+    /// node for a level hide its children at that level.
+    #[cfg(feature = "msft_lod")]
+    pub lod: bool,
+}
+
+impl Default for Simplify {
+    fn default() -> Self {
+        Self {
+            ratio: 0.5,
+            error: 0.01,
+            #[cfg(feature = "msft_lod")]
+            lod: false,
+        }
+    }
+}
+
+impl Transform for Simplify {
+    fn name(&self) -> &'static str {
+        "simplify"
+    }
+
+    fn apply(&self, graph: &mut Graph, doc: GltfDocument) -> Result<(), Box<dyn Error>> {
+        #[cfg(feature = "msft_lod")]
+        if self.lod {
+            return self.add_lods(graph, doc);
+        }
+
+        for mesh in doc.meshes(graph) {
+            for primitive in mesh.primitives(graph) {
+                if let Some(indices) = self.simplified_indices(graph, primitive)? {
+                    apply_indices(graph, doc, primitive, &indices);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Simplify {
+    /// Simplifies the indices of a triangle primitive,
+    /// or returns `None` if it cannot be simplified.
+    fn simplified_indices(
+        self,
+        graph: &Graph,
+        primitive: Primitive,
+    ) -> Result<Option<Vec<u32>>, Box<dyn Error>> {
+        if primitive.get(graph).mode != Mode::Triangles {
+            return Ok(None);
+        }
+
+        let Some(count) = vertex_count(graph, primitive) else {
+            warn!("Primitive attributes have different lengths, skipping simplify");
+            return Ok(None);
+        };
+
+        let Some(positions) = primitive.attribute(graph, Semantic::Positions) else {
+            return Ok(None);
+        };
+
+        let AccessorIter::F32x3(positions) = positions.to_iter(graph)? else {
+            warn!("Positions are not f32x3, skipping simplify");
+            return Ok(None);
+        };
+
+        let positions = positions.map(|p| DVec3::from(p.map(f64::from))).collect();
+
+        let mut indices = read_indices_or_sequential(graph, primitive, count)?;
+        indices.truncate(indices.len() - indices.len() % 3);
+
+        if indices.iter().any(|&i| i as usize >= count) {
+            warn!("Primitive indices out of bounds, skipping simplify");
+            return Ok(None);
+        }
+
+        let triangles = indices.len() / 3;
+
+        // Truncation rounds the target down to a whole triangle.
+        let target = (triangles as f32 * self.ratio.clamp(0.0, 1.0)) as usize;
+
+        let simplified = Simplifier::new(positions, &indices).run(
+            indices.clone(),
+            target,
+            f64::from(self.error.max(0.0)),
+        );
+
+        if simplified.len() == indices.len() {
+            return Ok(None);
+        }
+
+        debug!(
+            "Simplified {} triangles to {}",
+            triangles,
+            simplified.len() / 3
+        );
+
+        Ok(Some(simplified))
+    }
+
+    #[cfg(feature = "msft_lod")]
+    fn add_lods(self, graph: &mut Graph, doc: GltfDocument) -> Result<(), Box<dyn Error>> {
+        let nodes = doc.nodes(graph);
+
+        let existing = nodes
+            .iter()
+            .filter_map(|node| node.get_extension::<MsftLod>(graph))
+            .flat_map(|ext| ext.levels::<Node>(graph))
+            .collect::<HashSet<_>>();
+
+        let mut simplified = HashMap::<Mesh, Option<Mesh>>::new();
+
+        for node in nodes {
+            if existing.contains(&node) {
+                continue;
+            }
+
+            let Some(mesh) = node.mesh(graph) else {
+                continue;
+            };
+
+            let lod_mesh = match simplified.entry(mesh) {
+                Entry::Occupied(entry) => *entry.get(),
+                Entry::Vacant(entry) => *entry.insert(self.simplified_mesh(graph, doc, mesh)?),
+            };
+
+            let Some(lod_mesh) = lod_mesh else {
+                continue;
+            };
+
+            let ext = node
+                .get_extension::<MsftLod>(graph)
+                .unwrap_or_else(|| node.create_extension::<MsftLod>(graph));
+            let mut levels = ext.levels::<Node>(graph);
+
+            // Levels replace the node, so they share its transform.
+            let mut weight = node.get(graph).clone();
+            weight.name = weight
+                .name
+                .map(|name| format!("{name}_lod{}", levels.len() + 1));
+
+            let mut level = doc.create_node(graph);
+            *level.get_mut(graph) = weight;
+            level.set_mesh(graph, Some(lod_mesh));
+            level.set_skin(graph, node.skin(graph));
+
+            levels.push(level);
+            ext.set_levels(graph, &levels);
+        }
+
+        Ok(())
+    }
+
+    /// Creates a simplified copy of a mesh, or returns `None` if no primitive could be simplified.
+    #[cfg(feature = "msft_lod")]
+    fn simplified_mesh(
+        self,
+        graph: &mut Graph,
+        doc: GltfDocument,
+        mesh: Mesh,
+    ) -> Result<Option<Mesh>, Box<dyn Error>> {
+        let primitives = mesh
+            .primitives(graph)
+            .into_iter()
+            .map(|primitive| Ok((primitive, self.simplified_indices(graph, primitive)?)))
+            .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
+
+        if primitives.iter().all(|(_, indices)| indices.is_none()) {
+            return Ok(None);
+        }
+
+        let mut copy = doc.create_mesh(graph);
+        *copy.get_mut(graph) = mesh.get(graph).clone();
+
+        for (primitive, indices) in primitives {
+            let mut new = copy.create_primitive(graph);
+            *new.get_mut(graph) = primitive.get(graph).clone();
+            new.set_material(graph, primitive.material(graph));
+            new.set_indices(graph, primitive.indices(graph));
+
+            for (semantic, accessor) in primitive.attributes(graph) {
+                new.set_attribute(graph, semantic, Some(accessor));
+            }
+
+            for (i, target) in primitive.morph_targets(graph).into_iter().enumerate() {
+                let new_target = new.create_morph_target(graph, i);
+
+                for (semantic, accessor) in target.attributes(graph) {
+                    new_target.set_attribute(graph, semantic, Some(accessor));
+                }
+            }
+
+            if let Some(indices) = indices {
+                apply_indices(graph, doc, new, &indices);
+            }
+        }
+
+        Ok(Some(copy))
+    }
+}
+
+/// Replaces the indices of a primitive, removing vertices that are no longer used.
+fn apply_indices(graph: &mut Graph, doc: GltfDocument, primitive: Primitive, indices: &[u32]) {
+    let mut remap = HashMap::new();
+    let mut order = Vec::new();
+
+    let indices = indices
+        .iter()
+        .map(|&i| {
+            *remap.entry(i).or_insert_with(|| {
+                order.push(i);
+                index_u32(order.len() - 1)
+            })
+        })
+        .collect::<Vec<_>>();
+
+    remap_vertices(graph, doc, primitive, &order);
+
+    let indices = create_indices(graph, doc, &indices, order.len());
+    primitive.set_indices(graph, Some(indices));
+}
+
+/// How a vertex may be collapsed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum VertexKind {
+    /// Inside a continuous surface, can collapse in any direction.
+    Manifold,
+    /// On a mesh border, can only collapse along it.
+    Border,
+    /// On an attribute seam, can only collapse along it, together with its twin vertex.
+    Seam,
+    /// Never collapsed.
+    Locked,
+}
+
+/// Symmetric matrix of a sum of squared distances to planes.
+#[derive(Clone, Copy, Debug, Default)]
+struct Quadric {
+    /// `xx`, `yy` and `zz` terms.
+    diagonal: DVec3,
+    /// `xy`, `xz` and `yz` terms.
+    off_diagonal: DVec3,
+    linear: DVec3,
+    constant: f64,
+    weight: f64,
+}
+
+impl Quadric {
+    /// Plane through `point` with a unit `normal`.
+    fn plane(normal: DVec3, point: DVec3, weight: f64) -> Self {
+        let d = -normal.dot(point);
+        let DVec3 { x, y, z } = normal;
+
+        Self {
+            diagonal: normal * normal * weight,
+            off_diagonal: DVec3::new(x * y, x * z, y * z) * weight,
+            linear: normal * d * weight,
+            constant: d * d * weight,
+            weight,
+        }
+    }
+
+    fn add(&mut self, other: &Self) {
+        self.diagonal += other.diagonal;
+        self.off_diagonal += other.off_diagonal;
+        self.linear += other.linear;
+        self.constant += other.constant;
+        self.weight += other.weight;
+    }
+
+    /// Weighted mean squared distance from a point to the planes.
+    fn error(&self, p: DVec3) -> f64 {
+        if self.weight <= 0.0 {
+            return 0.0;
+        }
+
+        let DVec3 {
+            x: xx,
+            y: yy,
+            z: zz,
+        } = self.diagonal;
+        let DVec3 {
+            x: xy,
+            y: xz,
+            z: yz,
+        } = self.off_diagonal;
+
+        let ap = DVec3::new(
+            DVec3::new(xx, xy, xz).dot(p),
+            DVec3::new(xy, yy, yz).dot(p),
+            DVec3::new(xz, yz, zz).dot(p),
+        );
+
+        let r = (ap + 2.0 * self.linear).dot(p) + self.constant;
+        (r / self.weight).abs()
+    }
+}
+
+/// Number of triangles using each undirected edge.
+fn edge_counts(indices: &[u32], remap: impl Fn(u32) -> u32) -> HashMap<(u32, u32), u32> {
+    let mut counts = HashMap::new();
+
+    for triangle in indices.chunks_exact(3) {
+        for (a, b) in triangle_edges(triangle) {
+            *counts.entry(edge_key(remap(a), remap(b))).or_default() += 1;
+        }
+    }
+
+    counts
+}
+
+fn triangle_edges(triangle: &[u32]) -> [(u32, u32); 3] {
+    [
+        (triangle[0], triangle[1]),
+        (triangle[1], triangle[2]),
+        (triangle[2], triangle[0]),
+    ]
+}
+
+const fn edge_key(a: u32, b: u32) -> (u32, u32) {
+    if a < b { (a, b) } else { (b, a) }
+}
+
+/// Edge collapse simplification, in the style of meshoptimizer.
+///
+/// Vertices with the same position form a single position, identified by its first vertex.
+/// Each position keeps a list of its vertices, called wedges,
+/// which differ by the attributes on either side of a seam.
+struct Simplifier {
+    /// Positions scaled to fit a unit cube.
+    positions: Vec<DVec3>,
+    /// First vertex with the same position as each vertex.
+    canonical: Vec<u32>,
+    /// Vertices sharing each canonical position.
+    wedges: Vec<Vec<u32>>,
+    kinds: Vec<VertexKind>,
+    quadrics: Vec<Quadric>,
+}
+
+struct Collapse {
+    cost: f64,
+    from: u32,
+    to: u32,
+}
+
+impl Simplifier {
+    fn new(positions: Vec<DVec3>, indices: &[u32]) -> Self {
+        let count = positions.len();
+
+        let mut first = HashMap::new();
+        let canonical = positions
+            .iter()
+            .enumerate()
+            .map(|(i, p)| {
+                // Adding zero folds -0.0 into 0.0.
+                let key = (*p + DVec3::ZERO).to_array().map(f64::to_bits);
+                *first.entry(key).or_insert_with(|| index_u32(i))
+            })
+            .collect::<Vec<_>>();
+
+        let mut wedges = vec![Vec::new(); count];
+        let mut used = vec![false; count];
+
+        for &i in indices {
+            if !used[i as usize] {
+                used[i as usize] = true;
+                wedges[canonical[i as usize] as usize].push(i);
+            }
+        }
+
+        let (min, max) =
+            indices
+                .iter()
+                .fold((DVec3::INFINITY, DVec3::NEG_INFINITY), |(min, max), &i| {
+                    let p = positions[i as usize];
+                    (min.min(p), max.max(p))
+                });
+        let extent = (max - min).max_element();
+        let scale = if extent > 0.0 { extent.recip() } else { 1.0 };
+
+        let positions = positions.iter().map(|p| (*p - min) * scale).collect();
+
+        let mut simplifier = Self {
+            positions,
+            canonical,
+            wedges,
+            kinds: vec![VertexKind::Locked; count],
+            quadrics: vec![Quadric::default(); count],
+        };
+
+        simplifier.classify(indices);
+        simplifier.fill_quadrics(indices);
+        simplifier
+    }
+
+    /// Classifies each position by its open edges and wedges.
+    fn classify(&mut self, indices: &[u32]) {
+        let vertex_edges = edge_counts(indices, |i| i);
+        let position_edges = edge_counts(indices, |i| self.canonical[i as usize]);
+
+        let mut open_vertex = vec![0_u32; self.positions.len()];
+        let mut open_position = vec![0_u32; self.positions.len()];
+        let mut complex = vec![false; self.positions.len()];
+
+        for (&(a, b), &count) in &vertex_edges {
+            if count == 1 {
+                open_vertex[a as usize] += 1;
+                open_vertex[b as usize] += 1;
+            }
+        }
+
+        for (&(a, b), &count) in &position_edges {
+            if count == 1 {
+                open_position[a as usize] += 1;
+                open_position[b as usize] += 1;
+            } else if count > 2 {
+                complex[a as usize] = true;
+                complex[b as usize] = true;
+            }
+        }
+
+        for (p, wedges) in self.wedges.iter().enumerate() {
+            let open = |i: &u32| open_vertex[*i as usize];
+
+            self.kinds[p] = match (wedges.as_slice(), open_position[p]) {
+                _ if complex[p] => VertexKind::Locked,
+                ([w], 0) if open(w) == 0 => VertexKind::Manifold,
+                ([w], 2) if open(w) == 2 => VertexKind::Border,
+                ([a, b], 0) if open(a) == 2 && open(b) == 2 => VertexKind::Seam,
+                _ => VertexKind::Locked,
+            };
+        }
+    }
+
+    /// Accumulates the planes of each triangle, and planes perpendicular to open edges.
+    fn fill_quadrics(&mut self, indices: &[u32]) {
+        let vertex_edges = edge_counts(indices, |i| i);
+        let position_edges = edge_counts(indices, |i| self.canonical[i as usize]);
+
+        for triangle in indices.chunks_exact(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| self.positions[triangle[i] as usize]);
+            let normal = (b - a).cross(c - a);
+            let area = normal.length();
+
+            if area <= 0.0 {
+                continue;
+            }
+
+            let normal = normal / area;
+            let quadric = Quadric::plane(normal, a, area);
+
+            for &i in triangle {
+                self.quadrics[self.canonical[i as usize] as usize].add(&quadric);
+            }
+
+            for (i0, i1) in triangle_edges(triangle) {
+                if vertex_edges[&edge_key(i0, i1)] != 1 {
+                    continue;
+                }
+
+                let (p0, p1) = (self.canonical[i0 as usize], self.canonical[i1 as usize]);
+                let weight = if position_edges[&edge_key(p0, p1)] == 1 {
+                    BORDER_WEIGHT
+                } else {
+                    1.0
+                };
+
+                let (v0, v1) = (self.positions[p0 as usize], self.positions[p1 as usize]);
+                let edge = v1 - v0;
+                let length = edge.length();
+                let Some(perpendicular) = edge.cross(normal).try_normalize() else {
+                    continue;
+                };
+
+                let quadric = Quadric::plane(perpendicular, v0, length * length * weight);
+                self.quadrics[p0 as usize].add(&quadric);
+                self.quadrics[p1 as usize].add(&quadric);
+            }
+        }
+    }
+
+    /// Collapses edges until the target triangle count or error is reached.
+    fn run(&mut self, mut indices: Vec<u32>, target: usize, error: f64) -> Vec<u32> {
+        let limit = error * error;
+
+        while indices.len() / 3 > target {
+            let vertex_edges = edge_counts(&indices, |i| i);
+            let collapses = self.pick_collapses(&indices, &vertex_edges, limit);
+            if collapses.is_empty() {
+                break;
+            }
+
+            let mut adjacency = vec![Vec::new(); self.positions.len()];
+            for (t, triangle) in indices.chunks_exact(3).enumerate() {
+                for &i in triangle {
+                    adjacency[self.canonical[i as usize] as usize].push(t);
+                }
+            }
+
+            let mut remap = (0..index_u32(self.positions.len())).collect::<Vec<_>>();
+            let mut locked = vec![false; self.positions.len()];
+            let mut triangles = indices.len() / 3;
+
+            for collapse in collapses {
+                if triangles <= target {
+                    break;
+                }
+
+                let from = self.canonical[collapse.from as usize];
+                let to = self.canonical[collapse.to as usize];
+
+                if locked[from as usize]
+                    || locked[to as usize]
+                    || self.has_flips(&indices, &adjacency[from as usize], from, to)
+                {
+                    continue;
+                }
+
+                let Some(pairs) = self.collapse_pairs(&vertex_edges, collapse.from, collapse.to)
+                else {
+                    continue;
+                };
+
+                for (v0, v1) in pairs {
+                    remap[v0 as usize] = v1;
+                }
+
+                let quadric = self.quadrics[from as usize];
+                self.quadrics[to as usize].add(&quadric);
+
+                locked[from as usize] = true;
+                locked[to as usize] = true;
+
+                triangles -= adjacency[from as usize]
+                    .iter()
+                    .filter(|&&t| {
+                        indices[t * 3..t * 3 + 3]
+                            .iter()
+                            .any(|&i| self.canonical[i as usize] == to)
+                    })
+                    .count();
+            }
+
+            if !locked.contains(&true) {
+                break;
+            }
+
+            indices = indices
+                .chunks_exact(3)
+                .map(|triangle| {
+                    triangle
+                        .iter()
+                        .map(|&i| remap[i as usize])
+                        .collect::<Vec<_>>()
+                })
+                .filter(|triangle| {
+                    let [a, b, c] = [0, 1, 2].map(|i| self.canonical[triangle[i] as usize]);
+                    a != b && b != c && c != a
+                })
+                .flatten()
+                .collect();
+        }
+
+        indices
+    }
+
+    /// The cheapest allowed collapse of each position, sorted by cost.
+    fn pick_collapses(
+        &self,
+        indices: &[u32],
+        vertex_edges: &HashMap<(u32, u32), u32>,
+        limit: f64,
+    ) -> Vec<Collapse> {
+        let position_edges = edge_counts(indices, |i| self.canonical[i as usize]);
+        let mut best = HashMap::<u32, Collapse>::new();
+
+        for triangle in indices.chunks_exact(3) {
+            for (a, b) in triangle_edges(triangle) {
+                for (from, to) in [(a, b), (b, a)] {
+                    let p0 = self.canonical[from as usize];
+                    let p1 = self.canonical[to as usize];
+
+                    let allowed = match self.kinds[p0 as usize] {
+                        VertexKind::Manifold => true,
+                        VertexKind::Border => position_edges[&edge_key(p0, p1)] == 1,
+                        VertexKind::Seam => vertex_edges[&edge_key(from, to)] == 1,
+                        VertexKind::Locked => false,
+                    };
+
+                    if !allowed {
+                        continue;
+                    }
+
+                    let cost = self.quadrics[p0 as usize].error(self.positions[p1 as usize]);
+                    if cost > limit {
+                        continue;
+                    }
+
+                    match best.entry(p0) {
+                        Entry::Occupied(mut entry) if entry.get().cost > cost => {
+                            entry.insert(Collapse { cost, from, to });
+                        }
+                        Entry::Occupied(_) => {}
+                        Entry::Vacant(entry) => {
+                            entry.insert(Collapse { cost, from, to });
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut collapses = best.into_values().collect::<Vec<_>>();
+        collapses.sort_by(|a, b| a.cost.total_cmp(&b.cost).then(a.from.cmp(&b.from)));
+        collapses
+    }
+
+    /// Pairs of vertices to merge when collapsing `from` into `to`.
+    /// Seam vertices also move their twin along the matching seam edge.
+    fn collapse_pairs(
+        &self,
+        vertex_edges: &HashMap<(u32, u32), u32>,
+        from: u32,
+        to: u32,
+    ) -> Option<Vec<(u32, u32)>> {
+        let p0 = self.canonical[from as usize];
+
+        if self.kinds[p0 as usize] != VertexKind::Seam {
+            return Some(vec![(from, to)]);
+        }
+
+        if vertex_edges.get(&edge_key(from, to)) != Some(&1) {
+            return None;
+        }
+
+        let p1 = self.canonical[to as usize];
+        let twin = *self.wedges[p0 as usize].iter().find(|&&w| w != from)?;
+        let twin_to = *self.wedges[p1 as usize]
+            .iter()
+            .find(|&&w| w != to && vertex_edges.get(&edge_key(twin, w)) == Some(&1))?;
+
+        Some(vec![(from, to), (twin, twin_to)])
+    }
+
+    /// Whether moving position `from` onto `to` would flip any of its triangles.
+    fn has_flips(&self, indices: &[u32], triangles: &[usize], from: u32, to: u32) -> bool {
+        let target = self.positions[to as usize];
+
+        triangles.iter().any(|&t| {
+            let triangle = &indices[t * 3..t * 3 + 3];
+            let ids = [0, 1, 2].map(|i| self.canonical[triangle[i] as usize]);
+
+            if ids.contains(&to) {
+                return false;
+            }
+
+            let [a, b, c] = ids.map(|p| self.positions[p as usize]);
+            let before = (b - a).cross(c - a);
+
+            let [a, b, c] = ids.map(|p| {
+                if p == from {
+                    target
+                } else {
+                    self.positions[p as usize]
+                }
+            });
+            let after = (b - a).cross(c - a);
+
+            before.dot(after) <= 0.0
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::gltf::Mesh;
+
+    use super::{
+        super::geometry::{
+            read_indices,
+            test_utils::{create_positions, read_positions},
+        },
+        *,
+    };
+
+    /// A flat grid of `size` by `size` quads.
+    fn create_grid(graph: &mut Graph, doc: GltfDocument, size: u32) -> Mesh {
+        let mut positions = Vec::new();
+        for y in 0..=size {
+            for x in 0..=size {
+                positions.push([x as f32, y as f32, 0.0]);
+            }
+        }
+
+        let mut indices = Vec::new();
+        for y in 0..size {
+            for x in 0..size {
+                let i = y * (size + 1) + x;
+                let j = i + size + 1;
+                indices.extend([i, i + 1, j + 1, i, j + 1, j]);
+            }
+        }
+
+        let count = positions.len();
+        let positions = create_positions(graph, doc, &positions);
+        let indices = create_indices(graph, doc, &indices, count);
+
+        let mesh = doc.create_mesh(graph);
+        let primitive = mesh.create_primitive(graph);
+        primitive.set_attribute(graph, Semantic::Positions, Some(positions));
+        primitive.set_indices(graph, Some(indices));
+        mesh
+    }
+
+    fn triangle_count(graph: &Graph, mesh: Mesh) -> usize {
+        let primitive = mesh.primitives(graph)[0];
+        let indices = primitive
+            .indices(graph)
+            .expect("primitive should be indexed");
+        read_indices(graph, indices)
+            .expect("indices should be readable")
+            .len()
+            / 3
+    }
+
+    #[test]
+    fn simplify_grid() {
+        let mut graph = Graph::default();
+        let doc = GltfDocument::new(&mut graph);
+        let mesh = create_grid(&mut graph, doc, 4);
+
+        Simplify {
+            ratio: 0.0,
+            ..Default::default()
+        }
+        .apply(&mut graph, doc)
+        .expect("simplify should succeed");
+
+        assert_eq!(triangle_count(&graph, mesh), 2);
+
+        let mut positions = read_positions(&graph, mesh.primitives(&graph)[0]);
+        positions.sort_by(|a, b| a.partial_cmp(b).expect("positions should be finite"));
+        assert_eq!(
+            positions,
+            vec![
+                [0.0, 0.0, 0.0],
+                [0.0, 4.0, 0.0],
+                [4.0, 0.0, 0.0],
+                [4.0, 4.0, 0.0]
+            ]
+        );
+    }
+
+    #[test]
+    fn simplify_error() {
+        let mut graph = Graph::default();
+        let doc = GltfDocument::new(&mut graph);
+        let mesh = create_grid(&mut graph, doc, 4);
+
+        // Raise the center vertex, so collapsing it would move the surface.
+        let primitive = mesh.primitives(&graph)[0];
+        let mut positions = read_positions(&graph, primitive);
+        positions[12][2] = 1.0;
+        let positions = create_positions(&mut graph, doc, &positions);
+        primitive.set_attribute(&mut graph, Semantic::Positions, Some(positions));
+
+        Simplify {
+            ratio: 0.0,
+            error: 0.001,
+            #[cfg(feature = "msft_lod")]
+            lod: false,
+        }
+        .apply(&mut graph, doc)
+        .expect("simplify should succeed");
+
+        assert!(read_positions(&graph, primitive).iter().any(|p| p[2] > 0.0));
+        assert!(triangle_count(&graph, mesh) > 2);
+    }
+
+    #[cfg(feature = "msft_lod")]
+    #[test]
+    fn simplify_lod() {
+        let mut graph = Graph::default();
+        let doc = GltfDocument::new(&mut graph);
+        let mesh = create_grid(&mut graph, doc, 4);
+
+        let mut node = doc.create_node(&mut graph);
+        node.get_mut(&mut graph).name = Some("grid".to_string());
+        node.set_mesh(&mut graph, Some(mesh));
+
+        let child = doc.create_node(&mut graph);
+        node.add_child(&mut graph, &child);
+
+        Simplify {
+            lod: true,
+            ..Default::default()
+        }
+        .apply(&mut graph, doc)
+        .expect("simplify should succeed");
+
+        assert_eq!(triangle_count(&graph, mesh), 32);
+
+        let ext = node
+            .get_extension::<MsftLod>(&graph)
+            .expect("node should have MSFT_lod");
+        let levels = ext.levels::<Node>(&graph);
+        assert_eq!(levels.len(), 1);
+        assert_eq!(levels[0].get(&graph).name.as_deref(), Some("grid_lod1"));
+        assert!(levels[0].children(&graph).is_empty());
+
+        let lod_mesh = levels[0].mesh(&graph).expect("level should have a mesh");
+        assert_ne!(lod_mesh, mesh);
+        assert!(triangle_count(&graph, lod_mesh) <= 16);
+    }
+}
@@ -0,0 +1,242 @@
+//! Generate `MikkTSpace` vertex tangents.
+
+use std::error::Error;
+
+use bevy_mikktspace::{Geometry, TangentSpace, generate_tangents};
+use gltf::json::accessor::Type;
+use tracing::warn;
+
+use crate::graph::{
+    Graph, GraphNodeWeight,
+    gltf::{
+        Primitive,
+        accessor::iter::AccessorIter,
+        document::GltfDocument,
+        primitive::{Mode, Semantic},
+    },
+};
+
+use super::{
+    Transform,
+    geometry::{create_f32_accessor, read_indices_or_sequential, unweld_primitive, vertex_count},
+    weld::Weld,
+};
+
+/// Generates [`MikkTSpace`](http://www.mikktspace.com/) tangents for triangle primitives
+/// from their positions, normals and texture coordinates.
+///
+/// Texture coordinates are taken from the set used by the material's normal texture,
+/// or the first set if it has none. Primitives without normals are skipped, and can be
+/// given them with [`Normals`](super::normals::Normals) first.
+/// Indexed primitives are unwelded to generate tangents, then welded again.
+/// Replaced accessors are left in the document, and can be removed with
+/// [`Prune`](super::prune::Prune).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Tangents {
+    /// Replace existing tangents.
+    pub overwrite: bool,
+}
+
+impl Transform for Tangents {
+    fn name(&self) -> &'static str {
+        "tangents"
+    }
+
+    fn apply(&self, graph: &mut Graph, doc: GltfDocument) -> Result<(), Box<dyn Error>> {
+        for mesh in doc.meshes(graph) {
+            for primitive in mesh.primitives(graph) {
+                if primitive.get(graph).mode != Mode::Triangles
+                    || (!self.overwrite && primitive.attribute(graph, Semantic::Tangents).is_some())
+                {
+                    continue;
+                }
+
+                let Some(count) = vertex_count(graph, primitive) else {
+                    warn!("Primitive attributes have different lengths, skipping tangents");
+                    continue;
+                };
+
+                let indices = read_indices_or_sequential(graph, primitive, count)?;
+
+                if indices.iter().any(|&i| i as usize >= count) {
+                    warn!("Primitive indices out of bounds, skipping tangents");
+                    continue;
+                }
+
+                if indices.len() % 3 != 0 {
+                    warn!("Primitive is not a triangle list, skipping tangents");
+                    continue;
+                }
+
+                let Some(mut geometry) = tangent_inputs(graph, primitive, indices)? else {
+                    continue;
+                };
+
+                generate_tangents(&mut geometry)?;
+
+                let indexed = primitive.indices(graph).is_some();
+
+                if indexed {
+                    unweld_primitive(graph, doc, primitive, &geometry.indices);
+                }
+
+                let data = geometry.tangents.into_iter().flatten().collect::<Vec<_>>();
+
+                let buffer = primitive
+                    .attribute(graph, Semantic::Positions)
+                    .and_then(|positions| positions.buffer(graph));
+                let tangents = create_f32_accessor(graph, doc, buffer, Type::Vec4, &data);
+                primitive.set_attribute(graph, Semantic::Tangents, Some(tangents));
+
+                if indexed {
+                    Weld::default().weld_primitive(graph, doc, primitive)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Vertex data of a triangle list, with a tangent for each index.
+struct TangentGeometry {
+    indices: Vec<u32>,
+    positions: Vec<[f32; 3]>,
+    normals: Vec<[f32; 3]>,
+    uvs: Vec<[f32; 2]>,
+    tangents: Vec<[f32; 4]>,
+}
+
+impl TangentGeometry {
+    fn vertex(&self, face: usize, vert: usize) -> usize {
+        self.indices[face * 3 + vert] as usize
+    }
+}
+
+impl Geometry for TangentGeometry {
+    fn num_faces(&self) -> usize {
+        self.indices.len() / 3
+    }
+
+    fn num_vertices_of_face(&self, _face: usize) -> usize {
+        3
+    }
+
+    fn position(&self, face: usize, vert: usize) -> [f32; 3] {
+        self.positions[self.vertex(face, vert)]
+    }
+
+    fn normal(&self, face: usize, vert: usize) -> [f32; 3] {
+        self.normals[self.vertex(face, vert)]
+    }
+
+    fn tex_coord(&self, face: usize, vert: usize) -> [f32; 2] {
+        self.uvs[self.vertex(face, vert)]
+    }
+
+    fn set_tangent(&mut self, tangent_space: Option<TangentSpace>, face: usize, vert: usize) {
+        self.tangents[face * 3 + vert] = tangent_space
+            .as_ref()
+            .map_or([1.0, 0.0, 0.0, 1.0], TangentSpace::tangent_encoded);
+    }
+}
+
+/// Reads the attributes needed to generate tangents, warning if any are missing.
+fn tangent_inputs(
+    graph: &Graph,
+    primitive: Primitive,
+    indices: Vec<u32>,
+) -> Result<Option<TangentGeometry>, Box<dyn Error>> {
+    let set = primitive
+        .material(graph)
+        .filter(|material| material.normal_texture(graph).is_some())
+        .map_or(0, |material| material.get(graph).normal_tex_coord);
+    let set = u32::try_from(set)?;
+
+    let (Some(positions), Some(normals), Some(uvs)) = (
+        primitive.attribute(graph, Semantic::Positions),
+        primitive.attribute(graph, Semantic::Normals),
+        primitive.attribute(graph, Semantic::TexCoords(set)),
+    ) else {
+        warn!("Primitive is missing positions, normals or texture coordinates, skipping tangents");
+        return Ok(None);
+    };
+
+    let (AccessorIter::F32x3(positions), AccessorIter::F32x3(normals), AccessorIter::F32x2(uvs)) = (
+        positions.to_iter(graph)?,
+        normals.to_iter(graph)?,
+        uvs.to_iter(graph)?,
+    ) else {
+        warn!("Primitive attributes are not f32, skipping tangents");
+        return Ok(None);
+    };
+
+    Ok(Some(TangentGeometry {
+        tangents: vec![[1.0, 0.0, 0.0, 1.0]; indices.len()],
+        indices,
+        positions: positions.collect(),
+        normals: normals.collect(),
+        uvs: uvs.collect(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::geometry::{create_indices, test_utils::create_positions},
+        *,
+    };
+
+    #[test]
+    fn tangents() {
+        let mut graph = Graph::default();
+        let doc = GltfDocument::new(&mut graph);
+
+        let corners = [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]];
+        let positions = create_positions(&mut graph, doc, &corners.map(|[x, y]| [x, y, 0.0]));
+        let normals = create_f32_accessor(
+            &mut graph,
+            doc,
+            None,
+            Type::Vec3,
+            &[0.0, 0.0, 1.0].repeat(4),
+        );
+        let uvs = create_f32_accessor(&mut graph, doc, None, Type::Vec2, corners.as_flattened());
+        let indices = create_indices(&mut graph, doc, &[0, 1, 2, 0, 2, 3], 4);
+
+        let primitive = doc.create_mesh(&mut graph).create_primitive(&mut graph);
+        primitive.set_attribute(&mut graph, Semantic::Positions, Some(positions));
+        primitive.set_attribute(&mut graph, Semantic::Normals, Some(normals));
+        primitive.set_attribute(&mut graph, Semantic::TexCoords(0), Some(uvs));
+        primitive.set_indices(&mut graph, Some(indices));
+
+        Tangents::default()
+            .apply(&mut graph, doc)
+            .expect("tangents should succeed");
+
+        assert_eq!(vertex_count(&graph, primitive), Some(4));
+
+        let tangents = primitive
+            .attribute(&graph, Semantic::Tangents)
+            .expect("primitive should have tangents");
+
+        match tangents
+            .to_iter(&graph)
+            .expect("accessor should be readable")
+        {
+            AccessorIter::F32x4(iter) => {
+                assert_eq!(iter.collect::<Vec<_>>(), vec![[1.0, 0.0, 0.0, 1.0]; 4]);
+            }
+            _ => panic!("tangents should be f32x4"),
+        }
+
+        let existing = primitive.attribute(&graph, Semantic::Tangents);
+        primitive.set_attribute(&mut graph, Semantic::Normals, None);
+
+        Tangents { overwrite: true }
+            .apply(&mut graph, doc)
+            .expect("tangents should succeed");
+
+        assert_eq!(primitive.attribute(&graph, Semantic::Tangents), existing);
+    }
+}
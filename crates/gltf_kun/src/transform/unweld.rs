@@ -0,0 +1,101 @@
+//! Expand indexed geometry.
+
+use std::error::Error;
+
+use tracing::warn;
+
+use crate::graph::{Graph, gltf::document::GltfDocument};
+
+use super::{
+    Transform,
+    geometry::{read_indices, unweld_primitive, vertex_count},
+};
+
+/// Removes indices, duplicating shared vertices so that each index has its own vertex.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Unweld;
+
+impl Transform for Unweld {
+    fn name(&self) -> &'static str {
+        "unweld"
+    }
+
+    fn apply(&self, graph: &mut Graph, doc: GltfDocument) -> Result<(), Box<dyn Error>> {
+        for mesh in doc.meshes(graph) {
+            for primitive in mesh.primitives(graph) {
+                let Some(indices) = primitive.indices(graph) else {
+                    continue;
+                };
+
+                let Some(count) = vertex_count(graph, primitive) else {
+                    warn!("Primitive attributes have different lengths, skipping unweld");
+                    continue;
+                };
+
+                let order = read_indices(graph, indices)?;
+
+                if order.iter().any(|&i| i as usize >= count) {
+                    warn!("Primitive indices out of bounds, skipping unweld");
+                    continue;
+                }
+
+                unweld_primitive(graph, doc, primitive, &order);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::gltf::primitive::Semantic;
+
+    use super::{
+        super::geometry::{
+            create_indices,
+            test_utils::{create_positions, read_positions},
+        },
+        *,
+    };
+
+    #[test]
+    fn unweld() {
+        let mut graph = Graph::default();
+        let doc = GltfDocument::new(&mut graph);
+
+        let positions = create_positions(
+            &mut graph,
+            doc,
+            &[
+                [0.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0],
+                [1.0, 1.0, 0.0],
+                [0.0, 1.0, 0.0],
+            ],
+        );
+        let indices = create_indices(&mut graph, doc, &[0, 1, 2, 0, 2, 3], 4);
+
+        let mesh = doc.create_mesh(&mut graph);
+        let primitive = mesh.create_primitive(&mut graph);
+        primitive.set_attribute(&mut graph, Semantic::Positions, Some(positions));
+        primitive.set_indices(&mut graph, Some(indices));
+
+        Unweld
+            .apply(&mut graph, doc)
+            .expect("unweld should succeed");
+
+        assert!(primitive.indices(&graph).is_none());
+        assert_eq!(
+            read_positions(&graph, primitive),
+            vec![
+                [0.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0],
+                [1.0, 1.0, 0.0],
+                [0.0, 0.0, 0.0],
+                [1.0, 1.0, 0.0],
+                [0.0, 1.0, 0.0]
+            ]
+        );
+    }
+}
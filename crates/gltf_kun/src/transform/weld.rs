@@ -0,0 +1,223 @@
+//! Merge identical vertices.
+
+use std::{
+    collections::{HashMap, hash_map::Entry},
+    error::Error,
+};
+
+use gltf::json::accessor::ComponentType;
+use tracing::{debug, warn};
+
+use crate::graph::{
+    Graph, GraphNodeWeight,
+    gltf::{Primitive, document::GltfDocument, primitive::Mode},
+};
+
+use super::{
+    Transform,
+    geometry::{
+        create_indices, element_size, index_u32, read_indices_or_sequential, remap_vertices,
+        vertex_accessors, vertex_count,
+    },
+};
+
+/// Merges vertices whose attributes and morph targets are identical,
+/// producing indexed geometry.
+///
+/// Point primitives are not welded.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Weld {
+    /// Float components are snapped to a grid of this size before comparison.
+    /// Zero only merges exactly equal vertices.
+    pub tolerance: f32,
+}
+
+impl Transform for Weld {
+    fn name(&self) -> &'static str {
+        "weld"
+    }
+
+    fn apply(&self, graph: &mut Graph, doc: GltfDocument) -> Result<(), Box<dyn Error>> {
+        for mesh in doc.meshes(graph) {
+            for primitive in mesh.primitives(graph) {
+                if primitive.get(graph).mode == Mode::Points {
+                    continue;
+                }
+
+                self.weld_primitive(graph, doc, primitive)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Weld {
+    pub(super) fn weld_primitive(
+        self,
+        graph: &mut Graph,
+        doc: GltfDocument,
+        primitive: Primitive,
+    ) -> Result<(), Box<dyn Error>> {
+        let Some(count) = vertex_count(graph, primitive) else {
+            warn!("Primitive attributes have different lengths, skipping weld");
+            return Ok(());
+        };
+
+        let indices = read_indices_or_sequential(graph, primitive, count)?;
+
+        if indices.iter().any(|&i| i as usize >= count) {
+            warn!("Primitive indices out of bounds, skipping weld");
+            return Ok(());
+        }
+
+        let keys = self.vertex_keys(graph, primitive, count);
+
+        let mut unique = HashMap::new();
+        let mut order = Vec::new();
+        let mut remap = Vec::with_capacity(count);
+
+        for (vertex, key) in keys.into_iter().enumerate() {
+            let index = match unique.entry(key) {
+                Entry::Occupied(entry) => *entry.get(),
+                Entry::Vacant(entry) => {
+                    order.push(index_u32(vertex));
+                    *entry.insert(index_u32(order.len() - 1))
+                }
+            };
+            remap.push(index);
+        }
+
+        if order.len() == count && primitive.indices(graph).is_some() {
+            return Ok(());
+        }
+
+        debug!("Welded {} vertices to {}", count, order.len());
+
+        let indices = indices
+            .iter()
+            .map(|&i| remap[i as usize])
+            .collect::<Vec<_>>();
+
+        remap_vertices(graph, doc, primitive, &order);
+
+        let indices = create_indices(graph, doc, &indices, order.len());
+        primitive.set_indices(graph, Some(indices));
+
+        Ok(())
+    }
+
+    /// Builds a byte key for each vertex from every attribute.
+    fn vertex_keys(self, graph: &Graph, primitive: Primitive, count: usize) -> Vec<Vec<u8>> {
+        let mut keys = vec![Vec::new(); count];
+
+        for (_, accessor) in vertex_accessors(graph, primitive) {
+            let size = element_size(graph, accessor);
+            let weight = accessor.get(graph);
+            let quantize = self.tolerance > 0.0 && weight.component_type == ComponentType::F32;
+
+            for (key, element) in keys.iter_mut().zip(weight.data.chunks_exact(size)) {
+                if quantize {
+                    for component in element.chunks_exact(4) {
+                        let value = f32::from_le_bytes(
+                            component.try_into().expect("component should be 4 bytes"),
+                        );
+                        // Adding zero folds -0.0 into 0.0.
+                        let snapped = (value / self.tolerance).round() + 0.0;
+                        key.extend(snapped.to_bits().to_le_bytes());
+                    }
+                } else {
+                    key.extend_from_slice(element);
+                }
+            }
+        }
+
+        keys
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::gltf::primitive::Semantic;
+
+    use super::{
+        super::geometry::{
+            read_indices,
+            test_utils::{create_positions, read_positions},
+        },
+        *,
+    };
+
+    /// Two triangles forming a quad, without indices.
+    fn create_quad(graph: &mut Graph, doc: GltfDocument, offset: f32) -> Primitive {
+        let positions = create_positions(
+            graph,
+            doc,
+            &[
+                [0.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0],
+                [1.0, 1.0, 0.0],
+                [0.0, 0.0, 0.0],
+                [1.0, 1.0, 0.0],
+                [0.0, 1.0 + offset, 0.0],
+            ],
+        );
+
+        let mesh = doc.create_mesh(graph);
+        let primitive = mesh.create_primitive(graph);
+        primitive.set_attribute(graph, Semantic::Positions, Some(positions));
+        primitive
+    }
+
+    #[test]
+    fn weld_exact() {
+        let mut graph = Graph::default();
+        let doc = GltfDocument::new(&mut graph);
+        let primitive = create_quad(&mut graph, doc, 0.0);
+
+        Weld::default()
+            .apply(&mut graph, doc)
+            .expect("weld should succeed");
+
+        let indices = primitive
+            .indices(&graph)
+            .expect("primitive should be indexed");
+        assert_eq!(
+            read_indices(&graph, indices).expect("indices should be readable"),
+            vec![0, 1, 2, 0, 2, 3]
+        );
+        assert_eq!(
+            read_positions(&graph, primitive),
+            vec![
+                [0.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0],
+                [1.0, 1.0, 0.0],
+                [0.0, 1.0, 0.0]
+            ]
+        );
+    }
+
+    #[test]
+    fn weld_tolerance() {
+        let mut graph = Graph::default();
+        let doc = GltfDocument::new(&mut graph);
+        let primitive = create_quad(&mut graph, doc, 1e-6);
+        let mut points = doc.create_mesh(&mut graph).create_primitive(&mut graph);
+        points.get_mut(&mut graph).mode = Mode::Points;
+
+        Weld { tolerance: 1e-4 }
+            .apply(&mut graph, doc)
+            .expect("weld should succeed");
+
+        assert_eq!(read_positions(&graph, primitive).len(), 4);
+        assert!(points.indices(&graph).is_none());
+
+        let count = doc.accessors(&graph).len();
+
+        Weld { tolerance: 1e-4 }
+            .apply(&mut graph, doc)
+            .expect("weld should succeed");
+
+        assert_eq!(doc.accessors(&graph).len(), count);
+    }
+}
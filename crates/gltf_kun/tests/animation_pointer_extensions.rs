@@ -0,0 +1,150 @@
+use gltf_kun::{
+    extensions::{
+        DefaultExtensions,
+        khr_animation_pointer::{KhrAnimationPointer, KhrAnimationPointerWeight, PointerTarget},
+    },
+    graph::{
+        ByteNode, Extensions, Graph, GraphNodeWeight,
+        gltf::{
+            Accessor,
+            accessor::{ComponentType, Type},
+            animation::{AnimationSampler, TargetPath},
+            document::GltfDocument,
+        },
+    },
+    io::format::glb::{GlbExport, GlbImport},
+};
+use tracing_test::traced_test;
+
+fn create_accessor(
+    graph: &mut Graph,
+    doc: GltfDocument,
+    element_type: Type,
+    data: &[f32],
+) -> Accessor {
+    let mut accessor = doc.create_accessor(graph);
+    let weight = accessor.get_mut(graph);
+    weight.component_type = ComponentType::F32;
+    weight.element_type = element_type;
+    weight.data = data.iter().flat_map(|v| v.to_le_bytes()).collect();
+    accessor
+}
+
+fn create_sampler(
+    graph: &mut Graph,
+    doc: GltfDocument,
+    element_type: Type,
+    output: &[f32],
+) -> AnimationSampler {
+    let input = create_accessor(graph, doc, Type::Scalar, &[0.0, 1.0]);
+    let output = create_accessor(graph, doc, element_type, output);
+
+    let sampler = AnimationSampler::new(graph);
+    sampler.set_input(graph, Some(input));
+    sampler.set_output(graph, Some(output));
+    sampler
+}
+
+#[tokio::test]
+#[traced_test]
+async fn main() {
+    let mut graph = Graph::default();
+    let doc = GltfDocument::new(&mut graph);
+
+    let scene = doc.create_scene(&mut graph);
+    let node = doc.create_node(&mut graph);
+    scene.add_node(&mut graph, node);
+
+    let _ = doc.create_material(&mut graph);
+    let material = doc.create_material(&mut graph);
+
+    let animation = doc.create_animation(&mut graph);
+
+    // Regular node channel.
+    let node_sampler = create_sampler(&mut graph, doc, Type::Vec3, &[0.0; 6]);
+    let mut channel = animation.create_channel(&mut graph);
+    channel.get_mut(&mut graph).path = TargetPath::Translation;
+    channel.set_target(&mut graph, Some(node));
+    channel.set_sampler(&mut graph, Some(node_sampler));
+
+    // Pointer relative to a material.
+    let sampler = create_sampler(&mut graph, doc, Type::Vec3, &[0.0, 0.0, 0.0, 1.0, 0.5, 0.0]);
+    let channel = animation.create_channel(&mut graph);
+    channel.set_sampler(&mut graph, Some(sampler));
+    let ext = channel.create_extension::<KhrAnimationPointer>(&mut graph);
+    ext.write(
+        &mut graph,
+        &KhrAnimationPointerWeight {
+            pointer: "/emissiveFactor".to_string(),
+        },
+    );
+    ext.set_target(&mut graph, Some(PointerTarget::Material(material)));
+
+    // Absolute pointer to an object outside the graph.
+    let sampler = create_sampler(&mut graph, doc, Type::Scalar, &[0.5, 1.0]);
+    let channel = animation.create_channel(&mut graph);
+    channel.set_sampler(&mut graph, Some(sampler));
+    let ext = channel.create_extension::<KhrAnimationPointer>(&mut graph);
+    ext.write(
+        &mut graph,
+        &KhrAnimationPointerWeight {
+            pointer: "/cameras/0/perspective/yfov".to_string(),
+        },
+    );
+
+    // Pointer sharing the sampler of the node channel.
+    let channel = animation.create_channel(&mut graph);
+    channel.set_sampler(&mut graph, Some(node_sampler));
+    let ext = channel.create_extension::<KhrAnimationPointer>(&mut graph);
+    ext.write(
+        &mut graph,
+        &KhrAnimationPointerWeight {
+            pointer: "/scale".to_string(),
+        },
+    );
+    ext.set_target(&mut graph, Some(PointerTarget::Node(node)));
+
+    let glb =
+        GlbExport::<DefaultExtensions>::export(&mut graph, &doc).expect("Failed to export glb");
+
+    let mut graph = Graph::default();
+    let doc = GlbImport::<DefaultExtensions>::import_slice(&mut graph, &glb.0)
+        .await
+        .expect("Failed to import glb");
+
+    let animation = doc.animations(&graph)[0];
+    let channels = animation.channels(&graph);
+    assert_eq!(channels.len(), 4);
+
+    let pointers = channels
+        .iter()
+        .filter_map(|c| c.get_extension::<KhrAnimationPointer>(&graph))
+        .collect::<Vec<_>>();
+    assert_eq!(pointers.len(), 3);
+
+    let material = doc.materials(&graph)[1];
+    assert_eq!(
+        pointers[0].target(&graph),
+        Some(PointerTarget::Material(material))
+    );
+    assert_eq!(pointers[0].read(&graph).pointer, "/emissiveFactor");
+    assert_eq!(
+        pointers[0].resolve(&graph, &doc).as_deref(),
+        Some("/materials/1/emissiveFactor")
+    );
+
+    assert_eq!(pointers[1].target(&graph), None);
+    assert_eq!(
+        pointers[1].read(&graph).pointer,
+        "/cameras/0/perspective/yfov"
+    );
+
+    let output = channels[1]
+        .sampler(&graph)
+        .and_then(|s| s.output(&graph))
+        .expect("pointer channel should have an output");
+    assert_eq!(output.get(&graph).element_type, Type::Vec3);
+
+    assert!(channels[0].sampler(&graph).is_some());
+    assert_eq!(channels[0].sampler(&graph), channels[3].sampler(&graph));
+}
@@ -0,0 +1,114 @@
+use gltf_kun::{
+    extensions::{
+        DefaultExtensions,
+        khr_audio_emitter::{
+            KhrAudioEmitter, add_scene_emitter,
+            audio_data::AudioDataWeight,
+            audio_emitter::{AudioEmitter, AudioEmitterWeight, EmitterType},
+            audio_source::AudioSourceWeight,
+            scene_emitters,
+        },
+    },
+    graph::{ByteNode, Extensions, Graph, gltf::document::GltfDocument},
+    io::format::glb::{GlbExport, GlbImport},
+};
+use tracing_test::traced_test;
+
+const AUDIO_DATA: &[u8] = b"OggS audio";
+
+#[tokio::test]
+#[traced_test]
+async fn main() {
+    let mut graph = Graph::default();
+    let doc = GltfDocument::new(&mut graph);
+    doc.create_buffer(&mut graph);
+
+    let ext = doc.create_extension::<KhrAudioEmitter>(&mut graph);
+
+    let audio = ext.create_audio(
+        &mut graph,
+        &AudioDataWeight {
+            data: AUDIO_DATA.to_vec(),
+            ..Default::default()
+        },
+    );
+
+    let source = ext.create_source(
+        &mut graph,
+        &AudioSourceWeight {
+            auto_play: true,
+            looping: true,
+            ..Default::default()
+        },
+    );
+    source.set_audio(&mut graph, Some(audio));
+
+    let emitter = ext.create_emitter(
+        &mut graph,
+        &AudioEmitterWeight {
+            gain: 0.5,
+            ..Default::default()
+        },
+    );
+    emitter.add_source(&mut graph, source);
+
+    let node = doc.create_node(&mut graph);
+    node.add_extension(&mut graph, emitter);
+
+    let ambient = ext.create_emitter(
+        &mut graph,
+        &AudioEmitterWeight {
+            typ: EmitterType::Global,
+            ..Default::default()
+        },
+    );
+    ambient.add_source(&mut graph, source);
+
+    let scene = doc.create_scene(&mut graph);
+    scene.add_node(&mut graph, node);
+    add_scene_emitter(&mut graph, scene, ambient);
+
+    // Round trip through glb, so the audio is stored in the binary buffer.
+    let glb =
+        GlbExport::<DefaultExtensions>::export(&mut graph, &doc).expect("Failed to export glb");
+
+    let mut graph = Graph::default();
+    let doc = GlbImport::<DefaultExtensions>::import_slice(&mut graph, &glb.0)
+        .await
+        .expect("Failed to import glb");
+
+    let ext = doc
+        .get_extension::<KhrAudioEmitter>(&graph)
+        .expect("KHR_audio_emitter extension not found");
+
+    let audio = ext.audio(&graph);
+    assert_eq!(audio.len(), 1);
+
+    let audio_weight = audio[0].read(&graph);
+    assert_eq!(audio_weight.data, AUDIO_DATA);
+    assert_eq!(audio_weight.mime_type.as_deref(), Some("audio/ogg"));
+    assert!(audio[0].buffer(&graph).is_some());
+
+    let nodes = doc.nodes(&graph);
+    let emitter = nodes[0]
+        .get_extension::<AudioEmitter>(&graph)
+        .expect("Node has no emitter");
+
+    let emitter_weight = emitter.read(&graph);
+    assert_eq!(emitter_weight.typ, EmitterType::Positional);
+    assert!((emitter_weight.gain - 0.5).abs() < f32::EPSILON);
+
+    let sources = emitter.sources(&graph);
+    assert_eq!(sources.len(), 1);
+
+    let source_weight = sources[0].read(&graph);
+    assert!(source_weight.auto_play);
+    assert!(source_weight.looping);
+    assert_eq!(sources[0].audio(&graph), Some(audio[0]));
+
+    let scenes = doc.scenes(&graph);
+    let ambient = scene_emitters(&graph, scenes[0]);
+    assert_eq!(ambient.len(), 1);
+    assert_eq!(ambient[0].read(&graph).typ, EmitterType::Global);
+    assert_eq!(ambient[0].sources(&graph), sources);
+}
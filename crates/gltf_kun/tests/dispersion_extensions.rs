@@ -0,0 +1,48 @@
+use gltf_kun::{
+    extensions::{
+        DefaultExtensions,
+        khr_materials_dispersion::{KhrMaterialsDispersion, weight::DispersionWeight},
+    },
+    graph::{ByteNode, Extensions, Graph, gltf::document::GltfDocument},
+    io::format::glb::{GlbExport, GlbImport},
+};
+use tracing_test::traced_test;
+
+#[tokio::test]
+#[traced_test]
+async fn main() {
+    let mut graph = Graph::default();
+    let doc = GltfDocument::new(&mut graph);
+
+    let scene = doc.create_scene(&mut graph);
+    let node = doc.create_node(&mut graph);
+    scene.add_node(&mut graph, node);
+
+    doc.create_material(&mut graph);
+    let material = doc.create_material(&mut graph);
+
+    let weight = DispersionWeight { dispersion: 0.3 };
+    let ext = material.create_extension::<KhrMaterialsDispersion>(&mut graph);
+    ext.write(&mut graph, &weight);
+
+    let glb =
+        GlbExport::<DefaultExtensions>::export(&mut graph, &doc).expect("Failed to export glb");
+
+    let mut graph = Graph::default();
+    let doc = GlbImport::<DefaultExtensions>::import_slice(&mut graph, &glb.0)
+        .await
+        .expect("Failed to import glb");
+
+    let materials = doc.materials(&graph);
+    assert_eq!(materials.len(), 2);
+    assert!(
+        materials[0]
+            .get_extension::<KhrMaterialsDispersion>(&graph)
+            .is_none()
+    );
+
+    let ext = materials[1]
+        .get_extension::<KhrMaterialsDispersion>(&graph)
+        .expect("KHR_materials_dispersion extension not found");
+    assert_eq!(ext.read(&graph), weight);
+}
@@ -0,0 +1,64 @@
+use gltf_kun::{
+    extensions::{
+        DefaultExtensions,
+        ext_lights_image_based::{ExtLightsImageBased, light::ImageBasedLightWeight},
+    },
+    graph::{ByteNode, Extensions, Graph, gltf::document::GltfDocument},
+    io::format::glb::{GlbExport, GlbImport},
+};
+use tracing_test::traced_test;
+
+#[tokio::test]
+#[traced_test]
+async fn main() {
+    let mut graph = Graph::default();
+    let doc = GltfDocument::new(&mut graph);
+
+    let scene = doc.create_scene(&mut graph);
+    let node = doc.create_node(&mut graph);
+    scene.add_node(&mut graph, node);
+
+    let images = (0..12)
+        .map(|_| doc.create_image(&mut graph))
+        .collect::<Vec<_>>();
+
+    let weight = ImageBasedLightWeight {
+        name: Some("Studio".to_string()),
+        rotation: [0.0, 1.0, 0.0, 0.0],
+        intensity: 2.5,
+        irradiance_coefficients: vec![[0.5, 0.25, 0.125]; 9],
+        specular_image_size: 2,
+    };
+
+    let ext = doc.create_extension::<ExtLightsImageBased>(&mut graph);
+    let light = ext.create_light(&mut graph, &weight);
+    let mips = [
+        images[0..6].try_into().expect("6 faces"),
+        images[6..12].try_into().expect("6 faces"),
+    ];
+    light.set_specular_images(&mut graph, &mips);
+    scene.add_extension(&mut graph, light);
+
+    let glb =
+        GlbExport::<DefaultExtensions>::export(&mut graph, &doc).expect("Failed to export glb");
+
+    let mut graph = Graph::default();
+    let doc = GlbImport::<DefaultExtensions>::import_slice(&mut graph, &glb.0)
+        .await
+        .expect("Failed to import glb");
+
+    let ext = doc
+        .get_extension::<ExtLightsImageBased>(&graph)
+        .expect("EXT_lights_image_based extension not found");
+    let lights = ext.lights(&graph);
+    assert_eq!(lights.len(), 1);
+    assert_eq!(lights[0].read(&graph), weight);
+
+    let images = doc.images(&graph);
+    let mips = lights[0].specular_images(&graph);
+    assert_eq!(mips.len(), 2);
+    assert_eq!(mips[1][0], images[6]);
+
+    let scene = doc.scenes(&graph)[0];
+    assert_eq!(scene.get_extension(&graph), Some(lights[0]));
+}
@@ -0,0 +1,75 @@
+use gltf::json::accessor::Type;
+use gltf_kun::{
+    extensions::{
+        DefaultExtensions,
+        ext_mesh_gpu_instancing::{ExtMeshGpuInstancing, ROTATION, TRANSLATION},
+    },
+    graph::{
+        Extensions, Graph, GraphNodeWeight,
+        gltf::{Accessor, document::GltfDocument},
+    },
+    io::format::glb::{GlbExport, GlbImport},
+};
+use tracing_test::traced_test;
+
+fn create_accessor(
+    graph: &mut Graph,
+    doc: GltfDocument,
+    element_type: Type,
+    data: &[f32],
+) -> Accessor {
+    let mut accessor = doc.create_accessor(graph);
+    let weight = accessor.get_mut(graph);
+    weight.element_type = element_type;
+    weight.data = data.iter().flat_map(|v| v.to_le_bytes()).collect();
+    accessor
+}
+
+#[tokio::test]
+#[traced_test]
+async fn main() {
+    let mut graph = Graph::default();
+    let doc = GltfDocument::new(&mut graph);
+
+    let scene = doc.create_scene(&mut graph);
+    let node = doc.create_node(&mut graph);
+    scene.add_node(&mut graph, node);
+
+    let translation = create_accessor(&mut graph, doc, Type::Vec3, &[0.0, 0.0, 0.0, 1.0, 2.0, 3.0]);
+    let rotation = create_accessor(
+        &mut graph,
+        doc,
+        Type::Vec4,
+        &[0.0, 0.0, 0.0, 1.0, 0.0, 1.0, 0.0, 0.0],
+    );
+
+    let ext = node.create_extension::<ExtMeshGpuInstancing>(&mut graph);
+    ext.set_attribute(&mut graph, TRANSLATION, Some(translation));
+    ext.set_attribute(&mut graph, ROTATION, Some(rotation));
+
+    let glb =
+        GlbExport::<DefaultExtensions>::export(&mut graph, &doc).expect("Failed to export glb");
+
+    let mut graph = Graph::default();
+    let doc = GlbImport::<DefaultExtensions>::import_slice(&mut graph, &glb.0)
+        .await
+        .expect("Failed to import glb");
+
+    let nodes = doc.nodes(&graph);
+    assert_eq!(nodes.len(), 1);
+
+    let ext = nodes[0]
+        .get_extension::<ExtMeshGpuInstancing>(&graph)
+        .expect("EXT_mesh_gpu_instancing extension not found");
+
+    let attributes = ext.attributes(&graph);
+    assert_eq!(attributes.len(), 2);
+    assert_eq!(attributes[0].0, ROTATION);
+    assert_eq!(attributes[1].0, TRANSLATION);
+
+    let translation = ext
+        .attribute(&graph, TRANSLATION)
+        .expect("TRANSLATION attribute not found");
+    assert_eq!(translation.count(&graph), 2);
+    assert_eq!(translation.get(&graph).element_type, Type::Vec3);
+}
@@ -0,0 +1,49 @@
+use gltf_kun::{
+    extensions::{
+        DefaultExtensions,
+        omi_link::{OmiLink, weight::OmiLinkWeight},
+    },
+    graph::{ByteNode, Extensions, Graph, gltf::document::GltfDocument},
+    io::format::glb::{GlbExport, GlbImport},
+};
+use tracing_test::traced_test;
+
+#[tokio::test]
+#[traced_test]
+async fn main() {
+    let mut graph = Graph::default();
+    let doc = GltfDocument::new(&mut graph);
+
+    let scene = doc.create_scene(&mut graph);
+
+    let portal = doc.create_node(&mut graph);
+    scene.add_node(&mut graph, portal);
+
+    let other = doc.create_node(&mut graph);
+    scene.add_node(&mut graph, other);
+
+    let weight = OmiLinkWeight {
+        uri: "https://example.com/world.gltf".to_string(),
+        title: Some("Example World".to_string()),
+    };
+    let ext = portal.create_extension::<OmiLink>(&mut graph);
+    ext.write(&mut graph, &weight);
+
+    let glb =
+        GlbExport::<DefaultExtensions>::export(&mut graph, &doc).expect("Failed to export glb");
+
+    let mut graph = Graph::default();
+    let doc = GlbImport::<DefaultExtensions>::import_slice(&mut graph, &glb.0)
+        .await
+        .expect("Failed to import glb");
+
+    let nodes = doc.nodes(&graph);
+    assert_eq!(nodes.len(), 2);
+
+    let ext = nodes[0]
+        .get_extension::<OmiLink>(&graph)
+        .expect("OMI_link extension not found");
+    assert_eq!(ext.read(&graph), weight);
+
+    assert!(nodes[1].get_extension::<OmiLink>(&graph).is_none());
+}
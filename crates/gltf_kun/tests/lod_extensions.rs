@@ -0,0 +1,66 @@
+use gltf_kun::{
+    extensions::{
+        DefaultExtensions,
+        msft_lod::{MsftLod, weight::MsftLodWeight},
+    },
+    graph::{
+        ByteNode, Extensions, Graph, GraphNodeWeight,
+        gltf::{Material, Node, document::GltfDocument},
+    },
+    io::format::glb::{GlbExport, GlbImport},
+};
+use tracing_test::traced_test;
+
+#[tokio::test]
+#[traced_test]
+async fn main() {
+    let mut graph = Graph::default();
+    let doc = GltfDocument::new(&mut graph);
+
+    let mut lod_0 = doc.create_node(&mut graph);
+    let lod_2 = doc.create_node(&mut graph);
+    let lod_1 = doc.create_node(&mut graph);
+
+    lod_0.get_mut(&mut graph).extras =
+        Some(serde_json::value::to_raw_value(&serde_json::json!({ "foo": 1 })).expect("extras"));
+
+    let ext = lod_0.create_extension::<MsftLod>(&mut graph);
+    ext.write(
+        &mut graph,
+        &MsftLodWeight {
+            screen_coverage: vec![0.5, 0.2, 0.01],
+        },
+    );
+    ext.set_levels(&mut graph, &[lod_1, lod_2]);
+
+    let material_0 = doc.create_material(&mut graph);
+    let material_1 = doc.create_material(&mut graph);
+
+    let ext = material_0.create_extension::<MsftLod>(&mut graph);
+    ext.set_levels(&mut graph, &[material_1]);
+
+    let glb =
+        GlbExport::<DefaultExtensions>::export(&mut graph, &doc).expect("Failed to export glb");
+
+    let mut graph = Graph::default();
+    let doc = GlbImport::<DefaultExtensions>::import_slice(&mut graph, &glb.0)
+        .await
+        .expect("Failed to import glb");
+
+    let nodes = doc.nodes(&graph);
+    let ext = nodes[0]
+        .get_extension::<MsftLod>(&graph)
+        .expect("MSFT_lod extension not found");
+
+    assert_eq!(ext.levels::<Node>(&graph), vec![nodes[2], nodes[1]]);
+    assert_eq!(ext.read(&graph).screen_coverage, vec![0.5, 0.2, 0.01]);
+
+    let extras = nodes[0].get(&graph).extras.as_ref().expect("extras");
+    assert_eq!(extras.get(), r#"{"foo":1}"#);
+
+    let materials = doc.materials(&graph);
+    let ext = materials[0]
+        .get_extension::<MsftLod>(&graph)
+        .expect("MSFT_lod extension not found");
+    assert_eq!(ext.levels::<Material>(&graph), vec![materials[1]]);
+}
@@ -0,0 +1,124 @@
+use gltf_kun::{
+    extensions::{
+        DefaultExtensions,
+        ext_mesh_features::{
+            ExtMeshFeatures,
+            feature_id::{FeatureIdWeight, feature_id_semantic},
+        },
+    },
+    graph::{
+        ByteNode, Extensions, Graph,
+        gltf::{
+            accessor::{Accessor, ComponentType, Type, iter::AccessorIter},
+            document::GltfDocument,
+            primitive::Semantic,
+        },
+    },
+    io::format::glb::{GlbExport, GlbImport},
+};
+use tracing_test::traced_test;
+
+#[tokio::test]
+#[traced_test]
+async fn main() {
+    let mut graph = Graph::default();
+    let doc = GltfDocument::new(&mut graph);
+
+    let scene = doc.create_scene(&mut graph);
+    let node = doc.create_node(&mut graph);
+    scene.add_node(&mut graph, node);
+
+    let mesh = doc.create_mesh(&mut graph);
+    node.set_mesh(&mut graph, Some(mesh));
+    let primitive = mesh.create_primitive(&mut graph);
+
+    let buffer = doc.create_buffer(&mut graph);
+
+    let positions = [0.0f32; 9]
+        .iter()
+        .flat_map(|p| p.to_le_bytes())
+        .collect::<Vec<_>>();
+    let positions = Accessor::from_iter(
+        &mut graph,
+        AccessorIter::new(&positions, ComponentType::F32, Type::Vec3, false)
+            .expect("positions should be valid"),
+    );
+    positions.set_buffer(&mut graph, Some(buffer));
+    doc.add_accessor(&mut graph, positions);
+    primitive.set_attribute(&mut graph, Semantic::Positions, Some(positions));
+
+    let ids = Accessor::from_iter(
+        &mut graph,
+        AccessorIter::new(&[0, 1, 1], ComponentType::U8, Type::Scalar, false)
+            .expect("ids should be valid"),
+    );
+    ids.set_buffer(&mut graph, Some(buffer));
+    doc.add_accessor(&mut graph, ids);
+    primitive.set_attribute(&mut graph, feature_id_semantic(0), Some(ids));
+
+    let image = doc.create_image(&mut graph);
+    let texture = doc.create_texture(&mut graph);
+    texture.set_image(&mut graph, Some(image));
+
+    let features = primitive.create_extension::<ExtMeshFeatures>(&mut graph);
+
+    let attribute_weight = FeatureIdWeight {
+        feature_count: 2,
+        attribute: Some(0),
+        property_table: Some(0),
+        ..Default::default()
+    };
+    features.create_feature_id(&mut graph, &attribute_weight);
+
+    let texture_weight = FeatureIdWeight {
+        feature_count: 2,
+        null_feature_id: Some(255),
+        channels: vec![0, 1],
+        ..Default::default()
+    };
+    let texture_id = features.create_feature_id(&mut graph, &texture_weight);
+    texture_id.set_texture(&mut graph, Some(texture));
+
+    let implicit_weight = FeatureIdWeight {
+        feature_count: 3,
+        ..Default::default()
+    };
+    features.create_feature_id(&mut graph, &implicit_weight);
+
+    let glb =
+        GlbExport::<DefaultExtensions>::export(&mut graph, &doc).expect("Failed to export glb");
+
+    let mut graph = Graph::default();
+    let doc = GlbImport::<DefaultExtensions>::import_slice(&mut graph, &glb.0)
+        .await
+        .expect("Failed to import glb");
+
+    let primitive = doc.meshes(&graph)[0].primitives(&graph)[0];
+    let texture = doc.textures(&graph)[0];
+
+    let features = primitive
+        .get_extension::<ExtMeshFeatures>(&graph)
+        .expect("EXT_mesh_features extension not found");
+    let feature_ids = features.feature_ids(&graph);
+    assert_eq!(feature_ids.len(), 3);
+
+    assert_eq!(feature_ids[0].read(&graph), attribute_weight);
+    assert_eq!(
+        feature_ids[0]
+            .read_ids(&graph, primitive)
+            .expect("ids should read"),
+        vec![0, 1, 1]
+    );
+
+    assert_eq!(feature_ids[1].read(&graph), texture_weight);
+    assert_eq!(feature_ids[1].texture(&graph), Some(texture));
+    assert!(feature_ids[1].read_ids(&graph, primitive).is_err());
+
+    assert_eq!(feature_ids[2].read(&graph), implicit_weight);
+    assert_eq!(
+        feature_ids[2]
+            .read_ids(&graph, primitive)
+            .expect("ids should read"),
+        vec![0, 1, 2]
+    );
+}
@@ -3,7 +3,10 @@ use std::path::Path;
 use gltf_kun::{
     extensions::{
         DefaultExtensions, Extension,
-        omi_physics_body::{OmiPhysicsBody, weight::BodyType},
+        omi_physics_body::{
+            OmiPhysicsBody,
+            weight::{BodyType, CombineMode},
+        },
         omi_physics_shape::{
             OmiPhysicsShape,
             physics_shape::{BoxShape, PhysicsShapeWeight, Size},
@@ -81,6 +84,20 @@ fn validate_doc(graph: &Graph, doc: GltfDocument) {
         } else {
             let collider = body_ext.collider(graph).expect("No collider");
             assert_eq!(collider, shape);
+
+            let weight = body_ext.read(graph);
+
+            let material = weight.physics_material.expect("No physics material");
+            assert!((material.static_friction - 0.8).abs() < f32::EPSILON);
+            assert!((material.dynamic_friction - 0.5).abs() < f32::EPSILON);
+            assert!((material.restitution - 0.25).abs() < f32::EPSILON);
+            assert_eq!(material.friction_combine, Some(CombineMode::Maximum));
+            assert_eq!(material.restitution_combine, None);
+
+            let filter = weight.collision_filter.expect("No collision filter");
+            assert_eq!(filter.collision_systems, vec!["props".to_string()]);
+            assert!(filter.collide_with_systems.is_empty());
+            assert_eq!(filter.not_collide_with_systems, vec!["player".to_string()]);
         }
     }
 }
@@ -0,0 +1,51 @@
+use gltf_kun::{
+    extensions::{
+        DefaultExtensions,
+        omi_seat::{OmiSeat, weight::OmiSeatWeight},
+    },
+    graph::{ByteNode, Extensions, Graph, gltf::document::GltfDocument},
+    io::format::glb::{GlbExport, GlbImport},
+};
+use tracing_test::traced_test;
+
+#[tokio::test]
+#[traced_test]
+async fn main() {
+    let mut graph = Graph::default();
+    let doc = GltfDocument::new(&mut graph);
+
+    let scene = doc.create_scene(&mut graph);
+
+    let seat = doc.create_node(&mut graph);
+    scene.add_node(&mut graph, seat);
+
+    let other = doc.create_node(&mut graph);
+    scene.add_node(&mut graph, other);
+
+    let weight = OmiSeatWeight {
+        back: [0.0, 0.0, -0.3],
+        foot: [0.0, -0.5, 0.4],
+        knee: [0.0, 0.0, 0.3],
+        angle: 1.2,
+    };
+    let ext = seat.create_extension::<OmiSeat>(&mut graph);
+    ext.write(&mut graph, &weight);
+
+    let glb =
+        GlbExport::<DefaultExtensions>::export(&mut graph, &doc).expect("Failed to export glb");
+
+    let mut graph = Graph::default();
+    let doc = GlbImport::<DefaultExtensions>::import_slice(&mut graph, &glb.0)
+        .await
+        .expect("Failed to import glb");
+
+    let nodes = doc.nodes(&graph);
+    assert_eq!(nodes.len(), 2);
+
+    let ext = nodes[0]
+        .get_extension::<OmiSeat>(&graph)
+        .expect("OMI_seat extension not found");
+    assert_eq!(ext.read(&graph), weight);
+
+    assert!(nodes[1].get_extension::<OmiSeat>(&graph).is_none());
+}
@@ -0,0 +1,61 @@
+use gltf_kun::{
+    extensions::{
+        DefaultExtensions,
+        omi_spawn_point::{OmiSpawnPoint, weight::OmiSpawnPointWeight},
+    },
+    graph::{ByteNode, Extensions, Graph, gltf::document::GltfDocument},
+    io::format::glb::{GlbExport, GlbImport},
+};
+use tracing_test::traced_test;
+
+#[tokio::test]
+#[traced_test]
+async fn main() {
+    let mut graph = Graph::default();
+    let doc = GltfDocument::new(&mut graph);
+
+    let scene = doc.create_scene(&mut graph);
+
+    let red = doc.create_node(&mut graph);
+    scene.add_node(&mut graph, red);
+
+    let blue = doc.create_node(&mut graph);
+    scene.add_node(&mut graph, blue);
+
+    let red_weight = OmiSpawnPointWeight {
+        title: Some("Red Base".to_string()),
+        team: Some("Red".to_string()),
+        group: None,
+    };
+    let ext = red.create_extension::<OmiSpawnPoint>(&mut graph);
+    ext.write(&mut graph, &red_weight);
+
+    let blue_weight = OmiSpawnPointWeight {
+        title: None,
+        team: Some("Blue".to_string()),
+        group: Some("Arena".to_string()),
+    };
+    let ext = blue.create_extension::<OmiSpawnPoint>(&mut graph);
+    ext.write(&mut graph, &blue_weight);
+
+    let glb =
+        GlbExport::<DefaultExtensions>::export(&mut graph, &doc).expect("Failed to export glb");
+
+    let mut graph = Graph::default();
+    let doc = GlbImport::<DefaultExtensions>::import_slice(&mut graph, &glb.0)
+        .await
+        .expect("Failed to import glb");
+
+    let nodes = doc.nodes(&graph);
+    assert_eq!(nodes.len(), 2);
+
+    let ext = nodes[0]
+        .get_extension::<OmiSpawnPoint>(&graph)
+        .expect("OMI_spawn_point extension not found");
+    assert_eq!(ext.read(&graph), red_weight);
+
+    let ext = nodes[1]
+        .get_extension::<OmiSpawnPoint>(&graph)
+        .expect("OMI_spawn_point extension not found");
+    assert_eq!(ext.read(&graph), blue_weight);
+}
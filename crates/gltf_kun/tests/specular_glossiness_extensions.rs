@@ -0,0 +1,134 @@
+use std::io::Cursor;
+
+use gltf_kun::{
+    extensions::{
+        DefaultExtensions,
+        khr_materials_pbr_specular_glossiness::{
+            KhrMaterialsPbrSpecularGlossiness, SpecularGlossinessWeight, convert::convert,
+        },
+    },
+    graph::{
+        ByteNode, Extensions, Graph, GraphNodeWeight,
+        gltf::{buffer::Buffer, document::GltfDocument, texture::Texture},
+    },
+    io::format::glb::{GlbExport, GlbImport},
+};
+use image::{ImageFormat, Rgba, RgbaImage};
+use tracing_test::traced_test;
+
+fn create_texture(graph: &mut Graph, doc: GltfDocument, buffer: Buffer, pixel: [u8; 4]) -> Texture {
+    let mut data = Vec::new();
+    RgbaImage::from_pixel(2, 2, Rgba(pixel))
+        .write_to(&mut Cursor::new(&mut data), ImageFormat::Png)
+        .expect("image should encode");
+
+    let mut image = doc.create_image(graph);
+    image.set_buffer(graph, Some(buffer));
+    image.get_mut(graph).mime_type = Some("image/png".to_string());
+    image.get_mut(graph).data = data;
+
+    let texture = doc.create_texture(graph);
+    texture.set_image(graph, Some(image));
+    texture
+}
+
+#[tokio::test]
+#[traced_test]
+async fn main() {
+    let mut graph = Graph::default();
+    let doc = GltfDocument::new(&mut graph);
+
+    let scene = doc.create_scene(&mut graph);
+    let node = doc.create_node(&mut graph);
+    scene.add_node(&mut graph, node);
+
+    let buffer = doc.create_buffer(&mut graph);
+    let diffuse = create_texture(&mut graph, doc, buffer, [255, 128, 0, 255]);
+    let specular_glossiness = create_texture(&mut graph, doc, buffer, [10, 10, 10, 191]);
+
+    let baked_weight = SpecularGlossinessWeight {
+        glossiness_factor: 1.0,
+        ..Default::default()
+    };
+    let material = doc.create_material(&mut graph);
+    let ext = material.create_extension::<KhrMaterialsPbrSpecularGlossiness>(&mut graph);
+    ext.write(&mut graph, &baked_weight);
+    ext.set_diffuse_texture(&mut graph, Some(diffuse));
+    ext.set_specular_glossiness_texture(&mut graph, Some(specular_glossiness));
+
+    let factor_weight = SpecularGlossinessWeight {
+        diffuse_factor: [0.5, 0.5, 0.5, 1.0],
+        specular_factor: [0.04, 0.04, 0.04],
+        glossiness_factor: 0.25,
+        diffuse_tex_coord: 1,
+        ..Default::default()
+    };
+    let material = doc.create_material(&mut graph);
+    let ext = material.create_extension::<KhrMaterialsPbrSpecularGlossiness>(&mut graph);
+    ext.write(&mut graph, &factor_weight);
+    ext.set_diffuse_texture(&mut graph, Some(diffuse));
+
+    let glb =
+        GlbExport::<DefaultExtensions>::export(&mut graph, &doc).expect("Failed to export glb");
+
+    let mut graph = Graph::default();
+    let doc = GlbImport::<DefaultExtensions>::import_slice(&mut graph, &glb.0)
+        .await
+        .expect("Failed to import glb");
+
+    let materials = doc.materials(&graph);
+    let textures = doc.textures(&graph);
+    assert_eq!(materials.len(), 2);
+    assert_eq!(textures.len(), 2);
+
+    let ext = materials[0]
+        .get_extension::<KhrMaterialsPbrSpecularGlossiness>(&graph)
+        .expect("KHR_materials_pbrSpecularGlossiness extension not found");
+    assert_eq!(ext.read(&graph), baked_weight);
+    assert_eq!(ext.diffuse_texture(&graph), Some(textures[0]));
+    assert_eq!(ext.specular_glossiness_texture(&graph), Some(textures[1]));
+
+    let ext = materials[1]
+        .get_extension::<KhrMaterialsPbrSpecularGlossiness>(&graph)
+        .expect("KHR_materials_pbrSpecularGlossiness extension not found");
+    assert_eq!(ext.read(&graph), factor_weight);
+    assert_eq!(ext.specular_glossiness_texture(&graph), None);
+
+    convert(&mut graph, &doc).expect("conversion should succeed");
+
+    for material in &materials {
+        assert!(
+            material
+                .get_extension::<KhrMaterialsPbrSpecularGlossiness>(&graph)
+                .is_none()
+        );
+    }
+
+    // Baked material.
+    let textures = doc.textures(&graph);
+    assert_eq!(textures.len(), 4);
+    assert_eq!(materials[0].base_color_texture(&graph), Some(textures[2]));
+    assert_eq!(
+        materials[0].metallic_roughness_texture(&graph),
+        Some(textures[3])
+    );
+
+    let image = textures[3]
+        .image(&graph)
+        .expect("baked texture has an image");
+    let pixels = image::load_from_memory(&image.get(&graph).data)
+        .expect("baked image should decode")
+        .to_rgba8();
+    let texel = pixels.get_pixel(0, 0);
+    assert_eq!(texel[1], 64);
+    assert_eq!(texel[2], 0);
+
+    // Factor-only material.
+    let weight = materials[1].get(&graph);
+    assert!((weight.roughness_factor - 0.75).abs() < 1e-6);
+    assert!(weight.metallic_factor.abs() < 1e-6);
+    assert!((weight.base_color_factor[0] - 0.5).abs() < 1e-3);
+    assert_eq!(weight.base_color_tex_coord, 1);
+    assert_eq!(materials[1].base_color_texture(&graph), Some(textures[0]));
+    assert_eq!(materials[1].metallic_roughness_texture(&graph), None);
+}
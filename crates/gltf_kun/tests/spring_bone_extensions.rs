@@ -0,0 +1,97 @@
+use gltf_kun::{
+    extensions::{
+        DefaultExtensions,
+        vrmc_spring_bone::{
+            VrmcSpringBone,
+            collider::{ColliderGroupWeight, ColliderShape, ColliderWeight},
+            spring::{SpringJointWeight, SpringWeight},
+        },
+    },
+    graph::{ByteNode, Extensions, Graph, gltf::document::GltfDocument},
+    io::format::glb::{GlbExport, GlbImport},
+};
+use tracing_test::traced_test;
+
+#[tokio::test]
+#[traced_test]
+async fn main() {
+    let mut graph = Graph::default();
+    let doc = GltfDocument::new(&mut graph);
+
+    let head = doc.create_node(&mut graph);
+    let hair_1 = doc.create_node(&mut graph);
+    let hair_2 = doc.create_node(&mut graph);
+
+    let ext = doc.create_extension::<VrmcSpringBone>(&mut graph);
+
+    let collider = ext.create_collider(
+        &mut graph,
+        &ColliderWeight {
+            shape: ColliderShape::Sphere {
+                offset: [0.0, 0.1, 0.0],
+                radius: 0.15,
+            },
+        },
+    );
+    collider.set_node(&mut graph, Some(head));
+
+    let group = ext.create_collider_group(
+        &mut graph,
+        &ColliderGroupWeight {
+            name: Some("head".to_string()),
+        },
+    );
+    group.add_collider(&mut graph, collider);
+
+    let spring = ext.create_spring(
+        &mut graph,
+        &SpringWeight {
+            name: Some("hair".to_string()),
+        },
+    );
+    spring.create_joint(
+        &mut graph,
+        hair_1,
+        &SpringJointWeight {
+            gravity_power: 0.5,
+            ..Default::default()
+        },
+    );
+    spring.create_joint(&mut graph, hair_2, &SpringJointWeight::default());
+    spring.add_collider_group(&mut graph, group);
+    spring.set_center(&mut graph, Some(head));
+
+    let glb =
+        GlbExport::<DefaultExtensions>::export(&mut graph, &doc).expect("Failed to export glb");
+
+    let mut graph = Graph::default();
+    let doc = GlbImport::<DefaultExtensions>::import_slice(&mut graph, &glb.0)
+        .await
+        .expect("Failed to import glb");
+
+    let ext = doc
+        .get_extension::<VrmcSpringBone>(&graph)
+        .expect("VRMC_springBone extension not found");
+
+    let nodes = doc.nodes(&graph);
+
+    let colliders = ext.colliders(&graph);
+    assert_eq!(colliders.len(), 1);
+    assert_eq!(colliders[0].node(&graph), Some(nodes[0]));
+
+    let groups = ext.collider_groups(&graph);
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].read(&graph).name.as_deref(), Some("head"));
+    assert_eq!(groups[0].colliders(&graph), colliders);
+
+    let springs = ext.springs(&graph);
+    assert_eq!(springs.len(), 1);
+    assert_eq!(springs[0].center(&graph), Some(nodes[0]));
+    assert_eq!(springs[0].collider_groups(&graph), groups);
+
+    let joints = springs[0].joints(&graph);
+    assert_eq!(joints.len(), 2);
+    assert_eq!(joints[0].node(&graph), Some(nodes[1]));
+    assert_eq!(joints[1].node(&graph), Some(nodes[2]));
+    assert!((joints[0].read(&graph).gravity_power - 0.5).abs() < f32::EPSILON);
+}
@@ -0,0 +1,173 @@
+use gltf_kun::{
+    extensions::{
+        DefaultExtensions,
+        ext_structural_metadata::{
+            ExtStructuralMetadata, StructuralMetadataWeight,
+            primitive::{PrimitiveMetadata, PrimitiveMetadataWeight},
+            property_table::{PropertyTableProperty, PropertyTableWeight, PropertyValue},
+            property_texture::{PropertyTextureProperty, PropertyTextureWeight},
+            schema::{Class, ClassProperty, PropertyComponentType, PropertyType, Schema},
+        },
+    },
+    graph::{
+        ByteNode, Extensions, Graph,
+        gltf::{accessor::iter::AccessorElement, document::GltfDocument},
+    },
+    io::format::glb::{GlbExport, GlbImport},
+};
+use tracing_test::traced_test;
+
+fn schema() -> Schema {
+    let mut class = Class::default();
+    class.properties.insert(
+        "height".to_string(),
+        ClassProperty {
+            type_: PropertyType::Scalar,
+            component_type: Some(PropertyComponentType::Float32),
+            ..Default::default()
+        },
+    );
+    class.properties.insert(
+        "name".to_string(),
+        ClassProperty {
+            type_: PropertyType::String,
+            ..Default::default()
+        },
+    );
+
+    let mut schema = Schema {
+        id: "buildings".to_string(),
+        ..Default::default()
+    };
+    schema.classes.insert("building".to_string(), class);
+    schema
+}
+
+fn create_table(graph: &mut Graph, metadata: ExtStructuralMetadata) {
+    let table = metadata.create_property_table(
+        graph,
+        &PropertyTableWeight {
+            name: Some("Buildings".to_string()),
+            class: "building".to_string(),
+            count: 2,
+            properties: ["height", "name"]
+                .into_iter()
+                .map(|p| (p.to_string(), PropertyTableProperty::default()))
+                .collect(),
+        },
+    );
+    table.set_values(
+        graph,
+        "height",
+        Some([4.0f32, 8.0].iter().flat_map(|h| h.to_le_bytes()).collect()),
+    );
+    table.set_values(graph, "name", Some(b"ShedTower".to_vec()));
+    table.set_string_offsets(
+        graph,
+        "name",
+        Some([0u32, 4, 9].iter().flat_map(|o| o.to_le_bytes()).collect()),
+    );
+}
+
+#[tokio::test]
+#[traced_test]
+async fn main() {
+    let mut graph = Graph::default();
+    let doc = GltfDocument::new(&mut graph);
+
+    let scene = doc.create_scene(&mut graph);
+    let node = doc.create_node(&mut graph);
+    scene.add_node(&mut graph, node);
+
+    let mesh = doc.create_mesh(&mut graph);
+    node.set_mesh(&mut graph, Some(mesh));
+    let primitive = mesh.create_primitive(&mut graph);
+
+    let image = doc.create_image(&mut graph);
+    let texture = doc.create_texture(&mut graph);
+    texture.set_image(&mut graph, Some(image));
+
+    let metadata = doc.create_extension::<ExtStructuralMetadata>(&mut graph);
+    metadata.write(
+        &mut graph,
+        &StructuralMetadataWeight {
+            schema: Some(schema()),
+            ..Default::default()
+        },
+    );
+
+    create_table(&mut graph, metadata);
+
+    let property_texture = metadata.create_property_texture(
+        &mut graph,
+        &PropertyTextureWeight {
+            name: None,
+            class: "building".to_string(),
+            properties: [("height".to_string(), PropertyTextureProperty::default())].into(),
+        },
+    );
+    property_texture.set_texture(&mut graph, "height", Some(texture));
+
+    let primitive_metadata = PrimitiveMetadata::new(
+        &mut graph,
+        &PrimitiveMetadataWeight {
+            property_textures: vec![0],
+            property_attributes: Vec::new(),
+        },
+    );
+    primitive.add_extension(&mut graph, primitive_metadata);
+
+    let glb =
+        GlbExport::<DefaultExtensions>::export(&mut graph, &doc).expect("Failed to export glb");
+
+    let mut graph = Graph::default();
+    let doc = GlbImport::<DefaultExtensions>::import_slice(&mut graph, &glb.0)
+        .await
+        .expect("Failed to import glb");
+
+    let metadata = doc
+        .get_extension::<ExtStructuralMetadata>(&graph)
+        .expect("EXT_structural_metadata extension not found");
+    let schema = metadata.read(&graph).schema.expect("schema should exist");
+    assert_eq!(schema, self::schema());
+
+    let tables = metadata.property_tables(&graph);
+    assert_eq!(tables.len(), 1);
+    assert_eq!(tables[0].read(&graph).name.as_deref(), Some("Buildings"));
+
+    let heights = tables[0]
+        .read_property(&graph, &schema, "height")
+        .expect("heights should read");
+    assert_eq!(
+        heights,
+        vec![
+            PropertyValue::Element(AccessorElement::F32(4.0)),
+            PropertyValue::Element(AccessorElement::F32(8.0)),
+        ]
+    );
+
+    let names = tables[0]
+        .read_property(&graph, &schema, "name")
+        .expect("names should read");
+    assert_eq!(
+        names,
+        vec![
+            PropertyValue::String("Shed".to_string()),
+            PropertyValue::String("Tower".to_string()),
+        ]
+    );
+
+    let texture = doc.textures(&graph)[0];
+    let property_textures = metadata.property_textures(&graph);
+    assert_eq!(property_textures.len(), 1);
+    assert_eq!(
+        property_textures[0].texture(&graph, "height"),
+        Some(texture)
+    );
+
+    let primitive = doc.meshes(&graph)[0].primitives(&graph)[0];
+    let primitive_metadata = primitive
+        .get_extension::<PrimitiveMetadata>(&graph)
+        .expect("primitive metadata not found");
+    assert_eq!(primitive_metadata.read(&graph).property_textures, vec![0]);
+}
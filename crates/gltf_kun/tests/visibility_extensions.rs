@@ -0,0 +1,50 @@
+use gltf_kun::{
+    extensions::{
+        DefaultExtensions,
+        khr_node_visibility::{KhrNodeVisibility, weight::NodeVisibilityWeight},
+    },
+    graph::{ByteNode, Extensions, Graph, gltf::document::GltfDocument},
+    io::format::glb::{GlbExport, GlbImport},
+};
+use tracing_test::traced_test;
+
+#[tokio::test]
+#[traced_test]
+async fn main() {
+    let mut graph = Graph::default();
+    let doc = GltfDocument::new(&mut graph);
+
+    let scene = doc.create_scene(&mut graph);
+
+    let hidden = doc.create_node(&mut graph);
+    scene.add_node(&mut graph, hidden);
+
+    let child = doc.create_node(&mut graph);
+    hidden.add_child(&mut graph, &child);
+
+    let weight = NodeVisibilityWeight { visible: false };
+    let ext = hidden.create_extension::<KhrNodeVisibility>(&mut graph);
+    ext.write(&mut graph, &weight);
+
+    let glb =
+        GlbExport::<DefaultExtensions>::export(&mut graph, &doc).expect("Failed to export glb");
+
+    let mut graph = Graph::default();
+    let doc = GlbImport::<DefaultExtensions>::import_slice(&mut graph, &glb.0)
+        .await
+        .expect("Failed to import glb");
+
+    let nodes = doc.nodes(&graph);
+    assert_eq!(nodes.len(), 2);
+
+    let ext = nodes[0]
+        .get_extension::<KhrNodeVisibility>(&graph)
+        .expect("KHR_node_visibility extension not found");
+    assert_eq!(ext.read(&graph), weight);
+
+    assert!(
+        nodes[1]
+            .get_extension::<KhrNodeVisibility>(&graph)
+            .is_none()
+    );
+}
@@ -0,0 +1,120 @@
+use gltf_kun::{
+    extensions::{
+        DefaultExtensions,
+        vrmc_vrm::{
+            VrmcVrm,
+            expression::{ExpressionOverride, ExpressionWeight, MorphTargetBindWeight},
+            human_bone::HumanBone,
+            weight::{LookAt, LookAtType, Meta, VrmcVrmWeight},
+        },
+    },
+    graph::{ByteNode, Extensions, Graph, gltf::document::GltfDocument},
+    io::format::glb::{GlbExport, GlbImport},
+};
+use tracing_test::traced_test;
+
+#[tokio::test]
+#[traced_test]
+async fn main() {
+    let mut graph = Graph::default();
+    let doc = GltfDocument::new(&mut graph);
+
+    let hips = doc.create_node(&mut graph);
+    let head = doc.create_node(&mut graph);
+    let face = doc.create_node(&mut graph);
+
+    let ext = doc.create_extension::<VrmcVrm>(&mut graph);
+    ext.write(
+        &mut graph,
+        &VrmcVrmWeight {
+            meta: Meta {
+                name: "Avatar".to_string(),
+                authors: vec!["Alice".to_string()],
+                license_url: "https://vrm.dev/licenses/1.0/".to_string(),
+                ..Default::default()
+            },
+            look_at: Some(LookAt {
+                typ: LookAtType::Expression,
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+    );
+
+    ext.set_human_bone(&mut graph, HumanBone::Hips, Some(hips));
+    ext.set_human_bone(&mut graph, HumanBone::Head, Some(face));
+    ext.set_human_bone(&mut graph, HumanBone::Head, Some(head));
+
+    let blink = ext.create_expression(
+        &mut graph,
+        &ExpressionWeight {
+            name: "blink".to_string(),
+            preset: true,
+            is_binary: true,
+            override_mouth: ExpressionOverride::Block,
+            ..Default::default()
+        },
+    );
+    blink.create_morph_target_bind(
+        &mut graph,
+        face,
+        &MorphTargetBindWeight {
+            index: 2,
+            weight: 1.0,
+        },
+    );
+
+    ext.create_expression(
+        &mut graph,
+        &ExpressionWeight {
+            name: "wink".to_string(),
+            ..Default::default()
+        },
+    );
+
+    let glb =
+        GlbExport::<DefaultExtensions>::export(&mut graph, &doc).expect("Failed to export glb");
+
+    let mut graph = Graph::default();
+    let doc = GlbImport::<DefaultExtensions>::import_slice(&mut graph, &glb.0)
+        .await
+        .expect("Failed to import glb");
+
+    let ext = doc
+        .get_extension::<VrmcVrm>(&graph)
+        .expect("VRMC_vrm extension not found");
+
+    let weight = ext.read(&graph);
+    assert_eq!(weight.spec_version, "1.0");
+    assert_eq!(weight.meta.name, "Avatar");
+    assert_eq!(weight.look_at.map(|l| l.typ), Some(LookAtType::Expression));
+
+    let nodes = doc.nodes(&graph);
+    assert_eq!(ext.humanoid_bones(&graph).len(), 2);
+    assert_eq!(ext.human_bone(&graph, HumanBone::Hips), Some(nodes[0]));
+    assert_eq!(ext.human_bone(&graph, HumanBone::Head), Some(nodes[1]));
+    assert_eq!(ext.human_bone(&graph, HumanBone::Spine), None);
+
+    let expressions = ext.expressions(&graph);
+    assert_eq!(expressions.len(), 2);
+
+    let blink = expressions
+        .iter()
+        .find(|e| e.read(&graph).name == "blink")
+        .expect("blink expression not found");
+    let blink_weight = blink.read(&graph);
+    assert!(blink_weight.preset);
+    assert!(blink_weight.is_binary);
+    assert_eq!(blink_weight.override_mouth, ExpressionOverride::Block);
+
+    let binds = blink.morph_target_binds(&graph);
+    assert_eq!(binds.len(), 1);
+    assert_eq!(binds[0].node(&graph), Some(nodes[2]));
+    assert_eq!(binds[0].read(&graph).index, 2);
+
+    let wink = expressions
+        .iter()
+        .find(|e| e.read(&graph).name == "wink")
+        .expect("wink expression not found");
+    assert!(!wink.read(&graph).preset);
+}
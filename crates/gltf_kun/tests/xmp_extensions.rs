@@ -0,0 +1,80 @@
+use gltf_kun::{
+    extensions::{
+        DefaultExtensions,
+        khr_xmp_json_ld::{KhrXmpJsonLd, dublin_core::DublinCore, packet::PacketWeight},
+    },
+    graph::{ByteNode, Extensions, Graph, gltf::document::GltfDocument},
+    io::format::glb::{GlbExport, GlbImport},
+};
+use tracing_test::traced_test;
+
+#[tokio::test]
+#[traced_test]
+async fn main() {
+    let mut graph = Graph::default();
+    let doc = GltfDocument::new(&mut graph);
+
+    let scene = doc.create_scene(&mut graph);
+    let node = doc.create_node(&mut graph);
+    scene.add_node(&mut graph, node);
+
+    let mesh = doc.create_mesh(&mut graph);
+    let material = doc.create_material(&mut graph);
+    let image = doc.create_image(&mut graph);
+
+    let dc = DublinCore {
+        title: Some("Lamp".to_string()),
+        rights: Some("CC-BY-4.0".to_string()),
+        creators: vec!["Alice".to_string()],
+        ..Default::default()
+    };
+
+    let mut weight = PacketWeight::default();
+    weight.set_dublin_core(&dc);
+
+    let ext = doc.create_extension::<KhrXmpJsonLd>(&mut graph);
+    let asset_packet = ext.create_packet(&mut graph, &weight);
+    let image_packet = ext.create_packet(&mut graph, &PacketWeight::default());
+
+    ext.set_asset_packet(&mut graph, Some(asset_packet));
+    node.add_extension(&mut graph, asset_packet);
+    mesh.add_extension(&mut graph, asset_packet);
+    material.add_extension(&mut graph, asset_packet);
+    image.add_extension(&mut graph, image_packet);
+
+    let glb =
+        GlbExport::<DefaultExtensions>::export(&mut graph, &doc).expect("Failed to export glb");
+
+    let mut graph = Graph::default();
+    let doc = GlbImport::<DefaultExtensions>::import_slice(&mut graph, &glb.0)
+        .await
+        .expect("Failed to import glb");
+
+    let ext = doc
+        .get_extension::<KhrXmpJsonLd>(&graph)
+        .expect("KHR_xmp_json_ld extension not found");
+    let packets = ext.packets(&graph);
+    assert_eq!(packets.len(), 2);
+
+    let asset_packet = packets
+        .iter()
+        .copied()
+        .find(|p| p.read(&graph) == weight)
+        .expect("packet should round trip");
+    assert_eq!(asset_packet.read(&graph).dublin_core(), dc);
+    assert_eq!(ext.asset_packet(&graph), Some(asset_packet));
+
+    let node = doc.nodes(&graph)[0];
+    let mesh = doc.meshes(&graph)[0];
+    let material = doc.materials(&graph)[0];
+    let image = doc.images(&graph)[0];
+
+    assert_eq!(node.get_extension(&graph), Some(asset_packet));
+    assert_eq!(mesh.get_extension(&graph), Some(asset_packet));
+    assert_eq!(material.get_extension(&graph), Some(asset_packet));
+
+    let image_packet = image
+        .get_extension::<gltf_kun::extensions::khr_xmp_json_ld::packet::Packet>(&graph)
+        .expect("image packet should exist");
+    assert_ne!(image_packet, asset_packet);
+}